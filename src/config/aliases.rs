@@ -0,0 +1,101 @@
+//! Friendly names for pubkeys, kept in `aliases.json` next to the config file.
+//!
+//! The file is a flat JSON object mapping base58 pubkeys to names. It is
+//! optional; a missing file simply means no aliases.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::app_config_dir;
+
+/// Pubkey aliases loaded at startup and written back when one is edited.
+pub struct AliasStore {
+    path: PathBuf,
+    aliases: BTreeMap<String, String>,
+    /// Why the file could not be read; edits are refused so it isn't overwritten
+    load_error: Option<String>,
+}
+
+impl AliasStore {
+    /// Load aliases from the default location.
+    pub fn load() -> Self {
+        Self::load_from(app_config_dir().join("aliases.json"))
+    }
+
+    /// Load aliases from `path`, treating a missing file as empty.
+    pub fn load_from(path: PathBuf) -> Self {
+        let (aliases, load_error) = match fs::read_to_string(&path) {
+            Ok(contents) => match serde_json::from_str(&contents) {
+                Ok(aliases) => (aliases, None),
+                Err(e) => (BTreeMap::new(), Some(e.to_string())),
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => (BTreeMap::new(), None),
+            Err(e) => (BTreeMap::new(), Some(e.to_string())),
+        };
+        if let Some(error) = &load_error {
+            eprintln!("Warning: Ignoring alias file {}: {}", path.display(), error);
+        }
+
+        Self {
+            path,
+            aliases,
+            load_error,
+        }
+    }
+
+    /// Alias for a pubkey, if one is set.
+    pub fn get(&self, pubkey: &str) -> Option<&str> {
+        self.aliases.get(pubkey).map(String::as_str)
+    }
+
+    /// Set the alias for a pubkey and write the file; an empty name removes it.
+    pub fn set(&mut self, pubkey: &str, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(error) = &self.load_error {
+            return Err(format!(
+                "{} could not be read ({}); fix or remove it first",
+                self.path.display(),
+                error
+            )
+            .into());
+        }
+
+        let name = name.trim();
+        if name.is_empty() {
+            self.aliases.remove(pubkey);
+        } else {
+            self.aliases.insert(pubkey.to_string(), name.to_string());
+        }
+        let json = serde_json::to_string_pretty(&self.aliases)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alias_store_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("solana-ui-aliases-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut store = AliasStore::load_from(path.clone());
+        assert_eq!(store.get("Vote111"), None);
+        store.set("Vote111", "  My validator ").unwrap();
+        store.set("Other111", "Other").unwrap();
+        store.set("Other111", "").unwrap();
+
+        let reloaded = AliasStore::load_from(path.clone());
+        assert_eq!(reloaded.get("Vote111"), Some("My validator"));
+        assert_eq!(reloaded.get("Other111"), None);
+
+        fs::write(&path, "not json").unwrap();
+        let mut broken = AliasStore::load_from(path.clone());
+        assert!(broken.set("Vote111", "Name").is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "not json");
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -4,14 +4,144 @@
 //! - Application configuration persistence
 //! - Settings management
 //! - Config file handling
+//! - The optional pubkey alias file
 
-use crate::utils::Cluster;
+mod aliases;
+
+pub use aliases::AliasStore;
+
+use crate::constants::RECENT_SEARCHES_LIMIT;
+use crate::solana::{Commitment, RpcOperation, VoterDetail};
+use crate::utils::{push_recent_search, validate_rpc_url, Cluster, StripeIntensity};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
+/// An external validator page opened from the validators table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalLink {
+    /// Name shown in the context menu
+    pub name: String,
+    /// URL with `{identity}` and/or `{vote_account}` placeholders
+    pub url_template: String,
+}
+
+impl ExternalLink {
+    fn new(name: &str, url_template: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            url_template: url_template.to_string(),
+        }
+    }
+
+    /// Fill in the placeholders for a validator.
+    pub fn url_for(&self, identity: &str, vote_account: &str) -> String {
+        self.url_template
+            .replace("{identity}", identity)
+            .replace("{vote_account}", vote_account)
+    }
+}
+
+/// Search fields that keep a history of recent terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchField {
+    Validators,
+    GossipNodes,
+    FindVotersSlot,
+    LeaderIdentity,
+}
+
+/// Cluster and RPC URL given on the command line. They apply to the startup
+/// session only and are never written back to the config file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StartupOverrides {
+    pub cluster: Option<Cluster>,
+    /// Endpoint for the `Custom` cluster; implies `Custom` when set
+    pub rpc_url: Option<String>,
+}
+
+impl StartupOverrides {
+    /// Parse `--cluster <name>` and `--rpc-url <url>` (or `--flag=value`) from the
+    /// program arguments. Invalid values and unknown arguments are skipped and
+    /// described in the returned messages.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> (Self, Vec<String>) {
+        let mut overrides = Self::default();
+        let mut problems = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg.clone(), None),
+            };
+            if flag != "--cluster" && flag != "--rpc-url" {
+                problems.push(format!("Ignoring unknown argument '{}'", arg));
+                continue;
+            }
+            let Some(value) = inline_value.or_else(|| args.next()) else {
+                problems.push(format!("{} needs a value", flag));
+                continue;
+            };
+
+            if flag == "--cluster" {
+                match Cluster::from_name(&value) {
+                    Some(cluster) => overrides.cluster = Some(cluster),
+                    None => problems.push(format!(
+                        "Unknown cluster '{}'; expected mainnet, testnet, devnet, localhost or custom",
+                        value
+                    )),
+                }
+            } else if let Err(error) = validate_rpc_url(&value) {
+                problems.push(format!("Invalid RPC URL '{}': {}", value, error));
+            } else {
+                overrides.rpc_url = Some(value.trim().to_string());
+            }
+        }
+
+        if overrides.rpc_url.is_some() {
+            if let Some(cluster) = overrides.cluster
+                && cluster != Cluster::Custom
+            {
+                problems.push(format!(
+                    "--rpc-url takes precedence over --cluster {}",
+                    cluster.name().to_lowercase()
+                ));
+            }
+            overrides.cluster = Some(Cluster::Custom);
+        }
+        (overrides, problems)
+    }
+}
+
+/// Metrics CSV location used when `metrics_log_path` is unset.
+pub fn default_metrics_log_path() -> PathBuf {
+    app_config_dir().join("metrics.csv")
+}
+
+/// Sidecar holding the result of the last successful update check.
+pub fn update_check_cache_path() -> PathBuf {
+    app_config_dir().join("last_update_check.json")
+}
+
+/// Directory holding the config and alias files, created if missing.
+fn app_config_dir() -> PathBuf {
+    let config_dir = dirs::config_dir()
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")));
+
+    let app_config_dir = config_dir.join("solana-ui");
+
+    // Create the config directory if it doesn't exist
+    if let Err(e) = fs::create_dir_all(&app_config_dir) {
+        eprintln!("Warning: Failed to create config directory: {}", e);
+    }
+
+    app_config_dir
+}
+
 /// Application configuration that persists between sessions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppConfig {
     /// Last entered validator identity for leader schedule
     pub last_leader_identity: String,
@@ -19,6 +149,8 @@ pub struct AppConfig {
     pub last_leader_epoch: String,
     /// Last selected cluster
     pub selected_cluster: Cluster,
+    /// RPC URL used by the custom cluster
+    pub custom_rpc_url: String,
     /// Last entered identity search filter
     pub last_identity_search: String,
     /// Last entered vote account search filter
@@ -35,6 +167,70 @@ pub struct AppConfig {
     pub window_size: Option<(f32, f32)>,
     /// Window position (x, y)
     pub window_position: Option<(f32, f32)>,
+    /// Validator identities on the watchlist
+    pub watchlist: Vec<String>,
+    /// Request user attention when a watchlisted validator raises its commission
+    pub commission_alert_notify: bool,
+    /// Request user attention when a fetch finishes while the window is unfocused
+    pub fetch_complete_notify: bool,
+    /// Serve a local JSON status endpoint for headless monitoring
+    pub status_server_enabled: bool,
+    /// Localhost port of the status endpoint
+    pub status_server_port: u16,
+    /// Render tab names and button labels without emoji icons
+    pub plain_labels: bool,
+    /// Show pubkeys as `ABCD…WXYZ` in tables, with the full value on hover
+    pub abbreviate_pubkeys: bool,
+    /// Match pubkeys and signatures in searches case-sensitively, as base58 requires
+    pub case_sensitive_pubkey_search: bool,
+    /// Accent for status text and selection highlights as `[r, g, b]`; built-in colors when unset
+    pub accent_color: Option<[u8; 3]>,
+    /// Shading of alternate table rows: `off`, `subtle` or `strong`
+    pub table_stripes: StripeIntensity,
+    /// Draw success, error and request colors from a colorblind-safe palette
+    pub colorblind_palette: bool,
+    /// External validator pages offered in the validators table context menu
+    pub external_links: Vec<ExternalLink>,
+    /// Keep only this many validators by activated stake after each fetch; all when unset
+    pub max_validators: Option<usize>,
+    /// Render at most this many rows in the validators and gossip tables; all when unset
+    pub max_rendered_rows: Option<usize>,
+    /// Fetch delinquent validators as well as current ones
+    pub include_delinquent_validators: bool,
+    /// Flag validators below this version as outdated instead of the most common version
+    pub min_validator_version: Option<String>,
+    /// Largest slot range a single find-voters search may cover
+    pub max_find_voters_range: u64,
+    /// Transaction detail requested when finding voters
+    pub find_voters_detail: VoterDetail,
+    /// Per-operation RPC commitment overrides
+    pub rpc_commitments: BTreeMap<RpcOperation, Commitment>,
+    /// Async worker threads in the background runtime; applied on the next start
+    pub runtime_worker_threads: usize,
+    /// Seconds between background slot info refreshes; 0 turns polling off
+    pub slot_poll_secs: u64,
+    /// Slot info refresh interval while the window is in the background; 0 pauses polling
+    pub background_slot_poll_secs: u64,
+    /// Seconds after which fetched data is flagged as possibly stale; 0 turns the warning off
+    pub stale_after_secs: u64,
+    /// Keep the logs view at the bottom as new entries arrive
+    pub logs_auto_scroll: bool,
+    /// Lines of content shown per log entry before it is cut off
+    pub log_content_max_lines: usize,
+    /// Ask for confirmation before clearing logs or Find Voters/Leader Schedule results
+    pub confirm_clear: bool,
+    /// Block the window with a cancellable overlay while heavy fetches run
+    pub busy_overlay: bool,
+    /// Recently submitted search terms per field, newest first
+    pub recent_searches: BTreeMap<SearchField, Vec<String>>,
+    /// Where updates are downloaded; the system Downloads folder when unset
+    pub download_dir: Option<PathBuf>,
+    /// Append network overview metrics to a CSV file after validators refreshes
+    pub metrics_log_enabled: bool,
+    /// CSV file the metrics are appended to; `metrics.csv` beside the config when unset
+    pub metrics_log_path: Option<PathBuf>,
+    /// Minimum seconds between metrics rows
+    pub metrics_log_interval_secs: u64,
 }
 
 impl Default for AppConfig {
@@ -43,6 +239,7 @@ impl Default for AppConfig {
             last_leader_identity: String::new(),
             last_leader_epoch: String::new(),
             selected_cluster: Cluster::Mainnet,
+            custom_rpc_url: String::new(),
             last_identity_search: String::new(),
             last_vote_account_search: String::new(),
             last_slot_search: String::new(),
@@ -51,6 +248,44 @@ impl Default for AppConfig {
             last_selected_tab: "Validators".to_string(),
             window_size: None,
             window_position: None,
+            watchlist: Vec::new(),
+            commission_alert_notify: false,
+            fetch_complete_notify: false,
+            status_server_enabled: false,
+            status_server_port: 8787,
+            plain_labels: false,
+            abbreviate_pubkeys: false,
+            case_sensitive_pubkey_search: true,
+            accent_color: None,
+            table_stripes: StripeIntensity::Subtle,
+            colorblind_palette: false,
+            external_links: vec![
+                ExternalLink::new(
+                    "validators.app",
+                    "https://www.validators.app/validators/{identity}",
+                ),
+                ExternalLink::new("Stakewiz", "https://stakewiz.com/validator/{vote_account}"),
+            ],
+            max_validators: None,
+            max_rendered_rows: None,
+            include_delinquent_validators: true,
+            min_validator_version: None,
+            max_find_voters_range: 100,
+            find_voters_detail: VoterDetail::Full,
+            rpc_commitments: RpcOperation::default_commitments(),
+            runtime_worker_threads: 4,
+            slot_poll_secs: 2,
+            background_slot_poll_secs: 30,
+            stale_after_secs: 60,
+            logs_auto_scroll: true,
+            log_content_max_lines: 3,
+            confirm_clear: true,
+            busy_overlay: false,
+            recent_searches: BTreeMap::new(),
+            download_dir: None,
+            metrics_log_enabled: false,
+            metrics_log_path: None,
+            metrics_log_interval_secs: 300,
         }
     }
 }
@@ -81,23 +316,15 @@ impl ConfigManager {
 
     /// Get the configuration file path.
     fn get_config_path() -> PathBuf {
-        let config_dir = dirs::config_dir()
-            .unwrap_or_else(|| std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")));
-
-        let app_config_dir = config_dir.join("solana-ui");
-
-        // Create the config directory if it doesn't exist
-        if let Err(e) = fs::create_dir_all(&app_config_dir) {
-            eprintln!("Warning: Failed to create config directory: {}", e);
-        }
-
-        app_config_dir.join("config.json")
+        app_config_dir().join("config.json")
     }
 
     /// Load configuration from file.
     fn load_config(path: &PathBuf) -> Result<AppConfig, Box<dyn std::error::Error>> {
         let contents = fs::read_to_string(path)?;
-        let config: AppConfig = serde_json::from_str(&contents)?;
+        let mut config: AppConfig = serde_json::from_str(&contents)?;
+        // A zero range would reject every search; the settings window stops at one
+        config.max_find_voters_range = config.max_find_voters_range.max(1);
         Ok(config)
     }
 
@@ -151,16 +378,196 @@ impl ConfigManager {
         self.config.last_gossip_identity_search = gossip_identity_search.to_string();
     }
 
+    /// Recent search terms for a field, newest first.
+    pub fn recent_searches(&self, field: SearchField) -> &[String] {
+        self.config
+            .recent_searches
+            .get(&field)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Record a submitted search term in the field's history.
+    pub fn add_recent_search(&mut self, field: SearchField, term: &str) {
+        let history = self.config.recent_searches.entry(field).or_default();
+        push_recent_search(history, term, RECENT_SEARCHES_LIMIT);
+    }
+
     /// Update selected cluster.
     pub fn update_cluster(&mut self, cluster: Cluster) {
         self.config.selected_cluster = cluster;
     }
 
+    /// Update custom RPC URL.
+    pub fn update_custom_rpc_url(&mut self, url: &str) {
+        self.config.custom_rpc_url = url.to_string();
+    }
+
     /// Update selected tab.
     pub fn update_selected_tab(&mut self, tab: &str) {
         self.config.last_selected_tab = tab.to_string();
     }
 
+    /// Add a validator identity to the watchlist, or remove it if already present.
+    pub fn toggle_watchlist(&mut self, identity: &str) {
+        if let Some(index) = self
+            .config
+            .watchlist
+            .iter()
+            .position(|entry| entry == identity)
+        {
+            self.config.watchlist.remove(index);
+        } else {
+            self.config.watchlist.push(identity.to_string());
+        }
+    }
+
+    /// Add imported identities to the watchlist, skipping ones already present.
+    /// Returns how many were newly added.
+    pub fn add_to_watchlist(&mut self, identities: &[String]) -> usize {
+        let mut added = 0;
+        for identity in identities {
+            if !self.config.watchlist.contains(identity) {
+                self.config.watchlist.push(identity.clone());
+                added += 1;
+            }
+        }
+        added
+    }
+
+    /// Update commission alert notification preference.
+    pub fn update_commission_alert_notify(&mut self, notify: bool) {
+        self.config.commission_alert_notify = notify;
+    }
+
+    /// Update plain text labels preference.
+    pub fn update_plain_labels(&mut self, plain: bool) {
+        self.config.plain_labels = plain;
+    }
+
+    /// Update find voters detail level.
+    pub fn update_find_voters_detail(&mut self, detail: VoterDetail) {
+        self.config.find_voters_detail = detail;
+    }
+
+    /// Update the fetch completion alert preference.
+    pub fn update_fetch_complete_notify(&mut self, notify: bool) {
+        self.config.fetch_complete_notify = notify;
+    }
+
+    /// Update abbreviated pubkey display preference.
+    pub fn update_abbreviate_pubkeys(&mut self, abbreviate: bool) {
+        self.config.abbreviate_pubkeys = abbreviate;
+    }
+
+    /// Update case-sensitive pubkey search preference.
+    pub fn update_case_sensitive_pubkey_search(&mut self, case_sensitive: bool) {
+        self.config.case_sensitive_pubkey_search = case_sensitive;
+    }
+
+    /// Update the table row stripe intensity.
+    pub fn update_table_stripes(&mut self, stripes: StripeIntensity) {
+        self.config.table_stripes = stripes;
+    }
+
+    /// Update whether the colorblind-safe palette is used.
+    pub fn update_colorblind_palette(&mut self, enabled: bool) {
+        self.config.colorblind_palette = enabled;
+    }
+
+    /// Update the theme accent color; `None` restores the built-in colors.
+    pub fn update_accent_color(&mut self, accent: Option<[u8; 3]>) {
+        self.config.accent_color = accent;
+    }
+
+    /// Update logs auto-scroll preference.
+    pub fn update_logs_auto_scroll(&mut self, auto_scroll: bool) {
+        self.config.logs_auto_scroll = auto_scroll;
+    }
+
+    /// Update how many lines of content each log entry shows.
+    pub fn update_log_content_max_lines(&mut self, max_lines: usize) {
+        self.config.log_content_max_lines = max_lines;
+    }
+
+    /// Update the busy overlay preference.
+    pub fn update_busy_overlay(&mut self, enabled: bool) {
+        self.config.busy_overlay = enabled;
+    }
+
+    /// Update the clear confirmation preference.
+    pub fn update_confirm_clear(&mut self, confirm: bool) {
+        self.config.confirm_clear = confirm;
+    }
+
+    /// Update the commitment level used for an RPC operation.
+    pub fn update_rpc_commitment(&mut self, operation: RpcOperation, commitment: Commitment) {
+        self.config.rpc_commitments.insert(operation, commitment);
+    }
+
+    /// Update the runtime worker thread count used on the next start.
+    pub fn update_runtime_worker_threads(&mut self, threads: usize) {
+        self.config.runtime_worker_threads = threads;
+    }
+
+    /// Update the background slot info polling interval.
+    pub fn update_slot_poll_secs(&mut self, secs: u64) {
+        self.config.slot_poll_secs = secs;
+    }
+
+    /// Update the slot info polling interval used while the window is in the background.
+    pub fn update_background_slot_poll_secs(&mut self, secs: u64) {
+        self.config.background_slot_poll_secs = secs;
+    }
+
+    /// Update the stale data warning threshold.
+    pub fn update_stale_after_secs(&mut self, secs: u64) {
+        self.config.stale_after_secs = secs;
+    }
+
+    /// Update whether delinquent validators are fetched.
+    pub fn update_include_delinquent_validators(&mut self, include: bool) {
+        self.config.include_delinquent_validators = include;
+    }
+
+    /// Update the validator list cap; `None` keeps every validator.
+    pub fn update_max_validators(&mut self, max: Option<usize>) {
+        self.config.max_validators = max;
+    }
+
+    /// Update the rendered row cap; `None` renders every row.
+    pub fn update_max_rendered_rows(&mut self, max_rows: Option<usize>) {
+        self.config.max_rendered_rows = max_rows;
+    }
+
+    /// Update the largest slot range a find-voters search may cover.
+    pub fn update_max_find_voters_range(&mut self, max_range: u64) {
+        self.config.max_find_voters_range = max_range.max(1);
+    }
+
+    /// Update the minimum validator version; an empty version clears it.
+    pub fn update_min_validator_version(&mut self, version: &str) {
+        let version = version.trim();
+        self.config.min_validator_version = (!version.is_empty()).then(|| version.to_string());
+    }
+
+    /// Update the directory updates are downloaded to.
+    pub fn update_download_dir(&mut self, dir: Option<PathBuf>) {
+        self.config.download_dir = dir;
+    }
+
+    /// Update the metrics CSV log settings.
+    pub fn update_metrics_log(&mut self, enabled: bool, path: Option<PathBuf>, interval_secs: u64) {
+        self.config.metrics_log_enabled = enabled;
+        self.config.metrics_log_path = path;
+        self.config.metrics_log_interval_secs = interval_secs;
+    }
+
+    /// Update the status endpoint settings.
+    pub fn update_status_server(&mut self, enabled: bool, port: u16) {
+        self.config.status_server_enabled = enabled;
+        self.config.status_server_port = port;
+    }
+
     /// Auto-save configuration (with error handling).
     pub fn auto_save(&self) {
         if let Err(e) = self.save_config() {
@@ -168,3 +575,46 @@ impl ConfigManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_startup_overrides_cluster() {
+        let (overrides, problems) = StartupOverrides::from_args(args(&["--cluster", "testnet"]));
+        assert_eq!(overrides.cluster, Some(Cluster::Testnet));
+        assert_eq!(overrides.rpc_url, None);
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_startup_overrides_rpc_url_implies_custom() {
+        let (overrides, problems) = StartupOverrides::from_args(args(&[
+            "--cluster=mainnet",
+            "--rpc-url=http://10.0.0.5:8899",
+        ]));
+        assert_eq!(overrides.cluster, Some(Cluster::Custom));
+        assert_eq!(overrides.rpc_url.as_deref(), Some("http://10.0.0.5:8899"));
+        assert_eq!(problems.len(), 1);
+    }
+
+    #[test]
+    fn test_startup_overrides_skip_invalid_values() {
+        let (overrides, problems) = StartupOverrides::from_args(args(&[
+            "--cluster",
+            "betanet",
+            "--rpc-url",
+            "localhost:8899",
+            "--rpc-url=https://",
+            "--verbose",
+            "--cluster",
+        ]));
+        assert_eq!(overrides, StartupOverrides::default());
+        assert_eq!(problems.len(), 5);
+    }
+}
@@ -4,6 +4,10 @@
 
 use eframe::egui;
 
+// Window Constants
+/// Base window title; the selected cluster is appended to it
+pub const WINDOW_TITLE: &str = "Solana UI";
+
 // UI Layout Constants
 pub const HEADER_SPACING_LARGE: f32 = 24.0;
 pub const HEADER_SPACING_MEDIUM: f32 = 16.0;
@@ -17,9 +21,75 @@ pub const SEARCH_FIELD_HEIGHT: f32 = 20.0;
 pub const SMALL_SEARCH_FIELD_WIDTH: f32 = 300.0;
 pub const EPOCH_FIELD_WIDTH: f32 = 60.0;
 pub const BUTTON_FIELD_WIDTH: f32 = 150.0;
+pub const JUMP_FIELD_WIDTH: f32 = 180.0;
+
+// Detail Drawer Constants
+pub const DETAIL_DRAWER_WIDTH: f32 = 400.0;
+pub const CREDITS_CHART_HEIGHT: f32 = 80.0;
+pub const CREDITS_CHART_BAR_GAP: f32 = 2.0;
+pub const CREDITS_CHART_TREND_WIDTH: f32 = 1.5;
+/// Most recent epochs shown in the credits chart
+pub const CREDITS_CHART_MAX_EPOCHS: u64 = 64;
+/// Epochs of credits history a vote account keeps, which bounds the epochs active estimate
+pub const EPOCH_CREDITS_HISTORY_LEN: u64 = 64;
+/// Seconds a vote account balance lookup is reused when the drawer reopens on it
+pub const VOTE_BALANCE_CACHE_SECS: u64 = 30;
+
+// Commission Chart Constants
+pub const COMMISSION_CHART_WIDTH: f32 = 320.0;
+pub const COMMISSION_CHART_HEIGHT: f32 = 90.0;
+pub const COMMISSION_CHART_BAR_GAP: f32 = 8.0;
+
+// Network Tab Constants
+pub const NETWORK_PROGRESS_WIDTH: f32 = 320.0;
+
+// Snapshot Compare Constants
+/// Slots a validator's last vote may trail the newest vote before it counts as delinquent
+pub const DELINQUENT_SLOT_DISTANCE: u64 = 128;
+/// Smallest skip rate change, in percentage points, listed in a snapshot compare
+pub const SNAPSHOT_SKIP_RATE_EPSILON: f64 = 0.01;
+pub const SNAPSHOT_DIFF_HEIGHT: f32 = 240.0;
+
+// Partial Results Constants
+/// Height of the scrollable list of failed sub-requests shown with partial results
+pub const PARTIAL_FAILURES_HEIGHT: f32 = 120.0;
+
+// Rolling Voters Constants
+pub const ROLLING_VOTERS_WINDOW: usize = 10;
+
+// Busy Overlay Constants
+/// Repaint interval while the overlay waits for a fetch to finish
+pub const BUSY_OVERLAY_POLL_MS: u64 = 250;
+
+// Background Constants
+/// Repaint interval while the window is unfocused or minimized
+pub const BACKGROUND_REPAINT_SECS: u64 = 5;
+
+// Settings Window Constants
+pub const SETTINGS_WINDOW_WIDTH: f32 = 420.0;
+
+// Endpoint Ping Constants
+/// Seconds each endpoint gets to answer getHealth and getSlot
+pub const ENDPOINT_PING_TIMEOUT_SECS: u64 = 5;
+
+// Table Capture Constants
+/// Frames to wait for a requested screenshot before giving up
+pub const CAPTURE_TIMEOUT_FRAMES: u32 = 10;
+
+// Status Server Constants
+/// Pause after a failed accept before listening again
+pub const STATUS_SERVER_ACCEPT_BACKOFF_MS: u64 = 500;
+
+// Recent Searches Constants
+/// Terms remembered per search field
+pub const RECENT_SEARCHES_LIMIT: usize = 8;
+
+// Pubkey Display Constants
+pub const ABBREVIATED_PUBKEY_CHARS: usize = 4;
 
 // Table Column Widths
 pub const COLUMN_PUBKEY_WIDTH: f32 = 350.0;
+pub const COLUMN_PUBKEY_ABBREVIATED_WIDTH: f32 = 110.0;
 pub const COLUMN_ADDRESS_WIDTH: f32 = 150.0;
 pub const COLUMN_VERSION_WIDTH: f32 = 100.0;
 pub const COLUMN_FEATURE_WIDTH: f32 = 80.0;
@@ -29,12 +99,12 @@ pub const COLUMN_TIME_WIDTH: f32 = 220.0;
 pub const COLUMN_SLOT_WIDTH: f32 = 120.0;
 pub const COLUMN_EPOCH_WIDTH: f32 = 80.0;
 pub const COLUMN_SMALL_INDEX_WIDTH: f32 = 50.0;
-pub const COLUMN_VOTE_ACCOUNT_WIDTH: f32 = 350.0;
 pub const COLUMN_TRANSACTION_WIDTH: f32 = 450.0;
 pub const COLUMN_LOG_TYPE_WIDTH: f32 = 40.0;
 pub const COLUMN_LOG_TIMESTAMP_WIDTH: f32 = 150.0;
 pub const COLUMN_LOG_OPERATION_WIDTH: f32 = 120.0;
 pub const COLUMN_LOG_STATUS_WIDTH: f32 = 80.0;
+pub const COLUMN_LOG_DURATION_WIDTH: f32 = 80.0;
 pub const COLUMN_LOG_URL_WIDTH: f32 = 200.0;
 pub const COLUMN_LOG_CONTENT_WIDTH: f32 = 300.0;
 
@@ -44,6 +114,9 @@ pub const TABLE_ROW_HEIGHT: f32 = 24.0;
 pub const TABLE_ROW_HEIGHT_LARGE: f32 = 26.0;
 pub const TABLE_ROW_HEIGHT_SMALL: f32 = 18.0;
 pub const TABLE_ROW_HEIGHT_LOGS: f32 = 20.0;
+/// Stripe shade of the "strong" row stripes; the themes' own stripes use 5 and 4
+pub const STRONG_STRIPE_DARK_LUMINANCE: u8 = 20;
+pub const STRONG_STRIPE_LIGHT_ALPHA: u8 = 18;
 
 // Frame and Border Constants
 pub const FRAME_INNER_MARGIN: f32 = 12.0;
@@ -54,13 +127,25 @@ pub const FRAME_STROKE_WIDTH: f32 = 1.0;
 
 // Content Display Constants
 pub const LOG_MAX_ENTRIES: usize = 1000;
-pub const LOG_CONTENT_TRUNCATE_LENGTH: usize = 100;
-pub const LOG_CONTENT_DISPLAY_LENGTH: usize = 97;
+/// Characters of log content laid out per row; the full text is shown on hover
+pub const LOG_CONTENT_LAYOUT_CHARS: usize = 4000;
 
 // Colors
 pub const ERROR_BACKGROUND: egui::Color32 =
     egui::Color32::from_rgba_premultiplied(255, 200, 200, 50);
 pub const SUCCESS_COLOR: egui::Color32 = egui::Color32::from_rgb(0, 128, 0);
+pub const COMMISSION_ALERT_COLOR: egui::Color32 = egui::Color32::from_rgb(204, 102, 0); // Dark orange
+pub const WARNING_COLOR: egui::Color32 = egui::Color32::from_rgb(204, 153, 0); // Amber
+pub const STALLED_VOTE_COLOR: egui::Color32 = egui::Color32::from_rgb(204, 0, 0); // Red
+pub const CREDITS_CHART_COLOR: egui::Color32 = egui::Color32::from_rgb(0, 102, 204); // Blue
+pub const COMMISSION_CHART_COLOR: egui::Color32 = egui::Color32::from_rgb(102, 51, 153); // Purple
+pub const DEFAULT_ACCENT_RGB: [u8; 3] = [0, 102, 204]; // Blue, picker start when no accent is set
+pub const REFRESH_FLASH_COLOR: egui::Color32 =
+    egui::Color32::from_rgba_premultiplied(90, 76, 0, 90); // Translucent gold
+
+// Refresh Highlight Constants
+/// Seconds over which a changed row's highlight fades out
+pub const REFRESH_FLASH_SECS: f32 = 1.0;
 
 // Log Entry Type Colors
 pub const LOG_REQUEST_COLOR: egui::Color32 = egui::Color32::from_rgb(0, 102, 204); // Blue
@@ -71,6 +156,12 @@ pub const LOG_ERROR_COLOR: egui::Color32 = egui::Color32::from_rgb(204, 0, 0); /
 pub const VOTER_COLOR_1: egui::Color32 = egui::Color32::from_rgb(200, 230, 255); // Light blue
 pub const VOTER_COLOR_2: egui::Color32 = egui::Color32::from_rgb(255, 230, 200); // Light orange
 
+// Colorblind-safe palette (Okabe-Ito), swapped in for the red/green status colors
+pub const COLORBLIND_SUCCESS_COLOR: egui::Color32 = egui::Color32::from_rgb(0, 114, 178); // Blue
+pub const COLORBLIND_ERROR_COLOR: egui::Color32 = egui::Color32::from_rgb(213, 94, 0); // Vermillion
+/// Reddish purple, keeping request log entries apart from the success blue
+pub const COLORBLIND_REQUEST_COLOR: egui::Color32 = egui::Color32::from_rgb(204, 121, 167);
+
 // Sort Priority Constants
 pub const PRIMARY_SORT_INDEX: usize = 0;
 pub const SORT_PRIORITY_OFFSET: usize = 1;
@@ -7,7 +7,11 @@
 //!
 //! - [`config`] - Configuration management and persistence
 //! - [`constants`] - Application constants and magic numbers
+//! - [`metrics_log`] - Optional CSV log of network overview metrics
+//! - [`settings`] - Settings window sections backed by the config
+//! - [`shortcuts`] - Keyboard shortcut table and help overlay
 //! - [`solana`] - Solana RPC client and data models
+//! - [`status_server`] - Optional local HTTP/JSON status endpoint
 //! - [`tabs`] - Individual tab functionality modules
 //! - [`ui`] - Main application UI orchestration
 //! - [`updater`] - Auto-updater for GitHub releases
@@ -15,7 +19,11 @@
 
 pub mod config;
 pub mod constants;
+pub mod metrics_log;
+pub mod settings;
+pub mod shortcuts;
 pub mod solana;
+pub mod status_server;
 pub mod tabs;
 pub mod ui;
 pub mod updater;
@@ -5,7 +5,11 @@
 
 mod config;
 mod constants;
+mod metrics_log;
+mod settings;
+mod shortcuts;
 mod solana;
+mod status_server;
 mod tabs;
 mod ui;
 mod updater;
@@ -16,7 +20,6 @@ use ui::ValidatorApp;
 /// Application configuration constants.
 mod app_config {
     pub const WINDOW_SIZE: [f32; 2] = [1800.0, 1000.0];
-    pub const WINDOW_TITLE: &str = "Solana UI";
     pub const APP_NAME: &str = "solana-ui";
 }
 
@@ -60,6 +63,12 @@ fn load_solana_icon() -> Option<egui::IconData> {
 }
 
 fn main() -> Result<(), eframe::Error> {
+    // Cluster and RPC URL overrides for this launch only
+    let (overrides, problems) = config::StartupOverrides::from_args(std::env::args().skip(1));
+    for problem in &problems {
+        eprintln!("{}", problem);
+    }
+
     // Load the configuration to get window settings
     let config_manager = config::ConfigManager::new();
 
@@ -75,7 +84,7 @@ fn main() -> Result<(), eframe::Error> {
     // Configure native window options with saved geometry
     let mut viewport_builder = egui::ViewportBuilder::default()
         .with_inner_size([window_size.0, window_size.1])
-        .with_title(app_config::WINDOW_TITLE);
+        .with_title(constants::WINDOW_TITLE);
 
     // Set position if available
     if let Some(pos) = window_position {
@@ -101,6 +110,6 @@ fn main() -> Result<(), eframe::Error> {
     eframe::run_native(
         app_config::APP_NAME,
         options,
-        Box::new(|cc| Ok(Box::new(ValidatorApp::new(cc)))),
+        Box::new(|cc| Ok(Box::new(ValidatorApp::new(cc, overrides)))),
     )
 }
@@ -0,0 +1,127 @@
+//! Optional CSV log of cluster-wide metrics for trend analysis.
+//!
+//! When enabled, each validators refresh appends a row with the total stake,
+//! validator count, Nakamoto coefficient, TPS and epoch it stored. A header is
+//! written when the file is new or empty.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use chrono::{DateTime, Local};
+
+use crate::solana::{NetworkOverview, ValidatorInfo};
+use crate::utils::{active_stake, nakamoto_coefficient};
+
+/// Column names, in the order rows are written.
+pub const CSV_HEADER: &str =
+    "timestamp,cluster,total_stake_lamports,validator_count,nakamoto_coefficient,tps,epoch";
+
+/// One sample of the network overview figures.
+#[derive(Debug, Clone)]
+pub struct MetricsRow {
+    pub timestamp: DateTime<Local>,
+    pub cluster: String,
+    pub total_stake: u64,
+    pub validator_count: usize,
+    pub nakamoto: usize,
+    /// Empty in the CSV when no performance samples were returned
+    pub tps: Option<f64>,
+    /// Empty in the CSV until the network overview has been fetched
+    pub epoch: Option<u64>,
+}
+
+impl MetricsRow {
+    /// Sample the figures shown on the Network tab from the stored refresh data.
+    pub fn new(
+        cluster: &str,
+        validators: &[ValidatorInfo],
+        overview: Option<&NetworkOverview>,
+    ) -> Self {
+        Self {
+            timestamp: Local::now(),
+            cluster: cluster.to_string(),
+            total_stake: active_stake(validators),
+            validator_count: validators.len(),
+            nakamoto: nakamoto_coefficient(validators),
+            tps: overview.and_then(|overview| overview.tps),
+            epoch: overview.map(|overview| overview.epoch),
+        }
+    }
+
+    /// Format the row as a CSV line without the trailing newline.
+    pub fn to_csv_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.timestamp.to_rfc3339(),
+            self.cluster,
+            self.total_stake,
+            self.validator_count,
+            self.nakamoto,
+            self.tps
+                .map(|tps| format!("{:.1}", tps))
+                .unwrap_or_default(),
+            self.epoch
+                .map(|epoch| epoch.to_string())
+                .unwrap_or_default()
+        )
+    }
+}
+
+/// Append a row to `path`, creating the file and its header if needed.
+pub fn append_row(path: &Path, row: &MetricsRow) -> std::io::Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    let needs_header = !fs::metadata(path).is_ok_and(|meta| meta.len() > 0);
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if needs_header {
+        writeln!(file, "{}", CSV_HEADER)?;
+    }
+    writeln!(file, "{}", row.to_csv_line())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_row_writes_header_once() {
+        let path =
+            std::env::temp_dir().join(format!("solana-ui-metrics-{}.csv", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let row = MetricsRow {
+            timestamp: Local::now(),
+            cluster: "Testnet".to_string(),
+            total_stake: 1_500,
+            validator_count: 3,
+            nakamoto: 1,
+            tps: Some(2_345.67),
+            epoch: Some(42),
+        };
+        let without_tps = MetricsRow {
+            tps: None,
+            ..row.clone()
+        };
+        let without_overview = MetricsRow {
+            epoch: None,
+            ..without_tps.clone()
+        };
+        for row in [&row, &without_tps, &without_overview] {
+            append_row(&path, row).unwrap();
+        }
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert!(lines[1].ends_with(",Testnet,1500,3,1,2345.7,42"));
+        assert!(lines[2].ends_with(",Testnet,1500,3,1,,42"));
+        assert!(lines[3].ends_with(",Testnet,1500,3,1,,"));
+        let _ = fs::remove_file(&path);
+    }
+}
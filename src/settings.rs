@@ -0,0 +1,456 @@
+//! Settings window sections that read and write [`AppConfig`] through the
+//! [`ConfigManager`].
+//!
+//! The display section is shared with the top bar's View menu so both stay in
+//! sync; the rest only appears in the settings window.
+//!
+//! [`AppConfig`]: crate::config::AppConfig
+
+use std::path::PathBuf;
+
+use eframe::egui;
+
+use crate::config::{default_metrics_log_path, ConfigManager};
+use crate::constants::*;
+use crate::solana::{Commitment, RpcOperation};
+use crate::utils::StripeIntensity;
+
+/// Render display preferences: labels, pubkeys, prompts, log wrapping, row
+/// stripes and the accent color.
+pub fn render_display_settings(ui: &mut egui::Ui, config_manager: &mut ConfigManager) {
+    let mut plain_labels = config_manager.config().plain_labels;
+    if ui
+        .checkbox(&mut plain_labels, "Plain text labels (no emoji)")
+        .on_hover_text("Use emoji-free tab names and button labels")
+        .changed()
+    {
+        config_manager.update_plain_labels(plain_labels);
+        config_manager.auto_save();
+    }
+
+    let mut abbreviate_pubkeys = config_manager.config().abbreviate_pubkeys;
+    if ui
+        .checkbox(&mut abbreviate_pubkeys, "Abbreviate pubkeys")
+        .on_hover_text("Show pubkeys as ABCD…WXYZ; hover for the full value")
+        .changed()
+    {
+        config_manager.update_abbreviate_pubkeys(abbreviate_pubkeys);
+        config_manager.auto_save();
+    }
+
+    let mut case_sensitive = config_manager.config().case_sensitive_pubkey_search;
+    if ui
+        .checkbox(&mut case_sensitive, "Case-sensitive pubkey search")
+        .on_hover_text(
+            "Match pubkeys and signatures exactly; versions and other text ignore case either way",
+        )
+        .changed()
+    {
+        config_manager.update_case_sensitive_pubkey_search(case_sensitive);
+        config_manager.auto_save();
+    }
+
+    let mut colorblind_palette = config_manager.config().colorblind_palette;
+    if ui
+        .checkbox(&mut colorblind_palette, "Colorblind-safe palette")
+        .on_hover_text("Show success in blue and errors in orange instead of green and red")
+        .changed()
+    {
+        config_manager.update_colorblind_palette(colorblind_palette);
+        config_manager.auto_save();
+    }
+
+    let mut fetch_complete_notify = config_manager.config().fetch_complete_notify;
+    if ui
+        .checkbox(&mut fetch_complete_notify, "Notify when a fetch finishes")
+        .on_hover_text(
+            "Flash the taskbar when a fetch completes while the window is in the background",
+        )
+        .changed()
+    {
+        config_manager.update_fetch_complete_notify(fetch_complete_notify);
+        config_manager.auto_save();
+    }
+
+    let mut busy_overlay = config_manager.config().busy_overlay;
+    if ui
+        .checkbox(&mut busy_overlay, "Block input during heavy fetches")
+        .on_hover_text(
+            "Dim the window with a Cancel button while Find Voters or Leader Schedule fetches run",
+        )
+        .changed()
+    {
+        config_manager.update_busy_overlay(busy_overlay);
+        config_manager.auto_save();
+    }
+
+    let mut confirm_clear = config_manager.config().confirm_clear;
+    if ui
+        .checkbox(&mut confirm_clear, "Confirm before clearing")
+        .on_hover_text("Ask before clearing logs, Find Voters or Leader Schedule results")
+        .changed()
+    {
+        config_manager.update_confirm_clear(confirm_clear);
+        config_manager.auto_save();
+    }
+
+    ui.horizontal(|ui| {
+        let mut max_lines = config_manager.config().log_content_max_lines;
+        ui.label("Log content lines");
+        if ui
+            .add(egui::DragValue::new(&mut max_lines).range(1..=20))
+            .on_hover_text(
+                "Lines of content shown per log entry; hover a cut-off entry for all of it",
+            )
+            .changed()
+        {
+            config_manager.update_log_content_max_lines(max_lines);
+            config_manager.auto_save();
+        }
+    });
+
+    ui.horizontal(|ui| {
+        let current = config_manager.config().table_stripes;
+        let mut stripes = current;
+        ui.label("Row stripes");
+        egui::ComboBox::from_id_salt("settings_table_stripes")
+            .selected_text(stripes.name())
+            .show_ui(ui, |ui| {
+                for &intensity in StripeIntensity::all() {
+                    ui.selectable_value(&mut stripes, intensity, intensity.name());
+                }
+            })
+            .response
+            .on_hover_text("Shading of alternate table rows; Strong helps on low-contrast screens");
+        if stripes != current {
+            config_manager.update_table_stripes(stripes);
+            config_manager.auto_save();
+        }
+    });
+
+    ui.horizontal(|ui| {
+        let accent = config_manager.config().accent_color;
+        let mut rgb = accent.unwrap_or(DEFAULT_ACCENT_RGB);
+        ui.label("Accent color");
+        if egui::color_picker::color_edit_button_srgb(ui, &mut rgb)
+            .on_hover_text("Color of status text and selection highlights")
+            .changed()
+        {
+            config_manager.update_accent_color(Some(rgb));
+            config_manager.auto_save();
+        }
+        if ui
+            .add_enabled(accent.is_some(), egui::Button::new("Reset"))
+            .clicked()
+        {
+            config_manager.update_accent_color(None);
+            config_manager.auto_save();
+        }
+    });
+}
+
+/// Render per-operation commitment levels. Returns true when one changed, so
+/// open sessions can pick up the new levels.
+pub fn render_commitment_settings(ui: &mut egui::Ui, config_manager: &mut ConfigManager) -> bool {
+    let mut changed = false;
+    egui::Grid::new("settings_commitments")
+        .num_columns(2)
+        .spacing([HEADER_SPACING_LARGE, CONTENT_SPACING_SMALL])
+        .show(ui, |ui| {
+            for &operation in RpcOperation::all() {
+                let current = config_manager
+                    .config()
+                    .rpc_commitments
+                    .get(&operation)
+                    .copied()
+                    .unwrap_or_else(|| operation.default_commitment());
+                let mut selected = current;
+                ui.label(operation.name());
+                egui::ComboBox::from_id_salt(("settings_commitment", operation))
+                    .selected_text(selected.name())
+                    .show_ui(ui, |ui| {
+                        for &commitment in Commitment::all() {
+                            ui.selectable_value(&mut selected, commitment, commitment.name());
+                        }
+                    });
+                ui.end_row();
+
+                if selected != current {
+                    config_manager.update_rpc_commitment(operation, selected);
+                    config_manager.auto_save();
+                    changed = true;
+                }
+            }
+        });
+    changed
+}
+
+/// Render refresh intervals, fetch limits and version/staleness thresholds.
+pub fn render_data_settings(ui: &mut egui::Ui, config_manager: &mut ConfigManager) {
+    egui::Grid::new("settings_data")
+        .num_columns(2)
+        .spacing([HEADER_SPACING_LARGE, CONTENT_SPACING_SMALL])
+        .show(ui, |ui| {
+            let mut slot_poll_secs = config_manager.config().slot_poll_secs;
+            ui.label("Slot info refresh")
+                .on_hover_text("Seconds between background slot info refreshes; 0 turns it off");
+            if ui
+                .add(
+                    egui::DragValue::new(&mut slot_poll_secs)
+                        .range(0..=3600)
+                        .suffix(" s"),
+                )
+                .changed()
+            {
+                config_manager.update_slot_poll_secs(slot_poll_secs);
+                config_manager.auto_save();
+            }
+            ui.end_row();
+
+            let mut background_poll_secs = config_manager.config().background_slot_poll_secs;
+            ui.label("Background refresh").on_hover_text(
+                "Seconds between slot info refreshes while the window is unfocused or \
+                 minimized; 0 pauses them",
+            );
+            if ui
+                .add(
+                    egui::DragValue::new(&mut background_poll_secs)
+                        .range(0..=3600)
+                        .suffix(" s"),
+                )
+                .changed()
+            {
+                config_manager.update_background_slot_poll_secs(background_poll_secs);
+                config_manager.auto_save();
+            }
+            ui.end_row();
+
+            let mut stale_after_secs = config_manager.config().stale_after_secs;
+            ui.label("Stale data warning")
+                .on_hover_text("Flag tab data older than this many seconds; 0 turns it off");
+            if ui
+                .add(
+                    egui::DragValue::new(&mut stale_after_secs)
+                        .range(0..=86_400)
+                        .suffix(" s"),
+                )
+                .changed()
+            {
+                config_manager.update_stale_after_secs(stale_after_secs);
+                config_manager.auto_save();
+            }
+            ui.end_row();
+
+            let mut include_delinquent = config_manager.config().include_delinquent_validators;
+            ui.label("Delinquent validators");
+            if ui
+                .checkbox(&mut include_delinquent, "Include")
+                .on_hover_text("Fetch delinquent vote accounts as well as current ones")
+                .changed()
+            {
+                config_manager.update_include_delinquent_validators(include_delinquent);
+                config_manager.auto_save();
+            }
+            ui.end_row();
+
+            let max_validators = config_manager.config().max_validators;
+            let mut capped = max_validators.is_some();
+            let mut limit = max_validators.unwrap_or(1_000);
+            ui.label("Validator limit")
+                .on_hover_text("Keep only the top validators by activated stake after each fetch");
+            ui.horizontal(|ui| {
+                let toggled = ui.checkbox(&mut capped, "Cap at").changed();
+                let resized = ui
+                    .add_enabled(capped, egui::DragValue::new(&mut limit).range(1..=100_000))
+                    .changed();
+                if toggled || resized {
+                    config_manager.update_max_validators(capped.then_some(limit));
+                    config_manager.auto_save();
+                }
+            });
+            ui.end_row();
+
+            let max_rendered_rows = config_manager.config().max_rendered_rows;
+            let mut row_capped = max_rendered_rows.is_some();
+            let mut row_limit = max_rendered_rows.unwrap_or(500);
+            ui.label("Rendered rows").on_hover_text(
+                "Draw only the first rows of the validators and gossip tables, in the current \
+                 sort order, to keep large tables responsive on slow machines",
+            );
+            ui.horizontal(|ui| {
+                let toggled = ui.checkbox(&mut row_capped, "Cap at").changed();
+                let resized = ui
+                    .add_enabled(
+                        row_capped,
+                        egui::DragValue::new(&mut row_limit).range(10..=100_000),
+                    )
+                    .changed();
+                if toggled || resized {
+                    config_manager.update_max_rendered_rows(row_capped.then_some(row_limit));
+                    config_manager.auto_save();
+                }
+            });
+            ui.end_row();
+
+            let mut max_range = config_manager.config().max_find_voters_range;
+            ui.label("Find voters range")
+                .on_hover_text("Largest slot range a single find voters search may cover");
+            if ui
+                .add(
+                    egui::DragValue::new(&mut max_range)
+                        .range(1..=10_000)
+                        .suffix(" slots"),
+                )
+                .changed()
+            {
+                config_manager.update_max_find_voters_range(max_range);
+                config_manager.auto_save();
+            }
+            ui.end_row();
+
+            let mut min_version = config_manager
+                .config()
+                .min_validator_version
+                .clone()
+                .unwrap_or_default();
+            ui.label("Minimum version").on_hover_text(
+                "Flag validators below this version as outdated; leave empty to compare against \
+                 the most common version",
+            );
+            if ui
+                .add(egui::TextEdit::singleline(&mut min_version).hint_text("e.g. 2.1.14"))
+                .changed()
+            {
+                config_manager.update_min_validator_version(&min_version);
+                config_manager.auto_save();
+            }
+            ui.end_row();
+
+            let mut worker_threads = config_manager.config().runtime_worker_threads;
+            ui.label("Worker threads").on_hover_text(
+                "Async worker threads for background fetches; takes effect after a restart",
+            );
+            if ui
+                .add(egui::DragValue::new(&mut worker_threads).range(1..=64))
+                .changed()
+            {
+                config_manager.update_runtime_worker_threads(worker_threads);
+                config_manager.auto_save();
+            }
+            ui.end_row();
+
+            let mut commission_alert_notify = config_manager.config().commission_alert_notify;
+            ui.label("Commission alerts");
+            if ui
+                .checkbox(&mut commission_alert_notify, "Notify")
+                .on_hover_text(
+                    "Request attention when a watchlisted validator raises its commission",
+                )
+                .changed()
+            {
+                config_manager.update_commission_alert_notify(commission_alert_notify);
+                config_manager.auto_save();
+            }
+            ui.end_row();
+        });
+}
+
+/// Render the update download directory. Returns true when it changed, so the
+/// updater can pick it up.
+pub fn render_update_settings(ui: &mut egui::Ui, config_manager: &mut ConfigManager) -> bool {
+    let mut dir = config_manager
+        .config()
+        .download_dir
+        .as_ref()
+        .map(|dir| dir.display().to_string())
+        .unwrap_or_default();
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label("Download directory");
+        changed = ui
+            .add(egui::TextEdit::singleline(&mut dir).hint_text("System Downloads folder"))
+            .on_hover_text(
+                "Where update installers are saved; empty uses Downloads, then the temp folder",
+            )
+            .changed();
+    });
+    if changed {
+        config_manager.update_download_dir((!dir.trim().is_empty()).then(|| PathBuf::from(&dir)));
+        config_manager.auto_save();
+    }
+    changed
+}
+
+/// Render the local status endpoint settings, which apply on the next start.
+pub fn render_status_server_settings(ui: &mut egui::Ui, config_manager: &mut ConfigManager) {
+    let mut enabled = config_manager.config().status_server_enabled;
+    let mut port = config_manager.config().status_server_port;
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        changed |= ui
+            .checkbox(&mut enabled, "Serve /status on port")
+            .on_hover_text("Local JSON status document for headless monitoring")
+            .changed();
+        changed |= ui
+            .add_enabled(
+                enabled,
+                egui::DragValue::new(&mut port).range(1024..=u16::MAX),
+            )
+            .changed();
+    });
+    ui.label(egui::RichText::new("Takes effect after a restart").weak());
+    if changed {
+        config_manager.update_status_server(enabled, port);
+        config_manager.auto_save();
+    }
+}
+
+/// Render the network metrics CSV log: on/off, interval and file path.
+pub fn render_metrics_log_settings(ui: &mut egui::Ui, config_manager: &mut ConfigManager) {
+    let config = config_manager.config();
+    let mut enabled = config.metrics_log_enabled;
+    let mut interval_secs = config.metrics_log_interval_secs;
+    let mut path = config
+        .metrics_log_path
+        .as_ref()
+        .map(|path| path.display().to_string())
+        .unwrap_or_default();
+    let default_path = default_metrics_log_path().display().to_string();
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        changed |= ui
+            .checkbox(
+                &mut enabled,
+                "Append metrics after refreshes, at most every",
+            )
+            .on_hover_text(
+                "Log total stake, validator count, Nakamoto coefficient, TPS and epoch as CSV rows",
+            )
+            .changed();
+        changed |= ui
+            .add_enabled(
+                enabled,
+                egui::DragValue::new(&mut interval_secs)
+                    .range(10..=86_400)
+                    .suffix(" s"),
+            )
+            .changed();
+    });
+    ui.horizontal(|ui| {
+        ui.label("CSV file");
+        changed |= ui
+            .add_enabled(
+                enabled,
+                egui::TextEdit::singleline(&mut path).hint_text(default_path),
+            )
+            .changed();
+    });
+    if changed {
+        config_manager.update_metrics_log(
+            enabled,
+            (!path.trim().is_empty()).then(|| PathBuf::from(&path)),
+            interval_secs,
+        );
+        config_manager.auto_save();
+    }
+}
@@ -0,0 +1,293 @@
+//! Keyboard shortcut definitions shared by input handling and the help overlay.
+//!
+//! Every shortcut the application reacts to is listed in [`SHORTCUTS`], so the
+//! overlay can never drift from what `handle_keyboard_shortcuts` actually does.
+
+use eframe::egui;
+
+use crate::constants::*;
+use crate::tabs::validators::RowCursorCommand;
+use crate::tabs::AppTab;
+
+/// Action triggered by a keyboard shortcut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutAction {
+    Quit,
+    Refresh,
+    FocusSearch,
+    CheckForUpdates,
+    SwitchTab(AppTab),
+    /// Jump to the Logs tab, or back to the tab it was opened from
+    ToggleLogs,
+    ToggleShortcutHelp,
+    ToggleSettings,
+    /// Move or act on the validators table's keyboard row cursor
+    RowCursor(RowCursorCommand),
+}
+
+/// A single keyboard shortcut binding.
+#[derive(Debug, Clone, Copy)]
+pub struct Shortcut {
+    pub key: egui::Key,
+    /// Requires Cmd (macOS) or Ctrl (other platforms)
+    pub command: bool,
+    /// Requires Shift; bindings without it also fire with Shift held
+    pub shift: bool,
+    pub action: ShortcutAction,
+    pub description: &'static str,
+}
+
+impl Shortcut {
+    const fn new(
+        key: egui::Key,
+        command: bool,
+        shift: bool,
+        action: ShortcutAction,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            key,
+            command,
+            shift,
+            action,
+            description,
+        }
+    }
+
+    /// Check whether this shortcut was pressed this frame. Shift is only
+    /// required when the binding asks for it, so Cmd+Shift+R still refreshes.
+    pub fn matches(&self, input: &egui::InputState) -> bool {
+        let command = input.modifiers.mac_cmd || input.modifiers.ctrl;
+        input.key_pressed(self.key)
+            && command == self.command
+            && (!self.shift || input.modifiers.shift)
+    }
+
+    /// Human-readable key combination, e.g. "Cmd+Shift+U".
+    pub fn keys_label(&self) -> String {
+        let mut label = String::new();
+        if self.command {
+            label.push_str("Cmd+");
+        }
+        if self.shift {
+            label.push_str("Shift+");
+        }
+        label.push_str(self.key.name());
+        label
+    }
+}
+
+/// All keyboard shortcuts, in display order.
+pub const SHORTCUTS: &[Shortcut] = &[
+    Shortcut::new(
+        egui::Key::Num1,
+        true,
+        false,
+        ShortcutAction::SwitchTab(AppTab::Validators),
+        "Switch to Validators tab",
+    ),
+    Shortcut::new(
+        egui::Key::Num2,
+        true,
+        false,
+        ShortcutAction::SwitchTab(AppTab::GossipNodes),
+        "Switch to Gossip Nodes tab",
+    ),
+    Shortcut::new(
+        egui::Key::Num3,
+        true,
+        false,
+        ShortcutAction::SwitchTab(AppTab::FindVoters),
+        "Switch to Find Voters tab",
+    ),
+    Shortcut::new(
+        egui::Key::Num4,
+        true,
+        false,
+        ShortcutAction::SwitchTab(AppTab::LeaderSchedule),
+        "Switch to Leader Schedule tab",
+    ),
+    Shortcut::new(
+        egui::Key::Num5,
+        true,
+        false,
+        ShortcutAction::SwitchTab(AppTab::Network),
+        "Switch to Network tab",
+    ),
+    Shortcut::new(
+        egui::Key::Num6,
+        true,
+        false,
+        ShortcutAction::SwitchTab(AppTab::Logs),
+        "Switch to Logs tab",
+    ),
+    Shortcut::new(
+        egui::Key::Num7,
+        true,
+        false,
+        ShortcutAction::SwitchTab(AppTab::Update),
+        "Switch to Update tab",
+    ),
+    Shortcut::new(
+        egui::Key::L,
+        true,
+        false,
+        ShortcutAction::ToggleLogs,
+        "Toggle between the Logs tab and the previous tab",
+    ),
+    Shortcut::new(
+        egui::Key::R,
+        true,
+        false,
+        ShortcutAction::Refresh,
+        "Refresh the current tab",
+    ),
+    Shortcut::new(
+        egui::Key::F,
+        true,
+        false,
+        ShortcutAction::FocusSearch,
+        "Focus the search field",
+    ),
+    Shortcut::new(
+        egui::Key::U,
+        true,
+        true,
+        ShortcutAction::CheckForUpdates,
+        "Open the Update tab",
+    ),
+    Shortcut::new(
+        egui::Key::Comma,
+        true,
+        false,
+        ShortcutAction::ToggleSettings,
+        "Open or close the settings window",
+    ),
+    Shortcut::new(
+        egui::Key::Q,
+        true,
+        false,
+        ShortcutAction::Quit,
+        "Quit the application",
+    ),
+    Shortcut::new(
+        egui::Key::ArrowUp,
+        false,
+        false,
+        ShortcutAction::RowCursor(RowCursorCommand::Previous),
+        "Move to the previous validator row",
+    ),
+    Shortcut::new(
+        egui::Key::ArrowDown,
+        false,
+        false,
+        ShortcutAction::RowCursor(RowCursorCommand::Next),
+        "Move to the next validator row",
+    ),
+    Shortcut::new(
+        egui::Key::M,
+        false,
+        false,
+        ShortcutAction::RowCursor(RowCursorCommand::OpenMenu),
+        "Open the actions menu for the current validator row",
+    ),
+    Shortcut::new(
+        egui::Key::Enter,
+        false,
+        false,
+        ShortcutAction::RowCursor(RowCursorCommand::ShowDetails),
+        "Show details of the current validator row",
+    ),
+    Shortcut::new(
+        egui::Key::Questionmark,
+        false,
+        false,
+        ShortcutAction::ToggleShortcutHelp,
+        "Show or hide this overlay",
+    ),
+];
+
+/// Render the keyboard shortcut overlay window.
+pub fn render_shortcuts_overlay(ctx: &egui::Context, open: &mut bool) {
+    egui::Window::new("⌨ Keyboard Shortcuts")
+        .open(open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label("Cmd is Ctrl on Windows and Linux.");
+            ui.add_space(HEADER_SPACING_TINY);
+            egui::Grid::new("shortcuts_grid")
+                .num_columns(2)
+                .spacing([HEADER_SPACING_LARGE, CONTENT_SPACING_SMALL])
+                .striped(true)
+                .show(ui, |ui| {
+                    for shortcut in SHORTCUTS {
+                        ui.monospace(shortcut.keys_label());
+                        ui.label(shortcut.description);
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(key: egui::Key, modifiers: egui::Modifiers) -> egui::InputState {
+        let mut input = egui::InputState::default();
+        input.modifiers = modifiers;
+        input.events.push(egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        });
+        input
+    }
+
+    fn triggered(input: &egui::InputState) -> Vec<ShortcutAction> {
+        SHORTCUTS
+            .iter()
+            .filter(|shortcut| shortcut.matches(input))
+            .map(|shortcut| shortcut.action)
+            .collect()
+    }
+
+    #[test]
+    fn test_shift_only_required_when_bound() {
+        let command = egui::Modifiers::CTRL;
+        let command_shift = egui::Modifiers::CTRL | egui::Modifiers::SHIFT;
+
+        assert_eq!(
+            triggered(&input(egui::Key::R, command)),
+            [ShortcutAction::Refresh]
+        );
+        assert_eq!(
+            triggered(&input(egui::Key::R, command_shift)),
+            [ShortcutAction::Refresh]
+        );
+        assert_eq!(
+            triggered(&input(egui::Key::U, command_shift)),
+            [ShortcutAction::CheckForUpdates]
+        );
+        assert!(triggered(&input(egui::Key::U, command)).is_empty());
+        assert!(triggered(&input(egui::Key::R, egui::Modifiers::NONE)).is_empty());
+    }
+
+    #[test]
+    fn test_keys_bound_once() {
+        // A Shift binding and a plain binding on the same chord would both fire
+        for (index, shortcut) in SHORTCUTS.iter().enumerate() {
+            assert!(
+                !SHORTCUTS[index + 1..]
+                    .iter()
+                    .any(|other| other.key == shortcut.key && other.command == shortcut.command),
+                "{} is bound more than once",
+                shortcut.keys_label()
+            );
+        }
+    }
+}
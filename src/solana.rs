@@ -4,21 +4,111 @@
 //! - Data structures for validators, gossip nodes, slot voter information, and leader schedule
 //! - RPC client wrapper with async operations
 //! - Parsing and conversion from Solana RPC responses
+//! - Typed errors for RPC failures
 
-use anyhow::Result;
 use chrono::{DateTime, Local, Utc};
 use serde::{Deserialize, Serialize};
+use solana_client::client_error::{ClientError, ClientErrorKind};
 use solana_client::rpc_client::RpcClient;
 use solana_client::rpc_config::RpcBlockConfig;
 use solana_commitment_config::CommitmentConfig;
+use solana_rpc_client_api::request::RpcError;
 use solana_rpc_client_api::response::{RpcContactInfo, RpcVoteAccountInfo};
-use solana_sdk::{clock::Slot, pubkey::Pubkey};
-use solana_transaction_status::{TransactionDetails, UiTransactionEncoding};
-use std::collections::HashSet;
+use solana_sdk::{clock::Slot, epoch_schedule::EpochSchedule, pubkey::Pubkey};
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{
+    EncodedTransaction, TransactionDetails, UiAccountsList, UiTransactionEncoding,
+    UiTransactionStatusMeta,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 use crate::tabs::logs;
 
+/// JSON-RPC error codes for blocks that are skipped, pruned, or not yet available.
+const BLOCK_UNAVAILABLE_CODES: [i64; 4] = [-32001, -32004, -32007, -32009];
+
+/// Errors returned by `SolanaClient` operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolanaError {
+    /// The RPC node rejected the request with HTTP 429
+    RateLimited,
+    /// The request did not complete in time
+    Timeout,
+    /// The requested slot, block, or account does not exist
+    NotFound(String),
+    /// A response or user input could not be parsed
+    Parse(String),
+    /// The RPC node could not be reached
+    Network(String),
+    /// A find-voters slot range is larger than the configured maximum
+    RangeTooLarge { requested: u64, max: u64 },
+    /// Any other error reported by the RPC node
+    Rpc(String),
+}
+
+impl SolanaError {
+    /// Whether the same request may succeed if retried shortly.
+    pub const fn is_retryable(&self) -> bool {
+        matches!(self, Self::RateLimited | Self::Timeout | Self::Network(_))
+    }
+}
+
+impl fmt::Display for SolanaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RateLimited => write!(f, "Rate limited by the RPC node, try again shortly"),
+            Self::Timeout => write!(f, "Request to the RPC node timed out"),
+            Self::NotFound(message) => write!(f, "Not found: {}", message),
+            Self::Parse(message) => write!(f, "Parse error: {}", message),
+            Self::Network(message) => write!(f, "Network error: {}", message),
+            Self::RangeTooLarge { requested, max } => write!(
+                f,
+                "Slot range of {} slots exceeds the maximum of {} (max_find_voters_range)",
+                requested, max
+            ),
+            Self::Rpc(message) => write!(f, "RPC error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SolanaError {}
+
+impl From<ClientError> for SolanaError {
+    fn from(error: ClientError) -> Self {
+        match error.kind() {
+            ClientErrorKind::Reqwest(e) if e.is_timeout() => Self::Timeout,
+            ClientErrorKind::Reqwest(e) if e.status().is_some_and(|s| s.as_u16() == 429) => {
+                Self::RateLimited
+            }
+            ClientErrorKind::Reqwest(e) => Self::Network(e.to_string()),
+            ClientErrorKind::Io(e) if e.kind() == std::io::ErrorKind::TimedOut => Self::Timeout,
+            ClientErrorKind::Io(e) => Self::Network(e.to_string()),
+            ClientErrorKind::SerdeJson(e) => Self::Parse(e.to_string()),
+            ClientErrorKind::RpcError(RpcError::RpcResponseError { code, message, .. })
+                if BLOCK_UNAVAILABLE_CODES.contains(code) =>
+            {
+                Self::NotFound(message.clone())
+            }
+            ClientErrorKind::RpcError(RpcError::ParseError(message)) => {
+                Self::Parse(message.clone())
+            }
+            _ => Self::Rpc(error.to_string()),
+        }
+    }
+}
+
+impl From<tokio::task::JoinError> for SolanaError {
+    fn from(error: tokio::task::JoinError) -> Self {
+        Self::Rpc(format!("background task failed: {}", error))
+    }
+}
+
+/// Result type returned by `SolanaClient` operations.
+pub type Result<T> = std::result::Result<T, SolanaError>;
+
 /// Information about a Solana validator including voting and staking details.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidatorInfo {
@@ -40,8 +130,10 @@ pub struct ValidatorInfo {
     pub activated_stake: u64,
     /// Solana version string
     pub version: String,
-    /// Skip rate percentage (calculated from epoch credits)
-    pub skip_rate: f64,
+    /// Skip rate percentage (calculated from epoch credits); `None` without credits history
+    pub skip_rate: Option<f64>,
+    /// Listed among the delinquent vote accounts by the RPC node
+    pub delinquent: bool,
 }
 
 /// Information about a node in the Solana gossip network.
@@ -74,6 +166,14 @@ pub struct VoteTransactionInfo {
     pub signature: String,
 }
 
+/// A sub-request of a multi-step operation that failed while the others succeeded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartialFailure {
+    /// Which slot or call failed, e.g. "slot 1234" or "get_recent_performance_samples"
+    pub step: String,
+    pub error: String,
+}
+
 /// Results from searching for voters in a specific slot.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SlotVoterInfo {
@@ -85,6 +185,24 @@ pub struct SlotVoterInfo {
     pub vote_transactions: Vec<VoteTransactionInfo>,
     /// Total number of voters (cached for performance)
     pub total_voters: usize,
+    /// Last slot searched when the result covers a slot range
+    #[serde(default)]
+    pub end_slot: Option<u64>,
+    /// Vote instructions whose vote account sits in an address lookup table that could not be
+    /// resolved; voters may be undercounted when this is non-zero
+    #[serde(default)]
+    pub unresolved_vote_instructions: usize,
+    /// Slots of a range scan whose block could not be fetched; their voters are missing
+    #[serde(default)]
+    pub failed_slots: Vec<PartialFailure>,
+}
+
+/// Validators returned by a refresh, with the optional calls that failed along the way.
+#[derive(Debug, Clone)]
+pub struct ValidatorsFetch {
+    pub validators: Vec<ValidatorInfo>,
+    /// Failed lookups whose columns are left at their defaults, e.g. "Unknown" versions
+    pub failed_steps: Vec<PartialFailure>,
 }
 
 /// Information about a single leader slot.
@@ -113,6 +231,37 @@ pub struct LeaderScheduleInfo {
     pub total_slots: usize,
     /// Next upcoming leader slot (closest to current time)
     pub next_leader_slot: Option<LeaderSlot>,
+    /// The cluster's epoch at the time of the request
+    pub current_epoch: u64,
+    /// Whether the RPC node returned a schedule for the target epoch
+    pub schedule_published: bool,
+    /// Furthest epoch whose leader schedule is known at the time of the request
+    pub latest_published_epoch: u64,
+}
+
+/// Lamport balance of a vote account, looked up on demand for the detail drawer.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct VoteAccountBalance {
+    /// Lamports held by the vote account
+    pub lamports: u64,
+    /// Lamports the account must keep to stay rent exempt
+    pub rent_exempt_minimum: u64,
+}
+
+/// Cluster-wide figures that aren't derived from the validator list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkOverview {
+    /// Current epoch
+    pub epoch: u64,
+    /// Slots elapsed in the current epoch
+    pub slot_index: u64,
+    /// Total slots in the current epoch
+    pub slots_in_epoch: u64,
+    /// Transactions per second over the recent performance samples
+    pub tps: Option<f64>,
+    /// Optional calls that failed; the figures they feed are left empty
+    #[serde(default)]
+    pub failed_steps: Vec<PartialFailure>,
 }
 
 /// Async wrapper around Solana RPC client with caching and error handling.
@@ -120,6 +269,118 @@ pub struct LeaderScheduleInfo {
 pub struct SolanaClient {
     rpc_url: String,
     log_store: logs::LogStore,
+    commitments: BTreeMap<RpcOperation, Commitment>,
+}
+
+/// RPC operations whose commitment level can be overridden in the config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RpcOperation {
+    SlotInfo,
+    Validators,
+    FindVoters,
+    LeaderSchedule,
+}
+
+impl RpcOperation {
+    /// Commitment used when the config has no override for this operation.
+    pub const fn default_commitment(self) -> Commitment {
+        match self {
+            // Slot info favors freshness over stability
+            Self::SlotInfo => Commitment::Processed,
+            Self::Validators => Commitment::Finalized,
+            Self::FindVoters => Commitment::Confirmed,
+            Self::LeaderSchedule => Commitment::Finalized,
+        }
+    }
+
+    /// Get the display name for this operation.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::SlotInfo => "Slot info",
+            Self::Validators => "Validators",
+            Self::FindVoters => "Find voters",
+            Self::LeaderSchedule => "Leader schedule",
+        }
+    }
+
+    /// Get all configurable operations.
+    pub const fn all() -> &'static [Self] {
+        &[
+            Self::SlotInfo,
+            Self::Validators,
+            Self::FindVoters,
+            Self::LeaderSchedule,
+        ]
+    }
+
+    /// Default commitment for every configurable operation.
+    pub fn default_commitments() -> BTreeMap<Self, Commitment> {
+        Self::all()
+            .iter()
+            .map(|&operation| (operation, operation.default_commitment()))
+            .collect()
+    }
+}
+
+/// Transaction detail level requested from `getBlock` when finding voters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoterDetail {
+    /// Full transactions; vote accounts are read from each vote instruction
+    #[default]
+    Full,
+    /// Account lists only; lighter on the RPC node, one voter per vote transaction
+    Accounts,
+}
+
+impl VoterDetail {
+    /// Get the display name for this detail level.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Full => "Full transactions",
+            Self::Accounts => "Accounts only (lighter)",
+        }
+    }
+
+    /// Get all available detail levels.
+    pub const fn all() -> &'static [Self] {
+        &[Self::Full, Self::Accounts]
+    }
+}
+
+/// Commitment level requested from the RPC node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Commitment {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+impl Commitment {
+    /// Get the display name for this commitment level.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Processed => "processed",
+            Self::Confirmed => "confirmed",
+            Self::Finalized => "finalized",
+        }
+    }
+
+    /// Get all commitment levels.
+    pub const fn all() -> &'static [Self] {
+        &[Self::Processed, Self::Confirmed, Self::Finalized]
+    }
+
+    /// Convert to the Solana client commitment config.
+    pub fn to_config(self) -> CommitmentConfig {
+        match self {
+            Self::Processed => CommitmentConfig::processed(),
+            Self::Confirmed => CommitmentConfig::confirmed(),
+            Self::Finalized => CommitmentConfig::finalized(),
+        }
+    }
 }
 
 /// Vote program ID constant for efficient lookups
@@ -128,6 +389,9 @@ const VOTE_PROGRAM_ID: &str = "Vote111111111111111111111111111111111111111";
 /// Approximate slots per second for Solana network
 const SLOTS_PER_SECOND: f64 = 2.5;
 
+/// Recent performance samples (one per minute) averaged for the TPS figure
+const PERFORMANCE_SAMPLES: usize = 5;
+
 impl GossipNodeInfo {
     /// Convert from Solana RPC ContactInfo to our internal representation.
     /// Uses safe defaults for unparseable data.
@@ -148,6 +412,114 @@ impl GossipNodeInfo {
             shred_version: contact_info.shred_version,
         }
     }
+
+    /// Number of TPU, RPC and TPU QUIC addresses the node advertises.
+    fn populated_addresses(&self) -> usize {
+        [&self.tpu, &self.rpc, &self.tpu_quic]
+            .iter()
+            .filter(|addr| addr.is_some())
+            .count()
+    }
+
+    /// Merge a duplicate entry for the same pubkey into this one. The entry with more
+    /// populated addresses wins; its missing fields are filled from the other.
+    fn merge(&mut self, mut other: Self) {
+        if other.populated_addresses() > self.populated_addresses() {
+            std::mem::swap(self, &mut other);
+        }
+        if self.gossip == "Unknown" {
+            self.gossip = other.gossip;
+        }
+        self.tpu = self.tpu.take().or(other.tpu);
+        self.rpc = self.rpc.take().or(other.rpc);
+        self.tpu_quic = self.tpu_quic.take().or(other.tpu_quic);
+        self.version = self.version.take().or(other.version);
+        self.feature_set = self.feature_set.or(other.feature_set);
+        self.shred_version = self.shred_version.or(other.shred_version);
+    }
+}
+
+/// Merge the per-slot results of a range scan into one result.
+/// Skipped slots (`NotFound`) are ignored and other failures are listed in `failed_slots`;
+/// the merge only fails, with the first error, when every slot failed.
+pub fn combine_slot_results(
+    start_slot: u64,
+    end_slot: u64,
+    results: impl IntoIterator<Item = (u64, Result<SlotVoterInfo>)>,
+) -> Result<SlotVoterInfo> {
+    let mut combined = SlotVoterInfo {
+        slot: start_slot,
+        voters: HashSet::new(),
+        vote_transactions: Vec::new(),
+        total_voters: 0,
+        end_slot: Some(end_slot),
+        unresolved_vote_instructions: 0,
+        failed_slots: Vec::new(),
+    };
+    let mut attempted = 0;
+    let mut first_error = None;
+    for (slot, result) in results {
+        attempted += 1;
+        match result {
+            Ok(voter_info) => {
+                combined.voters.extend(voter_info.voters);
+                combined
+                    .vote_transactions
+                    .extend(voter_info.vote_transactions);
+                combined.unresolved_vote_instructions += voter_info.unresolved_vote_instructions;
+            }
+            // Skipped slots have no block
+            Err(SolanaError::NotFound(_)) => {}
+            Err(e) => {
+                combined.failed_slots.push(PartialFailure {
+                    step: format!("slot {}", slot),
+                    error: e.to_string(),
+                });
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    // Nothing to show when every slot failed
+    if combined.failed_slots.len() == attempted
+        && let Some(error) = first_error
+    {
+        return Err(error);
+    }
+    combined.total_voters = combined.voters.len();
+
+    Ok(combined)
+}
+
+/// Collapse gossip entries that share a pubkey into one, keeping first-seen order.
+/// Returns the unique nodes and how many duplicate entries were merged away.
+pub fn dedupe_gossip_nodes(nodes: Vec<GossipNodeInfo>) -> (Vec<GossipNodeInfo>, usize) {
+    let total = nodes.len();
+    let mut index_by_pubkey: HashMap<Pubkey, usize> = HashMap::with_capacity(total);
+    let mut unique: Vec<GossipNodeInfo> = Vec::with_capacity(total);
+    for node in nodes {
+        match index_by_pubkey.get(&node.pubkey) {
+            Some(&index) => unique[index].merge(node),
+            None => {
+                index_by_pubkey.insert(node.pubkey, unique.len());
+                unique.push(node);
+            }
+        }
+    }
+    let merged = total - unique.len();
+    (unique, merged)
+}
+
+/// Set each validator's version from the gossip entry of its identity, when it advertises one.
+pub fn apply_gossip_versions(validators: &mut [ValidatorInfo], nodes: &[GossipNodeInfo]) {
+    let versions: HashMap<Pubkey, &str> = nodes
+        .iter()
+        .filter_map(|node| Some((node.pubkey, node.version.as_deref()?)))
+        .collect();
+    for validator in validators {
+        if let Some(version) = versions.get(&validator.identity) {
+            validator.version = (*version).to_string();
+        }
+    }
 }
 
 impl ValidatorInfo {
@@ -170,12 +542,14 @@ impl ValidatorInfo {
             activated_stake: vote_account.activated_stake,
             version: "Unknown".to_string(),
             skip_rate,
+            delinquent: false,
         }
     }
 
     /// Calculate skip rate from epoch credits data.
-    /// Returns (total_credits, skip_rate_percentage).
-    fn calculate_skip_rate(epoch_credits: &[(u64, u64, u64)]) -> (u64, f64) {
+    /// Returns (total_credits, skip_rate_percentage); the skip rate is `None` for
+    /// vote accounts without any epoch credits yet, such as newly created ones.
+    fn calculate_skip_rate(epoch_credits: &[(u64, u64, u64)]) -> (u64, Option<f64>) {
         match epoch_credits.last() {
             Some(latest_epoch) => {
                 let credits = latest_epoch.1;
@@ -186,20 +560,51 @@ impl ValidatorInfo {
                     let slots_voted = credits - prev_credits;
                     let vote_rate = slots_voted as f64 / SLOTS_PER_EPOCH;
                     let skip_rate = (1.0 - vote_rate.min(1.0)) * 100.0;
-                    (credits, skip_rate.max(0.0))
+                    (credits, Some(skip_rate.max(0.0)))
                 } else {
-                    (credits, 0.0)
+                    (credits, Some(0.0))
                 }
             }
-            None => (0, 0.0),
+            None => (0, None),
         }
     }
 }
 
 impl SolanaClient {
     /// Create a new Solana RPC client wrapper.
-    pub fn new(rpc_url: String, log_store: logs::LogStore) -> Self {
-        Self { rpc_url, log_store }
+    pub fn new(
+        rpc_url: String,
+        log_store: logs::LogStore,
+        commitments: BTreeMap<RpcOperation, Commitment>,
+    ) -> Self {
+        Self {
+            rpc_url,
+            log_store,
+            commitments,
+        }
+    }
+
+    /// RPC endpoint this client talks to.
+    pub fn rpc_url(&self) -> &str {
+        &self.rpc_url
+    }
+
+    /// Log store this client records its requests in.
+    pub fn log_store(&self) -> &logs::LogStore {
+        &self.log_store
+    }
+
+    /// Replace the per-operation commitment overrides.
+    pub fn set_commitments(&mut self, commitments: BTreeMap<RpcOperation, Commitment>) {
+        self.commitments = commitments;
+    }
+
+    /// Commitment for an operation, falling back to its default when not overridden.
+    fn commitment(&self, operation: RpcOperation) -> Commitment {
+        self.commitments
+            .get(&operation)
+            .copied()
+            .unwrap_or_else(|| operation.default_commitment())
     }
 
     /// Fetch current slot information and epoch data.
@@ -207,16 +612,19 @@ impl SolanaClient {
     pub async fn fetch_slot_info(&self) -> Result<(Slot, Slot, u64)> {
         let rpc_url = self.rpc_url.clone();
         let log_store = self.log_store.clone();
+        let commitment = self.commitment(RpcOperation::SlotInfo);
 
         logs::log_request(
             &log_store,
             "get_slot + get_epoch_info",
             &rpc_url,
-            &format!("endpoint: {}", rpc_url),
+            &format!("endpoint: {}, commitment: {}", rpc_url, commitment.name()),
         );
 
+        let started = Instant::now();
+
         let result: Result<(Slot, Slot, u64)> = tokio::task::spawn_blocking(move || {
-            let client = RpcClient::new(rpc_url);
+            let client = RpcClient::new_with_commitment(rpc_url, commitment.to_config());
             let current_slot = client.get_slot()?;
             let epoch_info = client.get_epoch_info()?;
 
@@ -232,6 +640,7 @@ impl SolanaClient {
                     &self.rpc_url,
                     &format!("current: {}, latest: {}, epoch: {}", current, latest, epoch),
                     "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
                 );
             }
             Err(e) => {
@@ -247,26 +656,85 @@ impl SolanaClient {
         result
     }
 
-    /// Fetch all current validators from the network.
-    pub async fn fetch_validators(&self) -> Result<Vec<ValidatorInfo>> {
+    /// Fetch the latest slot the cluster has finalized, whatever the configured commitments.
+    pub async fn fetch_finalized_slot(&self) -> Result<Slot> {
         let rpc_url = self.rpc_url.clone();
         let log_store = self.log_store.clone();
 
+        logs::log_request(
+            &log_store,
+            "get_slot",
+            &rpc_url,
+            &format!("endpoint: {}, commitment: finalized", rpc_url),
+        );
+
+        let started = Instant::now();
+
+        let result: Result<Slot> = tokio::task::spawn_blocking(move || {
+            let client = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::finalized());
+            Ok(client.get_slot()?)
+        })
+        .await?;
+
+        match &result {
+            Ok(slot) => {
+                logs::log_response(
+                    &log_store,
+                    "get_slot",
+                    &self.rpc_url,
+                    &format!("Finalized slot: {}", slot),
+                    "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
+                );
+            }
+            Err(e) => {
+                logs::log_error(&log_store, "get_slot", &self.rpc_url, &e.to_string());
+            }
+        }
+
+        result
+    }
+
+    /// Fetch current validators from the network, plus delinquent ones when requested.
+    /// Versions come from a gossip lookup; when it fails the validators are still
+    /// returned, with "Unknown" versions and the failure in `failed_steps`.
+    pub async fn fetch_validators(&self, include_delinquent: bool) -> Result<ValidatorsFetch> {
+        let rpc_url = self.rpc_url.clone();
+        let log_store = self.log_store.clone();
+        let commitment = self.commitment(RpcOperation::Validators);
+
         logs::log_request(
             &log_store,
             "get_vote_accounts",
             &rpc_url,
-            &format!("endpoint: {}", rpc_url),
+            &format!("endpoint: {}, commitment: {}", rpc_url, commitment.name()),
         );
 
+        let started = Instant::now();
+
         let result: Result<Vec<ValidatorInfo>> = tokio::task::spawn_blocking(move || {
-            let client = RpcClient::new(rpc_url);
+            let client = RpcClient::new_with_commitment(rpc_url, commitment.to_config());
             let vote_accounts = client.get_vote_accounts()?;
 
+            let delinquent_accounts = if include_delinquent {
+                vote_accounts.delinquent
+            } else {
+                Vec::new()
+            };
             Ok(vote_accounts
                 .current
                 .into_iter()
-                .map(ValidatorInfo::from_rpc_vote_account)
+                .map(|vote_account| (vote_account, false))
+                .chain(
+                    delinquent_accounts
+                        .into_iter()
+                        .map(|vote_account| (vote_account, true)),
+                )
+                .map(|(vote_account, delinquent)| {
+                    let mut validator = ValidatorInfo::from_rpc_vote_account(vote_account);
+                    validator.delinquent = delinquent;
+                    validator
+                })
                 .collect::<Vec<_>>())
         })
         .await?;
@@ -279,6 +747,7 @@ impl SolanaClient {
                     &self.rpc_url,
                     &format!("Found {} validators", validators.len()),
                     "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
                 );
             }
             Err(e) => {
@@ -290,8 +759,22 @@ impl SolanaClient {
                 );
             }
         }
+        let mut validators = result?;
+
+        // Vote accounts carry no version; take it from the identity's gossip entry
+        let mut failed_steps = Vec::new();
+        match self.fetch_cluster_nodes().await {
+            Ok(nodes) => apply_gossip_versions(&mut validators, &nodes),
+            Err(e) => failed_steps.push(PartialFailure {
+                step: "get_cluster_nodes".to_string(),
+                error: e.to_string(),
+            }),
+        }
 
-        result
+        Ok(ValidatorsFetch {
+            validators,
+            failed_steps,
+        })
     }
 
     /// Fetch all nodes in the gossip network.
@@ -306,25 +789,38 @@ impl SolanaClient {
             &format!("endpoint: {}", rpc_url),
         );
 
-        let result: Result<Vec<GossipNodeInfo>> = tokio::task::spawn_blocking(move || {
+        let started = Instant::now();
+
+        let result: Result<(_, _)> = tokio::task::spawn_blocking(move || {
             let client = RpcClient::new(rpc_url);
             let cluster_nodes = client.get_cluster_nodes()?;
 
-            Ok(cluster_nodes
+            let nodes = cluster_nodes
                 .into_iter()
                 .map(GossipNodeInfo::from_rpc_contact_info)
-                .collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            Ok(dedupe_gossip_nodes(nodes))
         })
         .await?;
 
         match &result {
-            Ok(nodes) => {
+            Ok((nodes, merged)) => {
+                let summary = if *merged > 0 {
+                    format!(
+                        "Found {} gossip nodes ({} duplicate entries merged)",
+                        nodes.len(),
+                        merged
+                    )
+                } else {
+                    format!("Found {} gossip nodes", nodes.len())
+                };
                 logs::log_response(
                     &log_store,
                     "get_cluster_nodes",
                     &self.rpc_url,
-                    &format!("Found {} gossip nodes", nodes.len()),
+                    &summary,
                     "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
                 );
             }
             Err(e) => {
@@ -337,55 +833,444 @@ impl SolanaClient {
             }
         }
 
+        result.map(|(nodes, _)| nodes)
+    }
+
+    /// Fetch the total token supply in lamports.
+    pub async fn fetch_total_supply(&self) -> Result<u64> {
+        let rpc_url = self.rpc_url.clone();
+        let log_store = self.log_store.clone();
+        let commitment = self.commitment(RpcOperation::Validators);
+
+        logs::log_request(
+            &log_store,
+            "get_supply",
+            &rpc_url,
+            &format!("endpoint: {}, commitment: {}", rpc_url, commitment.name()),
+        );
+
+        let started = Instant::now();
+
+        let result: Result<u64> = tokio::task::spawn_blocking(move || {
+            let client = RpcClient::new_with_commitment(rpc_url, commitment.to_config());
+            Ok(client.supply()?.value.total)
+        })
+        .await?;
+
+        match &result {
+            Ok(total) => {
+                logs::log_response(
+                    &log_store,
+                    "get_supply",
+                    &self.rpc_url,
+                    &format!("Total supply: {} lamports", total),
+                    "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
+                );
+            }
+            Err(e) => {
+                logs::log_error(&log_store, "get_supply", &self.rpc_url, &e.to_string());
+            }
+        }
+
+        result
+    }
+
+    /// Fetch a vote account's balance and rent-exempt minimum via `getAccountInfo`.
+    pub async fn fetch_vote_account_balance(
+        &self,
+        vote_account: Pubkey,
+    ) -> Result<VoteAccountBalance> {
+        let rpc_url = self.rpc_url.clone();
+        let log_store = self.log_store.clone();
+        let commitment = self.commitment(RpcOperation::Validators);
+
+        logs::log_request(
+            &log_store,
+            "get_account_info",
+            &rpc_url,
+            &format!(
+                "endpoint: {}, account: {}, commitment: {}",
+                rpc_url,
+                vote_account,
+                commitment.name()
+            ),
+        );
+
+        let started = Instant::now();
+
+        let account_rpc_url = rpc_url.clone();
+        let account = tokio::task::spawn_blocking(move || {
+            let client = RpcClient::new_with_commitment(account_rpc_url, commitment.to_config());
+            client.get_account(&vote_account)
+        })
+        .await?;
+
+        let account = match account {
+            Ok(account) => {
+                logs::log_response(
+                    &log_store,
+                    "get_account_info",
+                    &rpc_url,
+                    &format!(
+                        "{} lamports, {} bytes of data",
+                        account.lamports,
+                        account.data.len()
+                    ),
+                    "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
+                );
+                account
+            }
+            Err(e) => {
+                let e = SolanaError::from(e);
+                logs::log_error(&log_store, "get_account_info", &rpc_url, &e.to_string());
+                return Err(e);
+            }
+        };
+
+        // The drawer shows the balance against what the account must keep
+        let data_len = account.data.len();
+        logs::log_request(
+            &log_store,
+            "get_minimum_balance_for_rent_exemption",
+            &rpc_url,
+            &format!("endpoint: {}, data length: {}", rpc_url, data_len),
+        );
+
+        let started = Instant::now();
+
+        let rent_rpc_url = rpc_url.clone();
+        let rent_exempt_minimum = tokio::task::spawn_blocking(move || {
+            let client = RpcClient::new_with_commitment(rent_rpc_url, commitment.to_config());
+            client.get_minimum_balance_for_rent_exemption(data_len)
+        })
+        .await?;
+
+        match rent_exempt_minimum {
+            Ok(rent_exempt_minimum) => {
+                logs::log_response(
+                    &log_store,
+                    "get_minimum_balance_for_rent_exemption",
+                    &rpc_url,
+                    &format!("{} lamports", rent_exempt_minimum),
+                    "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
+                );
+                Ok(VoteAccountBalance {
+                    lamports: account.lamports,
+                    rent_exempt_minimum,
+                })
+            }
+            Err(e) => {
+                let e = SolanaError::from(e);
+                logs::log_error(
+                    &log_store,
+                    "get_minimum_balance_for_rent_exemption",
+                    &rpc_url,
+                    &e.to_string(),
+                );
+                Err(e)
+            }
+        }
+    }
+
+    /// Fetch epoch progress and recent throughput for the network overview.
+    pub async fn fetch_network_overview(&self) -> Result<NetworkOverview> {
+        let rpc_url = self.rpc_url.clone();
+        let log_store = self.log_store.clone();
+        let commitment = self.commitment(RpcOperation::SlotInfo);
+
+        logs::log_request(
+            &log_store,
+            "get_epoch_info + get_recent_performance_samples",
+            &rpc_url,
+            &format!("endpoint: {}, commitment: {}", rpc_url, commitment.name()),
+        );
+
+        let started = Instant::now();
+
+        let result: Result<NetworkOverview> = tokio::task::spawn_blocking(move || {
+            let client = RpcClient::new_with_commitment(rpc_url, commitment.to_config());
+            let epoch_info = client.get_epoch_info()?;
+
+            // TPS is optional; keep the epoch figures when the samples can't be fetched
+            let mut failed_steps = Vec::new();
+            let samples = client
+                .get_recent_performance_samples(Some(PERFORMANCE_SAMPLES))
+                .unwrap_or_else(|e| {
+                    failed_steps.push(PartialFailure {
+                        step: "get_recent_performance_samples".to_string(),
+                        error: e.to_string(),
+                    });
+                    Vec::new()
+                });
+
+            let transactions: u64 = samples.iter().map(|s| s.num_transactions).sum();
+            let seconds: u64 = samples
+                .iter()
+                .map(|s| u64::from(s.sample_period_secs))
+                .sum();
+            Ok(NetworkOverview {
+                epoch: epoch_info.epoch,
+                slot_index: epoch_info.slot_index,
+                slots_in_epoch: epoch_info.slots_in_epoch,
+                tps: (seconds > 0).then(|| transactions as f64 / seconds as f64),
+                failed_steps,
+            })
+        })
+        .await?;
+
+        match &result {
+            Ok(overview) => {
+                logs::log_response(
+                    &log_store,
+                    "get_epoch_info + get_recent_performance_samples",
+                    &self.rpc_url,
+                    &format!(
+                        "Epoch {} at slot {}/{}",
+                        overview.epoch, overview.slot_index, overview.slots_in_epoch
+                    ),
+                    "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
+                );
+                for failure in &overview.failed_steps {
+                    logs::log_error(&log_store, &failure.step, &self.rpc_url, &failure.error);
+                }
+            }
+            Err(e) => {
+                logs::log_error(
+                    &log_store,
+                    "get_epoch_info + get_recent_performance_samples",
+                    &self.rpc_url,
+                    &e.to_string(),
+                );
+            }
+        }
+
+        result
+    }
+
+    /// Fetch the `solana-core` version of the RPC node.
+    pub async fn fetch_version(&self) -> Result<String> {
+        let rpc_url = self.rpc_url.clone();
+        let log_store = self.log_store.clone();
+
+        logs::log_request(
+            &log_store,
+            "get_version",
+            &rpc_url,
+            &format!("endpoint: {}", rpc_url),
+        );
+
+        let started = Instant::now();
+
+        let result: Result<String> = tokio::task::spawn_blocking(move || {
+            let client = RpcClient::new(rpc_url);
+            Ok(client.get_version()?.solana_core)
+        })
+        .await?;
+
+        match &result {
+            Ok(version) => {
+                logs::log_response(
+                    &log_store,
+                    "get_version",
+                    &self.rpc_url,
+                    &format!("solana-core {}", version),
+                    "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
+                );
+            }
+            Err(e) => {
+                logs::log_error(&log_store, "get_version", &self.rpc_url, &e.to_string());
+            }
+        }
+
+        result
+    }
+
+    /// Check that the endpoint is healthy and return its `solana-core` version.
+    pub async fn test_connection(&self) -> Result<String> {
+        let rpc_url = self.rpc_url.clone();
+        let log_store = self.log_store.clone();
+
+        logs::log_request(
+            &log_store,
+            "get_health + get_version",
+            &rpc_url,
+            &format!("endpoint: {}", rpc_url),
+        );
+
+        let started = Instant::now();
+
+        let result: Result<String> = tokio::task::spawn_blocking(move || {
+            let client = RpcClient::new(rpc_url);
+            client.get_health()?;
+            Ok(client.get_version()?.solana_core)
+        })
+        .await?;
+
+        match &result {
+            Ok(version) => {
+                logs::log_response(
+                    &log_store,
+                    "get_health + get_version",
+                    &self.rpc_url,
+                    &format!("healthy, version {}", version),
+                    "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
+                );
+            }
+            Err(e) => {
+                logs::log_error(
+                    &log_store,
+                    "get_health + get_version",
+                    &self.rpc_url,
+                    &e.to_string(),
+                );
+            }
+        }
+
+        result
+    }
+
+    /// Run `getHealth` then `getSlot`, giving up after `timeout`.
+    /// Returns the slot and the round-trip time of both calls.
+    pub async fn ping(&self, timeout: Duration) -> Result<(Slot, Duration)> {
+        let rpc_url = self.rpc_url.clone();
+        let log_store = self.log_store.clone();
+        let commitment = self.commitment(RpcOperation::SlotInfo);
+
+        logs::log_request(
+            &log_store,
+            "get_health + get_slot",
+            &rpc_url,
+            &format!("endpoint: {}, timeout: {}s", rpc_url, timeout.as_secs()),
+        );
+
+        let task = tokio::task::spawn_blocking(move || {
+            let client = RpcClient::new_with_timeout_and_commitment(
+                rpc_url,
+                timeout,
+                commitment.to_config(),
+            );
+            let started = Instant::now();
+            client.get_health()?;
+            let slot = client.get_slot()?;
+            Ok((slot, started.elapsed()))
+        });
+        let result: Result<(Slot, Duration)> = match tokio::time::timeout(timeout, task).await {
+            Ok(joined) => joined?,
+            Err(_) => Err(SolanaError::Timeout),
+        };
+
+        match &result {
+            Ok((slot, latency)) => {
+                logs::log_response(
+                    &log_store,
+                    "get_health + get_slot",
+                    &self.rpc_url,
+                    &format!("healthy, slot {}", slot),
+                    "200 OK",
+                    Some(latency.as_millis() as u64),
+                );
+            }
+            Err(e) => {
+                logs::log_error(
+                    &log_store,
+                    "get_health + get_slot",
+                    &self.rpc_url,
+                    &e.to_string(),
+                );
+            }
+        }
+
         result
     }
 
     /// Find all vote accounts that voted in a specific slot.
     /// Analyzes all transactions in the block to identify voting activity.
-    pub async fn find_voters_in_slot(&self, slot: u64) -> Result<SlotVoterInfo> {
+    pub async fn find_voters_in_slot(
+        &self,
+        slot: u64,
+        detail: VoterDetail,
+    ) -> Result<SlotVoterInfo> {
         let rpc_url = self.rpc_url.clone();
         let log_store = self.log_store.clone();
+        // get_block rejects processed commitment, so fall back to confirmed
+        let commitment = match self.commitment(RpcOperation::FindVoters) {
+            Commitment::Processed => Commitment::Confirmed,
+            commitment => commitment,
+        };
 
         logs::log_request(
             &log_store,
             "get_block",
             &rpc_url,
-            &format!("slot: {}", slot),
+            &format!(
+                "slot: {}, commitment: {}, detail: {}",
+                slot,
+                commitment.name(),
+                detail.name()
+            ),
         );
 
+        let started = Instant::now();
+
         let result: Result<SlotVoterInfo> = tokio::task::spawn_blocking(move || {
-            let client = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed());
+            let client = RpcClient::new_with_commitment(rpc_url, commitment.to_config());
 
+            let (encoding, transaction_details) = match detail {
+                VoterDetail::Full => (UiTransactionEncoding::Base64, TransactionDetails::Full),
+                VoterDetail::Accounts => {
+                    (UiTransactionEncoding::Json, TransactionDetails::Accounts)
+                }
+            };
             let config = RpcBlockConfig {
-                encoding: Some(UiTransactionEncoding::Base64),
-                transaction_details: Some(TransactionDetails::Full),
+                encoding: Some(encoding),
+                transaction_details: Some(transaction_details),
                 rewards: Some(false),
-                commitment: Some(CommitmentConfig::confirmed()),
+                commitment: Some(commitment.to_config()),
                 max_supported_transaction_version: Some(0),
             };
 
             let block = client.get_block_with_config(slot, config)?;
-            let vote_program_id = Pubkey::from_str(VOTE_PROGRAM_ID)?;
+            let vote_program_id =
+                Pubkey::from_str(VOTE_PROGRAM_ID).map_err(|e| SolanaError::Parse(e.to_string()))?;
             let mut voters = HashSet::new();
             let mut vote_transactions = Vec::new();
+            let mut unresolved_vote_instructions = 0;
 
             if let Some(transactions) = block.transactions {
                 for encoded_transaction in transactions {
-                    if let Some(tx_with_meta) = encoded_transaction.transaction.decode() {
+                    if let EncodedTransaction::Accounts(accounts_list) =
+                        &encoded_transaction.transaction
+                    {
+                        Self::extract_voter_from_accounts_list(
+                            accounts_list,
+                            &mut voters,
+                            &mut vote_transactions,
+                        );
+                    } else if let Some(tx_with_meta) = encoded_transaction.transaction.decode() {
                         // Get the first signature from the transaction's signatures
                         let signature = tx_with_meta
                             .signatures
                             .first()
                             .map(|sig| sig.to_string())
                             .unwrap_or_else(|| "unknown".to_string());
-
-                        Self::extract_voters_from_versioned_transaction_with_signature(
-                            &tx_with_meta,
-                            &vote_program_id,
-                            &mut voters,
-                            &mut vote_transactions,
-                            &signature,
-                        );
+                        let loaded_addresses =
+                            Self::loaded_addresses(encoded_transaction.meta.as_ref());
+
+                        unresolved_vote_instructions +=
+                            Self::extract_voters_from_versioned_transaction_with_signature(
+                                &tx_with_meta,
+                                &loaded_addresses,
+                                &vote_program_id,
+                                &mut voters,
+                                &mut vote_transactions,
+                                &signature,
+                            );
                     }
                 }
             }
@@ -396,6 +1281,9 @@ impl SolanaClient {
                 voters,
                 vote_transactions,
                 total_voters,
+                end_slot: None,
+                unresolved_vote_instructions,
+                failed_slots: Vec::new(),
             })
         })
         .await?;
@@ -407,10 +1295,13 @@ impl SolanaClient {
                     "get_block",
                     &self.rpc_url,
                     &format!(
-                        "Found {} voters in slot {}",
-                        voter_info.total_voters, voter_info.slot
+                        "Found {} voters in slot {} ({} unresolved lookup-table votes)",
+                        voter_info.total_voters,
+                        voter_info.slot,
+                        voter_info.unresolved_vote_instructions
                     ),
                     "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
                 );
             }
             Err(e) => {
@@ -421,34 +1312,124 @@ impl SolanaClient {
         result
     }
 
+    /// Extract the voter from a transaction fetched with `TransactionDetails::Accounts`.
+    /// Without instructions, the vote account is taken to be the first writable
+    /// non-signer, which is where vote transactions place it after the authority.
+    fn extract_voter_from_accounts_list(
+        accounts_list: &UiAccountsList,
+        voters: &mut HashSet<String>,
+        vote_transactions: &mut Vec<VoteTransactionInfo>,
+    ) {
+        let keys = &accounts_list.account_keys;
+        if !keys.iter().any(|account| account.pubkey == VOTE_PROGRAM_ID) {
+            return;
+        }
+
+        if let Some(vote_account) = keys
+            .iter()
+            .find(|account| account.writable && !account.signer)
+        {
+            voters.insert(vote_account.pubkey.clone());
+            vote_transactions.push(VoteTransactionInfo {
+                vote_account: vote_account.pubkey.clone(),
+                signature: accounts_list
+                    .signatures
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| "unknown".to_string()),
+            });
+        }
+    }
+
+    /// Find all vote accounts that voted in an inclusive slot range.
+    /// Skipped slots are ignored; ranges longer than `max_range` are rejected.
+    /// Slots that fail are listed in `failed_slots` and the scan carries on; it
+    /// only fails when no slot could be fetched at all.
+    pub async fn find_voters_in_slot_range(
+        &self,
+        start_slot: u64,
+        end_slot: u64,
+        max_range: u64,
+        detail: VoterDetail,
+    ) -> Result<SlotVoterInfo> {
+        let requested = crate::utils::slot_range_len(start_slot, end_slot).unwrap_or(u64::MAX);
+        if requested > max_range {
+            let error = SolanaError::RangeTooLarge {
+                requested,
+                max: max_range,
+            };
+            logs::log_error(
+                &self.log_store,
+                "get_block",
+                &self.rpc_url,
+                &error.to_string(),
+            );
+            return Err(error);
+        }
+
+        let mut results = Vec::new();
+        for slot in start_slot..=end_slot {
+            results.push((slot, self.find_voters_in_slot(slot, detail).await));
+        }
+        combine_slot_results(start_slot, end_slot, results)
+    }
+
+    /// Addresses loaded from lookup tables by a v0 transaction, writable first then readonly.
+    /// Empty for legacy transactions or when the meta is unavailable.
+    fn loaded_addresses(meta: Option<&UiTransactionStatusMeta>) -> Vec<Pubkey> {
+        match meta.map(|meta| &meta.loaded_addresses) {
+            Some(OptionSerializer::Some(loaded)) => loaded
+                .writable
+                .iter()
+                .chain(loaded.readonly.iter())
+                .filter_map(|address| Pubkey::from_str(address).ok())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
     /// Extract vote account addresses and transaction signatures from a single versioned transaction.
+    /// Account indexes past the static keys are resolved through `loaded_addresses`.
+    /// Returns the number of vote instructions whose vote account could not be resolved.
     fn extract_voters_from_versioned_transaction_with_signature(
         versioned_tx: &solana_sdk::transaction::VersionedTransaction,
+        loaded_addresses: &[Pubkey],
         vote_program_id: &Pubkey,
         voters: &mut HashSet<String>,
         vote_transactions: &mut Vec<VoteTransactionInfo>,
         signature: &str,
-    ) {
+    ) -> usize {
         let account_keys = versioned_tx.message.static_account_keys();
+        let mut unresolved = 0;
 
         for instruction in versioned_tx.message.instructions() {
             let program_id_index = instruction.program_id_index as usize;
 
+            // Program ids are always static keys, even in v0 transactions
             if program_id_index < account_keys.len()
                 && account_keys[program_id_index] == *vote_program_id
                 && !instruction.accounts.is_empty()
             {
                 let vote_account_index = instruction.accounts[0] as usize;
-                if vote_account_index < account_keys.len() {
-                    let vote_account = account_keys[vote_account_index].to_string();
-                    voters.insert(vote_account.clone());
-                    vote_transactions.push(VoteTransactionInfo {
-                        vote_account,
-                        signature: signature.to_string(),
-                    });
+                let vote_account = account_keys
+                    .get(vote_account_index)
+                    .or_else(|| loaded_addresses.get(vote_account_index - account_keys.len()));
+
+                match vote_account {
+                    Some(vote_account) => {
+                        let vote_account = vote_account.to_string();
+                        voters.insert(vote_account.clone());
+                        vote_transactions.push(VoteTransactionInfo {
+                            vote_account,
+                            signature: signature.to_string(),
+                        });
+                    }
+                    None => unresolved += 1,
                 }
             }
         }
+
+        unresolved
     }
 
     /// Fetch leader schedule for a specific validator identity.
@@ -461,24 +1442,35 @@ impl SolanaClient {
         let rpc_url = self.rpc_url.clone();
         let identity_clone = identity.to_string();
         let log_store = self.log_store.clone();
+        let commitment = self.commitment(RpcOperation::LeaderSchedule);
 
         logs::log_request(
             &log_store,
             "get_leader_schedule",
             &rpc_url,
-            &format!("identity: {}, epoch: {:?}", identity, target_epoch),
+            &format!(
+                "identity: {}, epoch: {:?}, commitment: {}",
+                identity,
+                target_epoch,
+                commitment.name()
+            ),
         );
 
+        let started = Instant::now();
+
         let result: Result<LeaderScheduleInfo> = tokio::task::spawn_blocking(move || {
-            let client = RpcClient::new(rpc_url);
+            let client = RpcClient::new_with_commitment(rpc_url, commitment.to_config());
 
             // Parse validator identity
-            let validator_pubkey = Pubkey::from_str(&identity_clone)?;
+            let validator_pubkey = Pubkey::from_str(&identity_clone).map_err(|e| {
+                SolanaError::Parse(format!("invalid identity {}: {}", identity_clone, e))
+            })?;
 
-            // Get current network state
-            let current_slot = client.get_slot()?;
-            let current_timestamp = Utc::now().timestamp();
+            // Get current network state. The slot comes from the same call as the
+            // epoch, since short epochs can roll over between two requests
             let epoch_info = client.get_epoch_info()?;
+            let current_slot = epoch_info.absolute_slot;
+            let current_timestamp = Utc::now().timestamp();
 
             let epoch_to_fetch = target_epoch.unwrap_or(epoch_info.epoch);
 
@@ -491,22 +1483,33 @@ impl SolanaClient {
                 epoch_info.epoch,
                 epoch_info.absolute_slot,
                 epoch_info.slot_index,
-                epoch_schedule.slots_per_epoch,
-                epoch_schedule.first_normal_slot,
+                &epoch_schedule,
             );
 
-            // Get leader schedule
-            let leader_schedule = if target_epoch.is_some() {
-                client.get_leader_schedule(Some(epoch_to_fetch))?
-            } else {
-                client.get_leader_schedule(None)?
+            // Schedules are only computed about one epoch ahead, so don't ask for later ones
+            let latest_published_epoch =
+                epoch_schedule.get_leader_schedule_epoch(epoch_info.absolute_slot);
+            let unpublished = LeaderScheduleInfo {
+                validator_identity: identity_clone.clone(),
+                target_epoch: epoch_to_fetch,
+                leader_slots: Vec::new(),
+                total_slots: 0,
+                next_leader_slot: None,
+                current_epoch: epoch_info.epoch,
+                schedule_published: false,
+                latest_published_epoch,
             };
+            if epoch_to_fetch > latest_published_epoch {
+                return Ok(unpublished);
+            }
+
+            // The RPC takes a slot and returns the schedule of the epoch containing it
+            let leader_schedule = client.get_leader_schedule(Some(epoch_start_slot))?;
 
             match leader_schedule {
                 Some(schedule) => {
                     if let Some(slots) = schedule.get(&validator_pubkey.to_string()) {
                         let mut leader_slots = Vec::new();
-                        let mut next_leader_slot = None;
 
                         for &relative_slot in slots {
                             let absolute_slot = epoch_start_slot + relative_slot as u64;
@@ -516,9 +1519,10 @@ impl SolanaClient {
                                 current_slot,
                                 current_timestamp,
                             );
-                            let slot_timestamp = time_local.timestamp();
-                            let time_diff =
-                                Self::format_time_difference(current_timestamp, slot_timestamp);
+                            let time_diff = Self::format_time_difference(
+                                current_timestamp,
+                                time_local.timestamp(),
+                            );
 
                             let leader_slot = LeaderSlot {
                                 epoch: epoch_to_fetch,
@@ -527,17 +1531,19 @@ impl SolanaClient {
                                 time_diff,
                             };
 
-                            // Track next upcoming slot
-                            if slot_timestamp > current_timestamp && next_leader_slot.is_none() {
-                                next_leader_slot = Some(leader_slot.clone());
-                            }
-
                             leader_slots.push(leader_slot);
                         }
 
                         // Sort by slot number
                         leader_slots.sort_by_key(|slot| slot.slot);
 
+                        // Compare slots rather than projected times, which can't tell
+                        // apart slots less than a second away
+                        let next_leader_slot = leader_slots
+                            .iter()
+                            .find(|slot| slot.slot > current_slot)
+                            .cloned();
+
                         let total_slots = leader_slots.len();
 
                         Ok(LeaderScheduleInfo {
@@ -546,39 +1552,50 @@ impl SolanaClient {
                             leader_slots,
                             total_slots,
                             next_leader_slot,
+                            current_epoch: epoch_info.epoch,
+                            schedule_published: true,
+                            latest_published_epoch,
                         })
                     } else {
+                        // Schedule exists, but this identity has no slots in it
                         Ok(LeaderScheduleInfo {
                             validator_identity: identity_clone,
                             target_epoch: epoch_to_fetch,
                             leader_slots: Vec::new(),
                             total_slots: 0,
                             next_leader_slot: None,
+                            current_epoch: epoch_info.epoch,
+                            schedule_published: true,
+                            latest_published_epoch,
                         })
                     }
                 }
-                None => Ok(LeaderScheduleInfo {
-                    validator_identity: identity_clone,
-                    target_epoch: epoch_to_fetch,
-                    leader_slots: Vec::new(),
-                    total_slots: 0,
-                    next_leader_slot: None,
-                }),
+                // No schedule for this epoch (typically not yet published)
+                None => Ok(unpublished),
             }
         })
         .await?;
 
         match &result {
             Ok(schedule) => {
+                let summary = if schedule.schedule_published {
+                    format!(
+                        "Found {} leader slots for {} in epoch {}",
+                        schedule.total_slots, schedule.validator_identity, schedule.target_epoch
+                    )
+                } else {
+                    format!(
+                        "Leader schedule for epoch {} not yet published (latest is epoch {})",
+                        schedule.target_epoch, schedule.latest_published_epoch
+                    )
+                };
                 logs::log_response(
                     &log_store,
                     "get_leader_schedule",
                     &self.rpc_url,
-                    &format!(
-                        "Found {} leader slots for {} in epoch {}",
-                        schedule.total_slots, schedule.validator_identity, schedule.target_epoch
-                    ),
+                    &summary,
                     "200 OK",
+                    Some(started.elapsed().as_millis() as u64),
                 );
             }
             Err(e) => {
@@ -595,25 +1612,20 @@ impl SolanaClient {
     }
 
     /// Calculate the starting slot for a given epoch.
+    /// Defers to the cluster's epoch schedule so warmup and non-default
+    /// slots-per-epoch (e.g. `solana-test-validator`) are handled.
     fn calculate_epoch_start_slot(
         target_epoch: u64,
         current_epoch: u64,
         current_absolute_slot: u64,
         current_slot_index: u64,
-        slots_per_epoch: u64,
-        first_normal_slot: u64,
+        epoch_schedule: &EpochSchedule,
     ) -> u64 {
         if target_epoch == current_epoch {
             // For current epoch, calculate from current position
-            current_absolute_slot - current_slot_index
-        } else if target_epoch == 0 {
-            0
-        } else if target_epoch * slots_per_epoch < first_normal_slot {
-            // Very early epochs during warmup period
-            target_epoch * slots_per_epoch / 2
+            current_absolute_slot.saturating_sub(current_slot_index)
         } else {
-            // Normal epochs after warmup
-            first_normal_slot + (target_epoch.saturating_sub(1)) * slots_per_epoch
+            epoch_schedule.get_first_slot_in_epoch(target_epoch)
         }
     }
 
@@ -626,46 +1638,213 @@ impl SolanaClient {
     ) -> DateTime<Local> {
         let slot_duration_secs = 1.0 / slots_per_second;
         let slot_diff = slot as i64 - current_slot as i64;
-        let timestamp = current_timestamp + (slot_diff as f64 * slot_duration_secs) as i64;
+        // Round so slots a fraction of a second away land on the nearest second
+        let timestamp = current_timestamp + (slot_diff as f64 * slot_duration_secs).round() as i64;
 
         let utc_time = DateTime::from_timestamp(timestamp, 0).unwrap_or_else(Utc::now);
         utc_time.with_timezone(&Local)
     }
 
     /// Format a time difference in a human-readable way.
+    ///
+    /// Shows the largest non-zero unit together with the next one down, truncated
+    /// (e.g. "2d 23h", "1h 0m", "59s"). Past targets get an "ago" suffix.
     pub fn format_time_difference(current_timestamp: i64, target_timestamp: i64) -> String {
         let diff = target_timestamp - current_timestamp;
-        let abs_diff = diff.abs();
+        let abs_diff = diff.unsigned_abs();
 
         let days = abs_diff / 86400;
         let hours = (abs_diff % 86400) / 3600;
         let minutes = (abs_diff % 3600) / 60;
         let seconds = abs_diff % 60;
 
-        let mut parts = Vec::new();
+        let formatted = if days > 0 {
+            format!("{}d {}h", days, hours)
+        } else if hours > 0 {
+            format!("{}h {}m", hours, minutes)
+        } else if minutes > 0 {
+            format!("{}m {}s", minutes, seconds)
+        } else if seconds > 0 {
+            format!("{}s", seconds)
+        } else {
+            return "now".to_string();
+        };
 
-        if days > 0 {
-            parts.push(format!("{}d", days));
-        }
-        if hours > 0 {
-            parts.push(format!("{}h", hours));
-        }
-        if minutes > 0 {
-            parts.push(format!("{}m", minutes));
+        if diff < 0 {
+            format!("{} ago", formatted)
+        } else {
+            formatted
         }
-        if seconds > 0 && days == 0 && hours == 0 {
-            parts.push(format!("{}s", seconds));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_epoch_start_slot_small_epochs() {
+        // Warmup epochs of 32 and 64 slots, then 128-slot epochs from slot 96
+        let schedule = EpochSchedule::custom(128, 128, true);
+        let start = |epoch| SolanaClient::calculate_epoch_start_slot(epoch, 2, 100, 4, &schedule);
+        assert_eq!(start(0), 0);
+        assert_eq!(start(1), 32);
+        assert_eq!(start(2), 96);
+        assert_eq!(start(3), 224);
+
+        // solana-test-validator style tiny epochs without warmup
+        let schedule = EpochSchedule::custom(32, 32, false);
+        assert_eq!(
+            SolanaClient::calculate_epoch_start_slot(4, 4, 140, 12, &schedule),
+            128
+        );
+        assert_eq!(
+            SolanaClient::calculate_epoch_start_slot(5, 4, 140, 12, &schedule),
+            160
+        );
+    }
+
+    #[test]
+    fn test_slot_to_timestamp_local_rounds_nearby_slots() {
+        let at = |slot| {
+            SolanaClient::slot_to_timestamp_local(slot, SLOTS_PER_SECOND, 1_000, 10_000).timestamp()
+        };
+        assert_eq!(at(1_000), 10_000);
+        assert_eq!(at(1_002), 10_001);
+        assert_eq!(at(998), 9_999);
+        assert_eq!(at(1_025), 10_010);
+    }
+
+    #[test]
+    fn test_format_time_difference_boundaries() {
+        assert_eq!(SolanaClient::format_time_difference(1_000, 1_000), "now");
+        assert_eq!(SolanaClient::format_time_difference(0, 59), "59s");
+        assert_eq!(SolanaClient::format_time_difference(0, 60), "1m 0s");
+        assert_eq!(SolanaClient::format_time_difference(0, 3_599), "59m 59s");
+        assert_eq!(SolanaClient::format_time_difference(0, 3_600), "1h 0m");
+        assert_eq!(SolanaClient::format_time_difference(0, 86_400), "1d 0h");
+    }
+
+    #[test]
+    fn test_format_time_difference_keeps_next_unit() {
+        // 2 days, 23 hours, 59 minutes: minutes are truncated, hours kept
+        let target = 2 * 86_400 + 23 * 3_600 + 59 * 60;
+        assert_eq!(SolanaClient::format_time_difference(0, target), "2d 23h");
+        // 2 days and 5 minutes still names the empty hours unit
+        assert_eq!(
+            SolanaClient::format_time_difference(0, 2 * 86_400 + 300),
+            "2d 0h"
+        );
+    }
+
+    #[test]
+    fn test_format_time_difference_past_and_future() {
+        assert_eq!(SolanaClient::format_time_difference(100, 41), "59s ago");
+        assert_eq!(SolanaClient::format_time_difference(7_200, 0), "2h 0m ago");
+        assert_eq!(SolanaClient::format_time_difference(0, 90), "1m 30s");
+    }
+
+    #[test]
+    fn test_calculate_skip_rate_without_history() {
+        assert_eq!(ValidatorInfo::calculate_skip_rate(&[]), (0, None));
+        let (credits, skip_rate) = ValidatorInfo::calculate_skip_rate(&[(5, 432_000, 0)]);
+        assert_eq!(credits, 432_000);
+        assert_eq!(skip_rate, Some(0.0));
+    }
+
+    fn gossip_node(pubkey: Pubkey, tpu: Option<&str>, rpc: Option<&str>) -> GossipNodeInfo {
+        GossipNodeInfo {
+            pubkey,
+            gossip: "Unknown".to_string(),
+            tpu: tpu.map(str::to_string),
+            rpc: rpc.map(str::to_string),
+            tpu_quic: None,
+            version: None,
+            feature_set: None,
+            shred_version: None,
         }
+    }
 
-        if parts.is_empty() {
-            "now".to_string()
-        } else {
-            let formatted = parts.join(" ");
-            if diff < 0 {
-                format!("{} ago", formatted)
-            } else {
-                formatted
-            }
+    #[test]
+    fn test_dedupe_gossip_nodes_merges_by_pubkey() {
+        let duplicated = Pubkey::new_from_array([1; 32]);
+        let other = Pubkey::new_from_array([2; 32]);
+        let mut sparse = gossip_node(duplicated, None, Some("10.0.0.1:8899"));
+        sparse.version = Some("2.2.0".to_string());
+        sparse.gossip = "10.0.0.1:8001".to_string();
+        let full = gossip_node(duplicated, Some("10.0.0.2:8003"), Some("10.0.0.2:8899"));
+
+        let (nodes, merged) =
+            dedupe_gossip_nodes(vec![sparse, gossip_node(other, None, None), full]);
+
+        assert_eq!(merged, 1);
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0].pubkey, duplicated);
+        assert_eq!(nodes[1].pubkey, other);
+        // Addresses come from the fuller entry; gaps are filled from the sparse one
+        assert_eq!(nodes[0].tpu.as_deref(), Some("10.0.0.2:8003"));
+        assert_eq!(nodes[0].rpc.as_deref(), Some("10.0.0.2:8899"));
+        assert_eq!(nodes[0].version.as_deref(), Some("2.2.0"));
+        assert_eq!(nodes[0].gossip, "10.0.0.1:8001");
+    }
+
+    fn slot_voters(slot: u64, voters: &[&str]) -> SlotVoterInfo {
+        SlotVoterInfo {
+            slot,
+            voters: voters.iter().map(|voter| voter.to_string()).collect(),
+            vote_transactions: Vec::new(),
+            total_voters: voters.len(),
+            end_slot: None,
+            unresolved_vote_instructions: 1,
+            failed_slots: Vec::new(),
         }
     }
+
+    #[test]
+    fn test_combine_slot_results_keeps_partial_results() {
+        let combined = combine_slot_results(
+            10,
+            13,
+            vec![
+                (10, Ok(slot_voters(10, &["Vote1", "Vote2"]))),
+                (11, Err(SolanaError::NotFound("slot 11".to_string()))),
+                (12, Err(SolanaError::Timeout)),
+                (13, Ok(slot_voters(13, &["Vote2", "Vote3"]))),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!((combined.slot, combined.end_slot), (10, Some(13)));
+        assert_eq!(combined.total_voters, 3);
+        assert_eq!(combined.unresolved_vote_instructions, 2);
+        // Skipped slots are not failures
+        assert_eq!(combined.failed_slots.len(), 1);
+        assert_eq!(combined.failed_slots[0].step, "slot 12");
+    }
+
+    #[test]
+    fn test_combine_slot_results_fails_when_every_slot_failed() {
+        let result = combine_slot_results(
+            10,
+            11,
+            vec![
+                (10, Err(SolanaError::Timeout)),
+                (11, Err(SolanaError::RateLimited)),
+            ],
+        );
+        assert_eq!(result.unwrap_err(), SolanaError::Timeout);
+
+        // A range of skipped slots is empty, not an error
+        let combined = combine_slot_results(
+            10,
+            11,
+            vec![
+                (10, Err(SolanaError::NotFound("slot 10".to_string()))),
+                (11, Err(SolanaError::NotFound("slot 11".to_string()))),
+            ],
+        )
+        .unwrap();
+        assert_eq!(combined.total_voters, 0);
+        assert!(combined.failed_slots.is_empty());
+    }
 }
@@ -0,0 +1,133 @@
+//! Optional local HTTP endpoint exposing application status as JSON.
+//!
+//! This module provides:
+//! - A shared status record updated by the background fetch tasks
+//! - A minimal HTTP/1.1 server answering `GET /status` for headless monitoring
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+
+use crate::constants::STATUS_SERVER_ACCEPT_BACKOFF_MS;
+use crate::solana::ValidatorInfo;
+use crate::tabs::logs;
+use crate::ui::SlotInfo;
+
+/// Refresh bookkeeping shared between the UI, fetch tasks and the status server.
+#[derive(Debug, Clone, Default)]
+pub struct StatusInfo {
+    /// Display name of the active cluster
+    pub cluster: String,
+    /// Time of the last successful validators refresh
+    pub last_refresh: Option<DateTime<Local>>,
+    /// Most recent validators, slot, gossip or voters fetch error, cleared on the next
+    /// successful validators refresh
+    pub last_error: Option<String>,
+}
+
+/// JSON document served by the status endpoint.
+#[derive(Debug, Serialize)]
+struct StatusReport {
+    cluster: String,
+    validator_count: usize,
+    current_slot: Option<u64>,
+    latest_slot: Option<u64>,
+    epoch: Option<u64>,
+    last_refresh: Option<String>,
+    error: Option<String>,
+}
+
+/// Data sources read by the status server on each request.
+#[derive(Clone)]
+pub struct StatusServerState {
+    pub validators: Arc<Mutex<Vec<ValidatorInfo>>>,
+    pub slot_info: Arc<Mutex<SlotInfo>>,
+    pub status_info: Arc<Mutex<StatusInfo>>,
+}
+
+/// Bind to localhost on the given port and serve status requests until the runtime shuts down.
+/// Each request reports on whatever sources `state` holds at that moment.
+pub async fn serve(port: u16, state: Arc<Mutex<StatusServerState>>, log_store: logs::LogStore) {
+    let address = format!("127.0.0.1:{}", port);
+    let listener = match TcpListener::bind(&address).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            logs::log_error(&log_store, "status_server", &address, &e.to_string());
+            return;
+        }
+    };
+
+    logs::log_response(
+        &log_store,
+        "status_server",
+        &address,
+        &format!("Serving status at http://{}/status", address),
+        "Listening",
+        None,
+    );
+
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                // Errors such as running out of file descriptors persist, so don't spin on them
+                logs::log_error(&log_store, "status_server", &address, &e.to_string());
+                tokio::time::sleep(Duration::from_millis(STATUS_SERVER_ACCEPT_BACKOFF_MS)).await;
+                continue;
+            }
+        };
+        let state = state.lock().await.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &state).await {
+                eprintln!("Status server connection error: {}", e);
+            }
+        });
+    }
+}
+
+/// Answer a single HTTP request and close the connection.
+async fn handle_connection(mut stream: TcpStream, state: &StatusServerState) -> Result<()> {
+    let mut buffer = [0u8; 1024];
+    let read = stream.read(&mut buffer).await?;
+    let request = String::from_utf8_lossy(&buffer[..read]);
+    let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+    let (status_line, body) = if path == "/" || path == "/status" {
+        let report = build_report(state).await;
+        ("200 OK", serde_json::to_string_pretty(&report)?)
+    } else {
+        ("404 Not Found", r#"{"error":"not found"}"#.to_string())
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status_line,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Collect the latest fetched data into a status report.
+async fn build_report(state: &StatusServerState) -> StatusReport {
+    let validator_count = state.validators.lock().await.len();
+    let (current_slot, latest_slot, epoch) = *state.slot_info.lock().await;
+    let status_info = state.status_info.lock().await.clone();
+
+    StatusReport {
+        cluster: status_info.cluster,
+        validator_count,
+        current_slot,
+        latest_slot,
+        epoch,
+        last_refresh: status_info.last_refresh.map(|time| time.to_rfc3339()),
+        error: status_info.last_error,
+    }
+}
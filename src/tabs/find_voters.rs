@@ -2,37 +2,303 @@
 
 use eframe::egui;
 use egui_extras::{Column, TableBuilder};
-use std::collections::{HashMap, HashSet};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
 
+use crate::config::AliasStore;
 use crate::constants::*;
-use crate::solana::SlotVoterInfo;
-use crate::utils::{create_cell_frame, create_error_frame, render_search_field};
+use crate::solana::{SlotVoterInfo, VoteTransactionInfo, VoterDetail};
+use crate::utils::{
+    create_cell_frame, find_voters_cli_command, icon_text, palette_color, parse_slot_range,
+    pubkey_column_width, pubkey_search_key, record_table_rect, refresh_button, render_error_frame,
+    render_partial_failures, render_pubkey, render_recent_searches, render_search_field,
+    slot_range_len, DisplaySettings, SortDirection, StatusColor,
+};
+
+/// A precomputed row of the voters table.
+#[derive(Debug, Clone)]
+struct VoterRow {
+    /// Position of the transaction in the block, before filtering and sorting
+    block_index: usize,
+    vote_account: String,
+    signature: String,
+    /// Alternating color group of the row's vote account
+    color_index: usize,
+}
+
+/// Column the voters table is sorted by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum VoterSortColumn {
+    /// Order the transactions appear in the block
+    BlockOrder,
+    #[default]
+    VoteAccount,
+    Signature,
+}
+
+/// Active sort of the voters table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct VoterSort {
+    column: VoterSortColumn,
+    direction: SortDirection,
+}
+
+impl Default for VoterSort {
+    fn default() -> Self {
+        Self {
+            column: VoterSortColumn::default(),
+            direction: SortDirection::Ascending,
+        }
+    }
+}
+
+impl VoterSort {
+    /// Sort by `column`, flipping the direction when it is already active.
+    fn toggle(&mut self, column: VoterSortColumn) {
+        if self.column == column {
+            self.direction = match self.direction {
+                SortDirection::Ascending => SortDirection::Descending,
+                SortDirection::Descending => SortDirection::Ascending,
+            };
+        } else {
+            *self = Self {
+                column,
+                direction: SortDirection::Ascending,
+            };
+        }
+    }
+
+    /// Arrow shown after the header of the active column.
+    fn indicator(&self, column: VoterSortColumn) -> &'static str {
+        match self.direction {
+            _ if self.column != column => "",
+            SortDirection::Ascending => " ▲",
+            SortDirection::Descending => " ▼",
+        }
+    }
+}
+
+/// Where the shown voter result came from, telling apart results that cover
+/// the same slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VoterSource {
+    /// Number of the find voters fetch that stored it
+    Fetch(u64),
+    /// Generation of the rolling window
+    Rolling(u64),
+}
+
+/// Filtered, sorted and grouped voter rows cached between frames.
+///
+/// Rebuilt only when the result, the filter or the sort changes, so large slots
+/// with thousands of vote transactions don't re-sort and re-group every frame.
+#[derive(Default)]
+pub struct VotersTableCache {
+    key: Option<(VoterSource, u64, Option<u64>, String, bool, VoterSort)>,
+    rows: Vec<VoterRow>,
+    unique_voters: usize,
+    /// Sort chosen from the table headers
+    sort: VoterSort,
+}
+
+impl VotersTableCache {
+    /// Rebuild the cached rows if the result, search term, case matching or sort changed.
+    fn refresh(
+        &mut self,
+        source: VoterSource,
+        result: &SlotVoterInfo,
+        search_term: &str,
+        case_sensitive: bool,
+    ) {
+        let key = (
+            source,
+            result.slot,
+            result.end_slot,
+            search_term.to_string(),
+            case_sensitive,
+            self.sort,
+        );
+        if self.key.as_ref() == Some(&key) {
+            return;
+        }
+
+        let search_key = pubkey_search_key(search_term, case_sensitive);
+        let mut rows: Vec<VoterRow> = result
+            .vote_transactions
+            .iter()
+            .enumerate()
+            .filter(|(_, vote_tx)| vote_transaction_matches(vote_tx, &search_key, case_sensitive))
+            .map(|(block_index, vote_tx)| VoterRow {
+                block_index,
+                vote_account: vote_tx.vote_account.clone(),
+                signature: vote_tx.signature.clone(),
+                color_index: 0,
+            })
+            .collect();
+
+        // Stable sorts keep block order within equal keys
+        match self.sort.column {
+            VoterSortColumn::BlockOrder => {}
+            VoterSortColumn::VoteAccount => {
+                rows.sort_by(|a, b| a.vote_account.cmp(&b.vote_account))
+            }
+            VoterSortColumn::Signature => rows.sort_by(|a, b| a.signature.cmp(&b.signature)),
+        }
+        if self.sort.direction == SortDirection::Descending {
+            rows.reverse();
+        }
+
+        // Alternate colors between runs of the same vote account in display order
+        let mut color_index = 1;
+        let mut previous_account: Option<&str> = None;
+        for row in &mut rows {
+            if previous_account != Some(row.vote_account.as_str()) {
+                color_index = 1 - color_index;
+            }
+            row.color_index = color_index;
+            previous_account = Some(row.vote_account.as_str());
+        }
+
+        let unique_voters = rows
+            .iter()
+            .map(|row| row.vote_account.as_str())
+            .collect::<HashSet<_>>()
+            .len();
+
+        self.key = Some(key);
+        self.rows = rows;
+        self.unique_voters = unique_voters;
+    }
+}
+
+/// Voters seen across the most recent finalized blocks, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct RollingVoters {
+    blocks: VecDeque<SlotVoterInfo>,
+    /// The window merged into one result, rebuilt only when a block comes or goes
+    combined: Option<SlotVoterInfo>,
+    /// Bumped each time `combined` changes
+    generation: u64,
+}
+
+impl RollingVoters {
+    /// Whether the window already holds this slot or a newer one.
+    pub fn has_scanned(&self, slot: u64) -> bool {
+        self.blocks.back().is_some_and(|block| block.slot >= slot)
+    }
+
+    /// Append a newly scanned block, dropping the oldest beyond the window size.
+    pub fn push(&mut self, block: SlotVoterInfo) {
+        if self.has_scanned(block.slot) {
+            return;
+        }
+        self.blocks.push_back(block);
+        while self.blocks.len() > ROLLING_VOTERS_WINDOW {
+            self.blocks.pop_front();
+        }
+        self.combined = self.combine();
+        self.generation += 1;
+    }
+
+    /// Forget all scanned blocks.
+    pub fn clear(&mut self) {
+        self.blocks.clear();
+        self.combined = None;
+        self.generation += 1;
+    }
+
+    /// First and last slot in the window.
+    fn slot_range(&self) -> Option<(u64, u64)> {
+        Some((self.blocks.front()?.slot, self.blocks.back()?.slot))
+    }
+
+    /// The window merged into a single result keyed by its latest slot.
+    fn combined(&self) -> Option<&SlotVoterInfo> {
+        self.combined.as_ref()
+    }
+
+    /// Merge the window into a single result keyed by its latest slot.
+    fn combine(&self) -> Option<SlotVoterInfo> {
+        let latest = self.blocks.back()?;
+        let mut combined = SlotVoterInfo {
+            slot: latest.slot,
+            voters: Default::default(),
+            vote_transactions: Vec::new(),
+            total_voters: 0,
+            end_slot: None,
+            unresolved_vote_instructions: 0,
+            failed_slots: Vec::new(),
+        };
+        for block in &self.blocks {
+            combined.voters.extend(block.voters.iter().cloned());
+            combined
+                .vote_transactions
+                .extend(block.vote_transactions.iter().cloned());
+            combined.unresolved_vote_instructions += block.unresolved_vote_instructions;
+        }
+        combined.total_voters = combined.voters.len();
+        Some(combined)
+    }
+}
 
 /// Parameters for the find voters tab rendering.
 pub struct FindVotersTabParams<'a> {
     pub slot_search: &'a mut String,
+    /// Recently searched slots and ranges, newest first
+    pub recent_slot_searches: &'a [String],
+    /// Latest slot known from the status bar's slot info
+    pub latest_slot: Option<u64>,
     pub voter_result: &'a Option<SlotVoterInfo>,
+    /// Number of the fetch that stored `voter_result`
+    pub voter_fetch: u64,
     pub search_term: &'a mut String,
-    pub error_message: &'a Option<String>,
+    /// Why this tab's last fetch failed
+    pub error_message: Option<&'a str>,
     pub is_loading: bool,
     pub should_focus_search: bool,
+    pub table_cache: &'a mut VotersTableCache,
+    /// Watch the latest finalized blocks instead of a single slot
+    pub rolling_mode: &'a mut bool,
+    /// Rolling window, unless a scan holds its lock this frame
+    pub rolling_voters: Option<&'a RollingVoters>,
+    /// Largest slot range a single search may cover
+    pub max_range: u64,
+    pub voter_detail: &'a mut VoterDetail,
+    /// RPC endpoint of the active session, for the CLI command
+    pub rpc_url: &'a str,
+    /// Vote account to give a yes/no answer for once results are in
+    pub target_vote_account: &'a mut String,
+    /// Friendly names shown in place of pubkeys
+    pub aliases: &'a AliasStore,
 }
 
 /// Render the find voters tab content.
 pub fn render_find_voters_tab(
     ui: &mut egui::Ui,
     params: FindVotersTabParams,
-    mut on_search_voters: impl FnMut(u64),
+    mut on_search_voters: impl FnMut(u64, u64),
     mut on_clear: impl FnMut(),
     mut on_search_change: impl FnMut(),
 ) {
     let FindVotersTabParams {
         slot_search,
+        recent_slot_searches,
+        latest_slot,
         voter_result,
+        voter_fetch,
         search_term,
         error_message,
         is_loading,
         should_focus_search,
+        table_cache,
+        rolling_mode,
+        rolling_voters,
+        max_range,
+        voter_detail,
+        rpc_url,
+        target_vote_account,
+        aliases,
     } = params;
     // Header with inline search controls
     ui.horizontal(|ui| {
@@ -40,7 +306,7 @@ pub fn render_find_voters_tab(
         ui.add_space(HEADER_SPACING_LARGE);
 
         // Search bar near headline for filtering results
-        ui.label("🔍 Filter:");
+        ui.label(icon_text(ui.ctx(), "🔍", "Filter:"));
         ui.add_space(CONTENT_SPACING_SMALL);
         let _search_response = render_search_field(
             ui,
@@ -65,92 +331,229 @@ pub fn render_find_voters_tab(
 
     frame.show(ui, |ui| {
         ui.horizontal(|ui| {
-            ui.label("🔍 Slot Number:");
+            ui.checkbox(
+                rolling_mode,
+                format!("Rolling: last {} blocks", ROLLING_VOTERS_WINDOW),
+            )
+            .on_hover_text("Continuously scan the latest finalized blocks");
+            ui.add_space(16.0);
+
+            let slot_enabled = !*rolling_mode;
+            let slot_range = parse_slot_range(slot_search);
+            let within_limit = slot_range.is_none_or(|(start, end)| {
+                slot_range_len(start, end).is_some_and(|len| len <= max_range)
+            });
+            ui.label(icon_text(ui.ctx(), "🔍", "Slot Number:"));
             ui.add_space(8.0);
             let slot_response = ui
-                .add_sized(
-                    [BUTTON_FIELD_WIDTH, SEARCH_FIELD_HEIGHT],
-                    egui::TextEdit::singleline(slot_search).hint_text("Enter slot..."),
+                .add_enabled_ui(slot_enabled, |ui| {
+                    ui.add_sized(
+                        [BUTTON_FIELD_WIDTH, SEARCH_FIELD_HEIGHT],
+                        egui::TextEdit::singleline(slot_search).hint_text("Slot or start-end"),
+                    )
+                })
+                .inner
+                .on_hover_text("Enter a slot number or an inclusive range such as 1000-1010");
+            let picked = ui
+                .add_enabled_ui(slot_enabled, |ui| {
+                    render_recent_searches(ui, slot_search, recent_slot_searches)
+                })
+                .inner;
+            let use_latest = ui
+                .add_enabled(
+                    slot_enabled && latest_slot.is_some(),
+                    egui::Button::new("Use latest"),
                 )
-                .on_hover_text("Enter a slot number to find voters");
+                .on_hover_text("Fill in the latest known slot")
+                .clicked();
+            if use_latest && let Some(slot) = latest_slot {
+                *slot_search = slot.to_string();
+            }
 
             ui.add_space(16.0);
-            if ui
-                .button("🔍 Search Voters")
-                .on_hover_text("Search for voters in slot (Cmd+R / Ctrl+R)")
-                .clicked()
-                || ui.input(|i| i.key_pressed(egui::Key::Enter) && !slot_search.is_empty())
+            let disabled_reason = if !slot_enabled {
+                Some("Turn off rolling mode to search specific slots")
+            } else if !within_limit {
+                Some("The slot range is larger than max_find_voters_range")
+            } else {
+                None
+            };
+            let search_enabled = disabled_reason.is_none();
+            let search_clicked = refresh_button(
+                ui,
+                is_loading,
+                disabled_reason,
+                icon_text(ui.ctx(), "🔍", "Search Voters"),
+                "Search for voters in slot (Cmd+R / Ctrl+R)",
+            )
+            .clicked();
+            if (search_clicked
+                || (search_enabled
+                    && !is_loading
+                    && ui.input(|i| i.key_pressed(egui::Key::Enter) && !slot_search.is_empty())))
+                && let Some((start_slot, end_slot)) = slot_range
             {
-                if let Ok(slot) = slot_search.parse::<u64>() {
-                    on_search_voters(slot);
-                }
+                on_search_voters(start_slot, end_slot);
             }
 
             ui.add_space(8.0);
-            if ui.button("🗑 Clear").clicked() {
+            if ui.button(icon_text(ui.ctx(), "🗑", "Clear")).clicked() {
                 on_clear();
             }
+            if ui
+                .add_enabled(
+                    slot_enabled && slot_range.is_some(),
+                    egui::Button::new(icon_text(ui.ctx(), "📋", "Copy CLI")),
+                )
+                .on_hover_text("Copy the equivalent solana CLI command")
+                .clicked()
+                && let Some((start_slot, end_slot)) = slot_range
+            {
+                ui.ctx()
+                    .copy_text(find_voters_cli_command(rpc_url, start_slot, end_slot));
+            }
+
+            ui.add_space(16.0);
+            egui::ComboBox::from_label("Detail")
+                .selected_text(voter_detail.name())
+                .show_ui(ui, |ui| {
+                    for &detail in VoterDetail::all() {
+                        ui.selectable_value(voter_detail, detail, detail.name());
+                    }
+                })
+                .response
+                .on_hover_text(
+                    "Accounts only is cheaper for the RPC node but infers one voter per \
+                     transaction instead of reading each vote instruction",
+                );
 
             // Save if slot search changed
-            if slot_response.changed() {
+            if slot_response.changed() || picked || use_latest {
                 on_search_change();
             }
         });
+
+        ui.horizontal(|ui| {
+            ui.label(icon_text(ui.ctx(), "🎯", "Check vote account:"));
+            ui.add_space(8.0);
+            ui.add_sized(
+                [pubkey_column_width(ui.ctx()), SEARCH_FIELD_HEIGHT],
+                egui::TextEdit::singleline(target_vote_account).hint_text("Optional"),
+            )
+            .on_hover_text("Answer whether this vote account voted in the searched slots");
+        });
+
+        let range_len = parse_slot_range(slot_search)
+            .filter(|_| !*rolling_mode)
+            .map(|(start_slot, end_slot)| slot_range_len(start_slot, end_slot));
+        if let Some(len) = range_len
+            && len.is_none_or(|len| len > max_range)
+        {
+            let covered = len.map_or_else(|| "over u64::MAX".to_string(), |len| len.to_string());
+            ui.colored_label(
+                WARNING_COLOR,
+                format!(
+                    "⚠ Range covers {} slots; the maximum is {} (max_find_voters_range)",
+                    covered, max_range
+                ),
+            );
+        }
     });
     ui.add_space(HEADER_SPACING_SMALL);
 
     if let Some(error) = error_message {
-        let frame = create_error_frame();
-
-        frame.show(ui, |ui| {
-            ui.colored_label(egui::Color32::RED, format!("❌ Error: {}", error));
-        });
+        render_error_frame(ui, "Find voters", error);
         ui.add_space(HEADER_SPACING_MEDIUM);
     }
 
     // Display results
-    if let Some(result) = voter_result {
-        // Apply filtering to vote transactions
-        let filtered_vote_transactions =
-            filter_vote_transactions(&result.vote_transactions, search_term);
+    let rolling_voters = rolling_voters.filter(|_| *rolling_mode);
+    let voter_result = match rolling_voters {
+        Some(rolling_voters) => rolling_voters
+            .combined()
+            .map(|result| (VoterSource::Rolling(rolling_voters.generation), result)),
+        None if *rolling_mode => None,
+        None => voter_result
+            .as_ref()
+            .map(|result| (VoterSource::Fetch(voter_fetch), result)),
+    };
+
+    if let Some((source, result)) = voter_result {
+        let case_sensitive = DisplaySettings::get(ui.ctx()).case_sensitive_pubkey_search;
+        table_cache.refresh(source, result, search_term, case_sensitive);
+
+        let rolling_scope = rolling_voters.and_then(|rolling| {
+            let (first, last) = rolling.slot_range()?;
+            Some(format!(
+                "Slots {}–{} ({} blocks)",
+                first,
+                last,
+                rolling.blocks.len()
+            ))
+        });
+        let scope = rolling_scope.unwrap_or_else(|| match result.end_slot {
+            Some(end_slot) if end_slot != result.slot => {
+                format!("Slots {}–{}", result.slot, end_slot)
+            }
+            _ => format!("Slot {}", result.slot),
+        });
 
         // Display results info with filtering status
         ui.horizontal(|ui| {
             if search_term.is_empty() {
                 ui.label(format!(
-                    "📊 Slot {}: Found {} voters",
-                    result.slot, result.total_voters
+                    "📊 {}: Found {} voters ({} vote transactions)",
+                    scope,
+                    result.total_voters,
+                    result.vote_transactions.len()
                 ));
             } else {
                 ui.label(format!(
-                    "📊 Slot {}: Showing {} of {} voters (filtered)",
-                    result.slot,
-                    filtered_vote_transactions.len(),
-                    result.total_voters
+                    "📊 {}: Showing {} of {} voters ({} of {} vote transactions, filtered)",
+                    scope,
+                    table_cache.unique_voters,
+                    result.total_voters,
+                    table_cache.rows.len(),
+                    result.vote_transactions.len()
                 ));
             }
         });
 
-        if !filtered_vote_transactions.is_empty() {
-            // Create filtered voters set from transactions
-            let filtered_voters: HashSet<String> = filtered_vote_transactions
-                .iter()
-                .map(|tx| tx.vote_account.clone())
-                .collect();
-
-            // Create filtered result
-            let filtered_result = SlotVoterInfo {
-                slot: result.slot,
-                voters: filtered_voters,
-                vote_transactions: filtered_vote_transactions,
-                total_voters: result.total_voters,
-            };
-            render_voters_table(ui, &filtered_result);
+        if result.unresolved_vote_instructions > 0 {
+            ui.colored_label(
+                WARNING_COLOR,
+                format!(
+                    "⚠ {} vote instructions reference lookup-table accounts that could not be resolved; voters may be undercounted",
+                    result.unresolved_vote_instructions
+                ),
+            );
+        }
+        render_partial_failures(
+            ui,
+            "find_voters_failed_slots",
+            &format!(
+                "{} slots could not be fetched and their voters are missing",
+                result.failed_slots.len()
+            ),
+            &result.failed_slots,
+        );
+
+        let target = target_vote_account.trim();
+        if !target.is_empty() {
+            render_target_verdict(ui, result, target, &scope);
+        }
+
+        if !table_cache.rows.is_empty() {
+            record_table_rect(ui, |ui| {
+                render_voters_table(ui, &table_cache.rows, aliases, &mut table_cache.sort);
+            });
         } else if search_term.is_empty() {
             ui.label("No voters found in this slot.");
         } else {
             ui.label(format!("No voters match the search term '{}'", search_term));
         }
+    } else if *rolling_mode {
+        ui.label("Waiting for the next finalized block...");
     } else if !is_loading && !slot_search.is_empty() {
         ui.label("Enter a slot number and click 'Search Voters' to find voters.");
     } else if is_loading {
@@ -158,109 +561,200 @@ pub fn render_find_voters_tab(
     }
 }
 
-/// Filter vote transactions based on search term.
-fn filter_vote_transactions(
-    vote_transactions: &[crate::solana::VoteTransactionInfo],
-    search_term: &str,
-) -> Vec<crate::solana::VoteTransactionInfo> {
-    if search_term.is_empty() {
-        return vote_transactions.to_vec();
+/// Render a prominent yes/no answer for whether `target` voted in the result's slots.
+fn render_target_verdict(ui: &mut egui::Ui, result: &SlotVoterInfo, target: &str, scope: &str) {
+    if Pubkey::from_str(target).is_err() {
+        ui.colored_label(
+            WARNING_COLOR,
+            format!("⚠ '{}' is not a valid vote account", target),
+        );
+        return;
     }
 
-    let search_lower = search_term.to_lowercase();
-    vote_transactions
+    let case_sensitive = DisplaySettings::get(ui.ctx()).case_sensitive_pubkey_search;
+    let target_key = pubkey_search_key(target, case_sensitive);
+    let voted = result
+        .voters
         .iter()
-        .filter(|tx| {
-            // Search in vote account and transaction signature
-            tx.vote_account.to_lowercase().contains(&search_lower)
-                || tx.signature.to_lowercase().contains(&search_lower)
-        })
-        .cloned()
-        .collect()
-}
-
-/// Render the voters table with transaction signatures and alternating colors per vote account.
-fn render_voters_table(ui: &mut egui::Ui, voter_info: &SlotVoterInfo) {
-    // Define alternating colors for vote account groups - making them very distinct for testing
-    let color1 = VOTER_COLOR_1;
-    let color2 = VOTER_COLOR_2;
-
-    // Sort transactions by vote account to group them together
-    let mut sorted_transactions = voter_info.vote_transactions.clone();
-    sorted_transactions.sort_by(|a, b| a.vote_account.cmp(&b.vote_account));
-
-    // Create mapping of vote accounts to color indices
-    let mut vote_account_colors = HashMap::new();
-    let mut unique_accounts = Vec::new();
-
-    // Collect unique vote accounts in order
-    for vote_tx in &sorted_transactions {
-        if !unique_accounts.contains(&vote_tx.vote_account) {
-            unique_accounts.push(vote_tx.vote_account.clone());
-        }
+        .any(|voter| pubkey_search_key(voter, case_sensitive) == target_key);
+    let scope = scope.to_lowercase();
+    let (text, color) = if voted {
+        (
+            format!("✅ {} voted in {}", target, scope),
+            palette_color(ui.ctx(), StatusColor::Success),
+        )
+    } else {
+        (
+            format!("❌ {} did not vote in {}", target, scope),
+            palette_color(ui.ctx(), StatusColor::Stalled),
+        )
+    };
+    ui.add_space(CONTENT_SPACING_SMALL);
+    ui.label(egui::RichText::new(text).heading().color(color));
+    if !voted && result.unresolved_vote_instructions > 0 {
+        ui.label("Some vote instructions could not be resolved, so this vote may have been missed");
     }
-
-    // Assign alternating colors to unique accounts
-    for (index, account) in unique_accounts.iter().enumerate() {
-        let color_index = index % 2;
-        vote_account_colors.insert(account.clone(), color_index);
+    if !voted && !result.failed_slots.is_empty() {
+        ui.label("Some slots could not be fetched, so this vote may have been missed");
     }
+    ui.add_space(CONTENT_SPACING_SMALL);
+}
+
+/// Whether a vote transaction matches a normalized search key.
+fn vote_transaction_matches(
+    vote_tx: &VoteTransactionInfo,
+    search_key: &str,
+    case_sensitive: bool,
+) -> bool {
+    // Search in vote account and transaction signature, both base58
+    search_key.is_empty()
+        || pubkey_search_key(&vote_tx.vote_account, case_sensitive).contains(search_key)
+        || pubkey_search_key(&vote_tx.signature, case_sensitive).contains(search_key)
+}
 
+/// Render the voters table with transaction signatures and alternating colors per vote account.
+/// Only the visible rows are laid out, so large slots stay responsive. Clicking a
+/// header sorts by that column; the new order is applied on the next frame.
+fn render_voters_table(
+    ui: &mut egui::Ui,
+    rows: &[VoterRow],
+    aliases: &AliasStore,
+    sort: &mut VoterSort,
+) {
+    let pubkey_width = pubkey_column_width(ui.ctx());
     TableBuilder::new(ui)
         .striped(false) // Disable default striping since we're doing custom colors
         .resizable(true)
         .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
         .column(Column::auto().at_least(COLUMN_SMALL_INDEX_WIDTH)) // Index
-        .column(Column::auto().at_least(COLUMN_VOTE_ACCOUNT_WIDTH)) // Vote Account
+        .column(Column::auto().at_least(pubkey_width)) // Vote Account
         .column(Column::auto().at_least(COLUMN_TRANSACTION_WIDTH)) // Transaction Signature
         .header(TABLE_HEADER_HEIGHT, |mut header| {
-            header.col(|ui| {
-                ui.heading("#");
-            });
-            header.col(|ui| {
-                ui.heading("Vote Account Public Key");
-            });
-            header.col(|ui| {
-                ui.heading("Transaction Signature");
-            });
+            for (column, title, hover) in [
+                (
+                    VoterSortColumn::BlockOrder,
+                    "#",
+                    "Sort by position in the block",
+                ),
+                (
+                    VoterSortColumn::VoteAccount,
+                    "Vote Account Public Key",
+                    "Sort by vote account, grouping each voter's transactions",
+                ),
+                (
+                    VoterSortColumn::Signature,
+                    "Transaction Signature",
+                    "Sort by transaction signature",
+                ),
+            ] {
+                header.col(|ui| {
+                    let text = format!("{}{}", title, sort.indicator(column));
+                    if ui.button(text).on_hover_text(hover).clicked() {
+                        sort.toggle(column);
+                    }
+                });
+            }
         })
-        .body(|mut body| {
-            for (index, vote_tx) in sorted_transactions.iter().enumerate() {
-                let color_index = vote_account_colors.get(&vote_tx.vote_account).unwrap_or(&0);
-                let bg_color = if *color_index == 0 { color1 } else { color2 };
-
-                body.row(TABLE_ROW_HEIGHT, |mut row| {
-                    row.col(|ui| {
-                        ui.scope(|ui| {
-                            ui.visuals_mut().panel_fill = bg_color;
-                            ui.visuals_mut().window_fill = bg_color;
-                            let frame = create_cell_frame(bg_color);
-                            frame.show(ui, |ui| {
-                                ui.label((index + 1).to_string());
-                            });
-                        });
+        .body(|body| {
+            body.rows(TABLE_ROW_HEIGHT, rows.len(), |mut row| {
+                let index = row.index();
+                let voter_row = &rows[index];
+                let bg_color = if voter_row.color_index == 0 {
+                    VOTER_COLOR_1
+                } else {
+                    VOTER_COLOR_2
+                };
+
+                row.col(|ui| {
+                    render_colored_cell(ui, bg_color, |ui| {
+                        ui.label((voter_row.block_index + 1).to_string());
                     });
-                    row.col(|ui| {
-                        ui.scope(|ui| {
-                            ui.visuals_mut().panel_fill = bg_color;
-                            ui.visuals_mut().window_fill = bg_color;
-                            let frame = create_cell_frame(bg_color);
-                            frame.show(ui, |ui| {
-                                ui.monospace(&vote_tx.vote_account);
-                            });
-                        });
+                });
+                row.col(|ui| {
+                    render_colored_cell(ui, bg_color, |ui| {
+                        render_pubkey(ui, aliases, &voter_row.vote_account);
                     });
-                    row.col(|ui| {
-                        ui.scope(|ui| {
-                            ui.visuals_mut().panel_fill = bg_color;
-                            ui.visuals_mut().window_fill = bg_color;
-                            let frame = create_cell_frame(bg_color);
-                            frame.show(ui, |ui| {
-                                ui.monospace(&vote_tx.signature);
-                            });
-                        });
+                });
+                row.col(|ui| {
+                    render_colored_cell(ui, bg_color, |ui| {
+                        ui.monospace(&voter_row.signature);
                     });
                 });
-            }
+            });
         });
 }
+
+/// Render cell content on a colored background.
+fn render_colored_cell(
+    ui: &mut egui::Ui,
+    bg_color: egui::Color32,
+    add_contents: impl FnOnce(&mut egui::Ui),
+) {
+    ui.scope(|ui| {
+        ui.visuals_mut().panel_fill = bg_color;
+        ui.visuals_mut().window_fill = bg_color;
+        let frame = create_cell_frame(bg_color);
+        frame.show(ui, add_contents);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range_result(slot: u64, end_slot: u64, signatures: &[&str]) -> SlotVoterInfo {
+        SlotVoterInfo {
+            slot,
+            voters: HashSet::from(["Vote1".to_string()]),
+            vote_transactions: signatures
+                .iter()
+                .map(|signature| VoteTransactionInfo {
+                    vote_account: "Vote1".to_string(),
+                    signature: signature.to_string(),
+                })
+                .collect(),
+            total_voters: 1,
+            end_slot: Some(end_slot),
+            unresolved_vote_instructions: 0,
+            failed_slots: Vec::new(),
+        }
+    }
+
+    fn cached_signatures(cache: &VotersTableCache) -> Vec<&str> {
+        cache
+            .rows
+            .iter()
+            .map(|row| row.signature.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_voters_table_cache_rebuilds_per_range_and_fetch() {
+        let mut cache = VotersTableCache::default();
+        cache.refresh(
+            VoterSource::Fetch(1),
+            &range_result(100, 105, &["a"]),
+            "",
+            false,
+        );
+        assert_eq!(cached_signatures(&cache), ["a"]);
+
+        // Same start slot and vote count, longer range
+        cache.refresh(
+            VoterSource::Fetch(2),
+            &range_result(100, 110, &["b"]),
+            "",
+            false,
+        );
+        assert_eq!(cached_signatures(&cache), ["b"]);
+
+        // Same range fetched again, e.g. after a reconnect
+        cache.refresh(
+            VoterSource::Fetch(3),
+            &range_result(100, 110, &["c"]),
+            "",
+            false,
+        );
+        assert_eq!(cached_signatures(&cache), ["c"]);
+    }
+}
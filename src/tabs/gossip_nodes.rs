@@ -1,45 +1,106 @@
 //! Gossip nodes tab functionality for the Solana UI application.
 
+use std::cmp::Reverse;
+use std::net::SocketAddr;
+
+use chrono::{DateTime, Local};
 use eframe::egui;
 use egui_extras::{Column, TableBuilder};
+use solana_sdk::pubkey::Pubkey;
 
+use crate::config::AliasStore;
 use crate::constants::*;
 use crate::solana::GossipNodeInfo;
-use crate::utils::{create_error_frame, render_search_field};
+use crate::utils::{
+    apply_row_stripes, icon_text, parse_version, private_gossip_endpoints, pubkey_column_width,
+    pubkey_search_key, record_table_rect, refresh_button, render_error_frame, render_hold_toggle,
+    render_last_updated, render_pubkey, render_recent_searches, render_row_cap_notice,
+    render_search_field, rendered_rows, DisplaySettings, GossipSortColumn, SortDirection,
+};
+
+/// Header titles in display order, with the column each one sorts by.
+const TABLE_HEADERS: [(&str, Option<GossipSortColumn>); 8] = [
+    ("Pubkey", Some(GossipSortColumn::Pubkey)),
+    ("Gossip Address", Some(GossipSortColumn::GossipAddress)),
+    ("TPU Address", None),
+    ("RPC Address", None),
+    ("TPU QUIC Address", None),
+    ("Version", Some(GossipSortColumn::Version)),
+    ("Feature Set", Some(GossipSortColumn::FeatureSet)),
+    ("Shred Version", Some(GossipSortColumn::ShredVersion)),
+];
+
+/// Parameters for the gossip nodes tab rendering.
+pub struct GossipNodesTabParams<'a> {
+    pub gossip_nodes: &'a [GossipNodeInfo],
+    pub search_term: &'a mut String,
+    /// Recently submitted search terms, newest first
+    pub recent_searches: &'a [String],
+    /// Why this tab's last fetch failed
+    pub error_message: Option<&'a str>,
+    pub is_loading: bool,
+    pub should_focus_search: bool,
+    /// When the gossip nodes were last fetched
+    pub last_updated: Option<DateTime<Local>>,
+    /// Show a frozen copy of the data instead of the latest refresh
+    pub hold: &'a mut bool,
+    /// Column the table is sorted by; `None` keeps the RPC order
+    pub sort: &'a mut Option<(GossipSortColumn, SortDirection)>,
+    /// Friendly names shown in place of pubkeys
+    pub aliases: &'a AliasStore,
+}
 
 /// Render the gossip nodes tab content.
 pub fn render_gossip_nodes_tab(
     ui: &mut egui::Ui,
-    gossip_nodes: &[GossipNodeInfo],
-    search_term: &mut String,
-    error_message: &Option<String>,
-    is_loading: bool,
-    should_focus_search: bool,
+    params: GossipNodesTabParams,
     mut on_refresh: impl FnMut(),
+    mut on_search_commit: impl FnMut(),
 ) {
+    let GossipNodesTabParams {
+        gossip_nodes,
+        search_term,
+        recent_searches,
+        error_message,
+        is_loading,
+        should_focus_search,
+        last_updated,
+        hold,
+        sort,
+        aliases,
+    } = params;
     ui.horizontal(|ui| {
         ui.heading("Gossip Network Nodes");
         ui.add_space(HEADER_SPACING_LARGE);
 
         // Search bar near headline
-        ui.label("🔍 Search:");
+        ui.label(icon_text(ui.ctx(), "🔍", "Search:"));
         ui.add_space(CONTENT_SPACING_SMALL);
-        let _search_response = render_search_field(
+        let search_response = render_search_field(
             ui,
             search_term,
             "Search nodes...",
             should_focus_search,
             SEARCH_FIELD_WIDTH,
         );
+        let picked = render_recent_searches(ui, search_term, recent_searches);
+        if picked || (search_response.lost_focus() && !search_term.trim().is_empty()) {
+            on_search_commit();
+        }
 
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-            if ui
-                .button("🔄 Refresh Nodes")
-                .on_hover_text("Refresh gossip nodes data (Cmd+R / Ctrl+R)")
-                .clicked()
+            if refresh_button(
+                ui,
+                is_loading,
+                None,
+                icon_text(ui.ctx(), "🔄", "Refresh Nodes"),
+                "Refresh gossip nodes data (Cmd+R / Ctrl+R)",
+            )
+            .clicked()
             {
                 on_refresh();
             }
+            render_hold_toggle(ui, hold);
         });
     });
     ui.add_space(HEADER_SPACING_SMALL);
@@ -47,11 +108,7 @@ pub fn render_gossip_nodes_tab(
     ui.add_space(HEADER_SPACING_MEDIUM);
 
     if let Some(error) = error_message {
-        let frame = create_error_frame();
-
-        frame.show(ui, |ui| {
-            ui.colored_label(egui::Color32::RED, format!("❌ Error: {}", error));
-        });
+        render_error_frame(ui, "Gossip nodes", error);
         ui.add_space(HEADER_SPACING_MEDIUM);
     }
 
@@ -59,35 +116,48 @@ pub fn render_gossip_nodes_tab(
         ui.label("No gossip nodes data. Click 'Refresh Nodes' to load gossip nodes.");
     } else {
         // Apply filtering
-        let filtered_nodes = filter_gossip_nodes(gossip_nodes, search_term);
+        let case_sensitive = DisplaySettings::get(ui.ctx()).case_sensitive_pubkey_search;
+        let mut filtered_nodes = filter_gossip_nodes(gossip_nodes, search_term, case_sensitive);
+        if let Some((column, direction)) = *sort {
+            sort_gossip_nodes(&mut filtered_nodes, column, direction);
+        }
 
         // Show filter results info
-        if !search_term.is_empty() {
-            ui.horizontal(|ui| {
+        ui.horizontal(|ui| {
+            if !search_term.is_empty() {
                 ui.label(format!(
                     "🌐 Showing {} of {} gossip nodes (filtered)",
                     filtered_nodes.len(),
                     gossip_nodes.len()
                 ));
-            });
-        } else {
-            ui.horizontal(|ui| {
+            } else {
                 ui.label(format!("🌐 Showing {} gossip nodes", filtered_nodes.len()));
-            });
-        }
+            }
+            render_last_updated(ui, last_updated);
+            render_row_cap_notice(ui, filtered_nodes.len());
+        });
 
         // Create gossip nodes table
-        render_gossip_nodes_table(ui, &filtered_nodes);
+        record_table_rect(ui, |ui| {
+            render_gossip_nodes_table(ui, rendered_rows(ui.ctx(), &filtered_nodes), aliases, sort);
+        });
     }
 }
 
-/// Render the gossip nodes table.
-fn render_gossip_nodes_table(ui: &mut egui::Ui, gossip_nodes: &[GossipNodeInfo]) {
+/// Render the gossip nodes table; clicking a sortable header updates `sort`.
+fn render_gossip_nodes_table(
+    ui: &mut egui::Ui,
+    gossip_nodes: &[GossipNodeInfo],
+    aliases: &AliasStore,
+    sort: &mut Option<(GossipSortColumn, SortDirection)>,
+) {
+    let striped = apply_row_stripes(ui);
+    let pubkey_width = pubkey_column_width(ui.ctx());
     TableBuilder::new(ui)
-        .striped(true)
+        .striped(striped)
         .resizable(true)
         .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-        .column(Column::auto().at_least(COLUMN_PUBKEY_WIDTH)) // Pubkey
+        .column(Column::auto().at_least(pubkey_width)) // Pubkey
         .column(Column::auto().at_least(COLUMN_ADDRESS_WIDTH)) // Gossip Address
         .column(Column::auto().at_least(COLUMN_ADDRESS_WIDTH)) // TPU Address
         .column(Column::auto().at_least(COLUMN_ADDRESS_WIDTH)) // RPC Address
@@ -96,44 +166,62 @@ fn render_gossip_nodes_table(ui: &mut egui::Ui, gossip_nodes: &[GossipNodeInfo])
         .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Feature Set
         .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Shred Version
         .header(TABLE_HEADER_HEIGHT, |mut header| {
-            header.col(|ui| {
-                ui.heading("Pubkey");
-            });
-            header.col(|ui| {
-                ui.heading("Gossip Address");
-            });
-            header.col(|ui| {
-                ui.heading("TPU Address");
-            });
-            header.col(|ui| {
-                ui.heading("RPC Address");
-            });
-            header.col(|ui| {
-                ui.heading("TPU QUIC Address");
-            });
-            header.col(|ui| {
-                ui.heading("Version");
-            });
-            header.col(|ui| {
-                ui.heading("Feature Set");
-            });
-            header.col(|ui| {
-                ui.heading("Shred Version");
-            });
+            for (title, column) in TABLE_HEADERS {
+                header.col(|ui| {
+                    let Some(column) = column else {
+                        ui.heading(title);
+                        return;
+                    };
+                    let indicator = match *sort {
+                        Some((sorted, SortDirection::Ascending)) if sorted == column => " ▲",
+                        Some((sorted, SortDirection::Descending)) if sorted == column => " ▼",
+                        _ => "",
+                    };
+                    if ui
+                        .button(format!("{}{}", title, indicator))
+                        .on_hover_text("Click to sort; click again to reverse")
+                        .clicked()
+                    {
+                        *sort = Some(match *sort {
+                            Some((sorted, SortDirection::Ascending)) if sorted == column => {
+                                (column, SortDirection::Descending)
+                            }
+                            _ => (column, SortDirection::Ascending),
+                        });
+                    }
+                });
+            }
         })
         .body(|mut body| {
             for node in gossip_nodes.iter() {
                 body.row(TABLE_ROW_HEIGHT, |mut row| {
-                    render_gossip_node_row(&mut row, node);
+                    render_gossip_node_row(&mut row, node, aliases);
                 });
             }
         });
 }
 
 /// Render a single gossip node row.
-fn render_gossip_node_row(row: &mut egui_extras::TableRow<'_, '_>, node: &GossipNodeInfo) {
+fn render_gossip_node_row(
+    row: &mut egui_extras::TableRow<'_, '_>,
+    node: &GossipNodeInfo,
+    aliases: &AliasStore,
+) {
     row.col(|ui| {
-        ui.monospace(node.pubkey.to_string());
+        render_pubkey(ui, aliases, &node.pubkey.to_string());
+        let private = private_gossip_endpoints(node);
+        if !private.is_empty() {
+            ui.label(
+                egui::RichText::new(icon_text(ui.ctx(), "🏠", "private"))
+                    .weak()
+                    .small(),
+            )
+            .on_hover_text(format!(
+                "Advertises a private or loopback address for {}; \
+                 it is likely unreachable from outside its own network",
+                private.join(", ")
+            ));
+        }
     });
     row.col(|ui| {
         ui.label(&node.gossip);
@@ -166,45 +254,236 @@ fn render_gossip_node_row(row: &mut egui_extras::TableRow<'_, '_>, node: &Gossip
     });
 }
 
+/// Sort value of one gossip node column.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum GossipSortValue {
+    Pubkey(Pubkey),
+    /// Parsed so addresses compare numerically
+    Address(Option<SocketAddr>),
+    /// Parsed so 2.1.10 sorts after 2.1.9
+    Version(Option<[u32; 3]>),
+    Number(Option<u64>),
+}
+
+/// Key ordered in either direction; one sort only ever holds one variant.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum DirectedKey<T> {
+    Ascending(T),
+    Descending(Reverse<T>),
+}
+
+/// Whether the node lacks the column's value, and the value to sort by.
+/// Unparseable addresses (such as "Unknown") and versions count as missing.
+fn gossip_sort_key(node: &GossipNodeInfo, column: GossipSortColumn) -> (bool, GossipSortValue) {
+    match column {
+        GossipSortColumn::Pubkey => (false, GossipSortValue::Pubkey(node.pubkey)),
+        GossipSortColumn::GossipAddress => {
+            let address = node.gossip.parse::<SocketAddr>().ok();
+            (address.is_none(), GossipSortValue::Address(address))
+        }
+        GossipSortColumn::Version => {
+            let version = node.version.as_deref().and_then(parse_version);
+            (version.is_none(), GossipSortValue::Version(version))
+        }
+        GossipSortColumn::FeatureSet => {
+            let feature_set = node.feature_set.map(u64::from);
+            (feature_set.is_none(), GossipSortValue::Number(feature_set))
+        }
+        GossipSortColumn::ShredVersion => {
+            let shred_version = node.shred_version.map(u64::from);
+            (
+                shred_version.is_none(),
+                GossipSortValue::Number(shred_version),
+            )
+        }
+    }
+}
+
+/// Sort gossip nodes by one column; nodes missing the value sort last in either direction.
+fn sort_gossip_nodes(
+    nodes: &mut [GossipNodeInfo],
+    column: GossipSortColumn,
+    direction: SortDirection,
+) {
+    // Parsing addresses and versions is costly enough to do once per node
+    nodes.sort_by_cached_key(|node| {
+        let (missing, value) = gossip_sort_key(node, column);
+        let value = match direction {
+            SortDirection::Ascending => DirectedKey::Ascending(value),
+            SortDirection::Descending => DirectedKey::Descending(Reverse(value)),
+        };
+        (missing, value)
+    });
+}
+
+/// Number of gossip nodes matching a search term, for the tab label badge.
+pub fn filtered_gossip_node_count(
+    nodes: &[GossipNodeInfo],
+    search_term: &str,
+    case_sensitive: bool,
+) -> usize {
+    if search_term.is_empty() {
+        return nodes.len();
+    }
+
+    let search_lower = search_term.to_lowercase();
+    let search_key = pubkey_search_key(search_term, case_sensitive);
+    nodes
+        .iter()
+        .filter(|node| gossip_node_matches(node, &search_lower, &search_key, case_sensitive))
+        .count()
+}
+
 /// Filter gossip nodes based on search term.
-fn filter_gossip_nodes(nodes: &[GossipNodeInfo], search_term: &str) -> Vec<GossipNodeInfo> {
+fn filter_gossip_nodes(
+    nodes: &[GossipNodeInfo],
+    search_term: &str,
+    case_sensitive: bool,
+) -> Vec<GossipNodeInfo> {
     if search_term.is_empty() {
         return nodes.to_vec();
     }
 
     let search_lower = search_term.to_lowercase();
+    let search_key = pubkey_search_key(search_term, case_sensitive);
     nodes
         .iter()
-        .filter(|node| {
-            // Search in pubkey, addresses, version, and other text fields
-            node.pubkey
-                .to_string()
-                .to_lowercase()
-                .contains(&search_lower)
-                || node.gossip.to_lowercase().contains(&search_lower)
-                || node
-                    .tpu
-                    .as_ref()
-                    .is_some_and(|s| s.to_lowercase().contains(&search_lower))
-                || node
-                    .rpc
-                    .as_ref()
-                    .is_some_and(|s| s.to_lowercase().contains(&search_lower))
-                || node
-                    .tpu_quic
-                    .as_ref()
-                    .is_some_and(|s| s.to_lowercase().contains(&search_lower))
-                || node
-                    .version
-                    .as_ref()
-                    .is_some_and(|s| s.to_lowercase().contains(&search_lower))
-                || node
-                    .feature_set
-                    .is_some_and(|f| f.to_string().contains(&search_lower))
-                || node
-                    .shred_version
-                    .is_some_and(|s| s.to_string().contains(&search_lower))
-        })
+        .filter(|node| gossip_node_matches(node, &search_lower, &search_key, case_sensitive))
         .cloned()
         .collect()
 }
+
+/// Whether a gossip node matches a lowercased search term or a normalized pubkey search key.
+fn gossip_node_matches(
+    node: &GossipNodeInfo,
+    search_lower: &str,
+    search_key: &str,
+    case_sensitive: bool,
+) -> bool {
+    // Search in pubkey, addresses, version, and other text fields
+    pubkey_search_key(&node.pubkey.to_string(), case_sensitive).contains(search_key)
+        || node.gossip.to_lowercase().contains(search_lower)
+        || node
+            .tpu
+            .as_ref()
+            .is_some_and(|s| s.to_lowercase().contains(search_lower))
+        || node
+            .rpc
+            .as_ref()
+            .is_some_and(|s| s.to_lowercase().contains(search_lower))
+        || node
+            .tpu_quic
+            .as_ref()
+            .is_some_and(|s| s.to_lowercase().contains(search_lower))
+        || node
+            .version
+            .as_ref()
+            .is_some_and(|s| s.to_lowercase().contains(search_lower))
+        || node
+            .feature_set
+            .is_some_and(|f| f.to_string().contains(search_lower))
+        || node
+            .shred_version
+            .is_some_and(|s| s.to_string().contains(search_lower))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(seed: u8, gossip: &str, version: Option<&str>) -> GossipNodeInfo {
+        GossipNodeInfo {
+            pubkey: Pubkey::new_from_array([seed; 32]),
+            gossip: gossip.to_string(),
+            tpu: None,
+            rpc: None,
+            tpu_quic: None,
+            version: version.map(str::to_string),
+            feature_set: None,
+            shred_version: None,
+        }
+    }
+
+    fn sorted_gossip(
+        mut nodes: Vec<GossipNodeInfo>,
+        column: GossipSortColumn,
+        direction: SortDirection,
+    ) -> Vec<String> {
+        sort_gossip_nodes(&mut nodes, column, direction);
+        nodes.into_iter().map(|node| node.gossip).collect()
+    }
+
+    #[test]
+    fn test_sort_gossip_nodes_by_version() {
+        let nodes = vec![
+            node(1, "a", Some("2.1.10")),
+            node(2, "b", None),
+            node(3, "c", Some("2.1.9")),
+            node(4, "d", Some("not a version")),
+            node(5, "e", Some("2.2.0")),
+        ];
+        assert_eq!(
+            sorted_gossip(
+                nodes.clone(),
+                GossipSortColumn::Version,
+                SortDirection::Ascending
+            ),
+            ["c", "a", "e", "b", "d"]
+        );
+        // Missing and unparseable versions stay last, in their original order
+        assert_eq!(
+            sorted_gossip(nodes, GossipSortColumn::Version, SortDirection::Descending),
+            ["e", "a", "c", "b", "d"]
+        );
+    }
+
+    #[test]
+    fn test_sort_gossip_nodes_missing_numbers_last() {
+        let mut nodes = vec![node(1, "a", None), node(2, "b", None), node(3, "c", None)];
+        nodes[0].shred_version = Some(2);
+        nodes[2].shred_version = Some(1);
+        assert_eq!(
+            sorted_gossip(
+                nodes.clone(),
+                GossipSortColumn::ShredVersion,
+                SortDirection::Ascending
+            ),
+            ["c", "a", "b"]
+        );
+        assert_eq!(
+            sorted_gossip(
+                nodes,
+                GossipSortColumn::ShredVersion,
+                SortDirection::Descending
+            ),
+            ["a", "c", "b"]
+        );
+    }
+
+    #[test]
+    fn test_sort_gossip_nodes_by_socket_address() {
+        let nodes = vec![
+            node(1, "10.0.0.10:8001", None),
+            node(2, "Unknown", None),
+            node(3, "10.0.0.2:8001", None),
+            node(4, "10.0.0.2:80", None),
+        ];
+        // Numeric order puts .2 before .10; unparseable addresses sort last either way
+        assert_eq!(
+            sorted_gossip(
+                nodes.clone(),
+                GossipSortColumn::GossipAddress,
+                SortDirection::Ascending
+            ),
+            ["10.0.0.2:80", "10.0.0.2:8001", "10.0.0.10:8001", "Unknown"]
+        );
+        assert_eq!(
+            sorted_gossip(
+                nodes,
+                GossipSortColumn::GossipAddress,
+                SortDirection::Descending
+            ),
+            ["10.0.0.10:8001", "10.0.0.2:8001", "10.0.0.2:80", "Unknown"]
+        );
+    }
+}
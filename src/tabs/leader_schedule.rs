@@ -6,16 +6,48 @@ use egui_extras::{Column, TableBuilder};
 
 use crate::constants::*;
 use crate::solana::{LeaderScheduleInfo, SolanaClient};
-use crate::utils::create_error_frame;
+use crate::utils::{
+    apply_row_stripes, icon_text, leader_schedule_cli_command, palette_color, plain_labels,
+    record_table_rect, refresh_button, render_error_frame, render_recent_searches, right_aligned,
+    StatusColor,
+};
+
+/// Where the leader schedule lookup stands, driving the tab's messaging.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum LeaderScheduleState {
+    /// Nothing fetched yet, or cleared
+    #[default]
+    Idle,
+    Loading,
+    /// The result has at least one leader slot
+    Loaded,
+    /// The result came back without leader slots
+    Empty,
+    Error(String),
+}
 
 /// Parameters for the leader schedule tab rendering.
-#[allow(dead_code)]
 pub struct LeaderScheduleTabParams<'a> {
     pub leader_identity_search: &'a mut String,
+    /// Recently fetched identities, newest first
+    pub recent_identity_searches: &'a [String],
     pub leader_epoch_search: &'a mut String,
     pub leader_result: &'a Option<LeaderScheduleInfo>,
-    pub error_message: &'a Option<String>,
+    pub state: &'a LeaderScheduleState,
+    /// Why this tab's last fetch failed
+    pub error_message: Option<&'a str>,
     pub is_loading: bool,
+    /// RPC endpoint of the active session, for the CLI command
+    pub rpc_url: &'a str,
+}
+
+/// Epoch typed into the epoch field; `None` (current epoch) when empty or invalid.
+fn requested_epoch(epoch_search: &str) -> Option<u64> {
+    if epoch_search.is_empty() {
+        None
+    } else {
+        epoch_search.parse::<u64>().ok()
+    }
 }
 
 /// Render the leader schedule tab content.
@@ -28,10 +60,13 @@ pub fn render_leader_schedule_tab(
 ) {
     let LeaderScheduleTabParams {
         leader_identity_search,
+        recent_identity_searches,
         leader_epoch_search,
         leader_result,
+        state,
         error_message,
         is_loading,
+        rpc_url,
     } = params;
     // Header with inline search controls
     ui.horizontal(|ui| {
@@ -39,7 +74,7 @@ pub fn render_leader_schedule_tab(
         ui.add_space(HEADER_SPACING_LARGE);
 
         // Compact search controls right next to the heading
-        ui.label("🔑 Identity:");
+        ui.label(icon_text(ui.ctx(), "🔑", "Identity:"));
         ui.add_space(CONTENT_SPACING_SMALL);
         let identity_response = ui
             .add_sized(
@@ -48,9 +83,10 @@ pub fn render_leader_schedule_tab(
                     .hint_text("Enter validator identity..."),
             )
             .on_hover_text("Enter validator identity public key (base58)");
+        let picked = render_recent_searches(ui, leader_identity_search, recent_identity_searches);
 
         ui.add_space(HEADER_SPACING_TINY);
-        ui.label("📅 Epoch:");
+        ui.label(icon_text(ui.ctx(), "📅", "Epoch:"));
         ui.add_space(CONTENT_SPACING_SMALL);
         let epoch_response = ui
             .add_sized(
@@ -60,28 +96,51 @@ pub fn render_leader_schedule_tab(
             .on_hover_text("Leave empty for current epoch");
 
         ui.add_space(HEADER_SPACING_TINY);
-        if (ui
-            .button("🔍 Fetch")
-            .on_hover_text("Fetch leader schedule (Cmd+R / Ctrl+R)")
-            .clicked()
-            || ui.input(|i| i.key_pressed(egui::Key::Enter) && !leader_identity_search.is_empty()))
-            && !leader_identity_search.trim().is_empty()
-        {
-            let epoch = if leader_epoch_search.is_empty() {
-                None
-            } else {
-                leader_epoch_search.parse::<u64>().ok()
-            };
-            on_fetch_schedule(leader_identity_search.trim(), epoch);
+        let fetch_clicked = refresh_button(
+            ui,
+            is_loading,
+            None,
+            icon_text(ui.ctx(), "🔍", "Fetch"),
+            "Fetch leader schedule (Cmd+R / Ctrl+R)",
+        )
+        .clicked();
+        let enter_pressed = !is_loading
+            && ui.input(|i| i.key_pressed(egui::Key::Enter) && !leader_identity_search.is_empty());
+        if (fetch_clicked || enter_pressed) && !leader_identity_search.trim().is_empty() {
+            on_fetch_schedule(
+                leader_identity_search.trim(),
+                requested_epoch(leader_epoch_search),
+            );
         }
 
         ui.add_space(CONTENT_SPACING_SMALL);
-        if ui.button("🗑").on_hover_text("Clear all").clicked() {
+        let clear_label = if plain_labels(ui.ctx()) {
+            "Clear"
+        } else {
+            "🗑"
+        };
+        if ui.button(clear_label).on_hover_text("Clear all").clicked() {
             on_clear();
         }
+        let cli_command = leader_schedule_cli_command(
+            rpc_url,
+            leader_identity_search,
+            requested_epoch(leader_epoch_search),
+        );
+        if ui
+            .add_enabled(
+                cli_command.is_some(),
+                egui::Button::new(icon_text(ui.ctx(), "📋", "Copy CLI")),
+            )
+            .on_hover_text("Copy the equivalent solana CLI command")
+            .clicked()
+            && let Some(command) = cli_command
+        {
+            ui.ctx().copy_text(command);
+        }
 
         // Save if leader schedule fields changed
-        if identity_response.changed() || epoch_response.changed() {
+        if identity_response.changed() || epoch_response.changed() || picked {
             on_search_change();
         }
     });
@@ -90,52 +149,82 @@ pub fn render_leader_schedule_tab(
     ui.separator();
     ui.add_space(HEADER_SPACING_SMALL);
 
-    if let Some(error) = error_message {
-        let frame = create_error_frame();
-
-        frame.show(ui, |ui| {
-            ui.colored_label(egui::Color32::RED, format!("❌ Error: {}", error));
-        });
+    // The recorded failure, or the state's own until the recorded one is read
+    let error = error_message.or(match state {
+        LeaderScheduleState::Error(error) => Some(error.as_str()),
+        _ => None,
+    });
+    if let Some(error) = error {
+        render_error_frame(ui, "Leader schedule", error);
         ui.add_space(HEADER_SPACING_MEDIUM);
     }
 
     // Display results
-    if let Some(result) = leader_result {
-        // Display results info
-        ui.horizontal(|ui| {
-            ui.label(format!(
-                "📊 Validator {}: {} leader slots in epoch {}",
-                result.validator_identity, result.total_slots, result.target_epoch
-            ));
-
-            // Show next upcoming slot info
-            if let Some(next_slot) = &result.next_leader_slot {
-                ui.separator();
-                // Recalculate time difference for current timestamp
-                let current_timestamp = Utc::now().timestamp();
-                let updated_time_diff = SolanaClient::format_time_difference(
-                    current_timestamp,
-                    next_slot.time_local.timestamp(),
-                );
-                ui.colored_label(
-                    SUCCESS_COLOR,
-                    format!("⏰ Next: Slot {} in {}", next_slot.slot, updated_time_diff),
-                );
-            }
-        });
+    let result = match (state, leader_result) {
+        (LeaderScheduleState::Idle, _) => {
+            ui.label("Enter a validator identity and click 'Fetch' to get leader slots.");
+            return;
+        }
+        (LeaderScheduleState::Loading, _) => {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Fetching leader schedule...");
+            });
+            return;
+        }
+        (LeaderScheduleState::Error(_), _) => return,
+        (LeaderScheduleState::Loaded | LeaderScheduleState::Empty, Some(result)) => result,
+        (LeaderScheduleState::Loaded | LeaderScheduleState::Empty, None) => return,
+    };
 
-        if !result.leader_slots.is_empty() {
-            render_leader_schedule_table(ui, result);
-        } else {
-            ui.label(format!(
-                "No leader slots found for validator {} in epoch {}",
-                result.validator_identity, result.target_epoch
-            ));
+    // Display results info
+    ui.horizontal(|ui| {
+        ui.label(format!(
+            "📊 Validator {}: {} leader slots in epoch {}",
+            result.validator_identity, result.total_slots, result.target_epoch
+        ));
+
+        // Show next upcoming slot info
+        if let Some(next_slot) = &result.next_leader_slot {
+            ui.separator();
+            // Recalculate time difference for current timestamp
+            let current_timestamp = Utc::now().timestamp();
+            let updated_time_diff = SolanaClient::format_time_difference(
+                current_timestamp,
+                next_slot.time_local.timestamp(),
+            );
+            ui.colored_label(
+                palette_color(ui.ctx(), StatusColor::Success),
+                format!("⏰ Next: Slot {} in {}", next_slot.slot, updated_time_diff),
+            );
         }
-    } else if !is_loading && !leader_identity_search.is_empty() {
-        ui.label("Enter a validator identity and click 'Fetch Schedule' to get leader slots.");
-    } else if is_loading {
-        ui.label("Fetching leader schedule...");
+    });
+
+    if *state == LeaderScheduleState::Empty {
+        ui.label(empty_schedule_message(result));
+    } else {
+        record_table_rect(ui, |ui| render_leader_schedule_table(ui, result));
+    }
+}
+
+/// Explain why a leader schedule result has no slots.
+fn empty_schedule_message(result: &LeaderScheduleInfo) -> String {
+    if result.schedule_published {
+        format!(
+            "Validator {} has no leader slots in epoch {}",
+            result.validator_identity, result.target_epoch
+        )
+    } else if result.target_epoch > result.latest_published_epoch {
+        format!(
+            "Leader schedule for epoch {} is not published yet: schedules are known up to \
+             epoch {} (current epoch is {})",
+            result.target_epoch, result.latest_published_epoch, result.current_epoch
+        )
+    } else {
+        format!(
+            "Leader schedule for epoch {} is not available from this RPC node",
+            result.target_epoch
+        )
     }
 }
 
@@ -155,8 +244,9 @@ fn render_leader_schedule_table(ui: &mut egui::Ui, leader_info: &LeaderScheduleI
     egui::ScrollArea::vertical()
         .auto_shrink(SCROLL_AUTO_SHRINK)
         .show(ui, |ui| {
+            let striped = apply_row_stripes(ui);
             TableBuilder::new(ui)
-                .striped(true)
+                .striped(striped)
                 .resizable(true)
                 .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
                 .column(Column::auto().at_least(COLUMN_EPOCH_WIDTH)) // Epoch
@@ -188,21 +278,28 @@ fn render_leader_schedule_table(ui: &mut egui::Ui, leader_info: &LeaderScheduleI
 
                         body.row(row_height, |mut row| {
                             row.col(|ui| {
-                                if is_next_upcoming {
-                                    ui.colored_label(
-                                        SUCCESS_COLOR,
-                                        format!("➤ {}", leader_slot.epoch),
-                                    );
-                                } else {
-                                    ui.label(leader_slot.epoch.to_string());
-                                }
+                                right_aligned(ui, |ui| {
+                                    if is_next_upcoming {
+                                        ui.colored_label(
+                                            palette_color(ui.ctx(), StatusColor::Success),
+                                            format!("➤ {}", leader_slot.epoch),
+                                        );
+                                    } else {
+                                        ui.label(leader_slot.epoch.to_string());
+                                    }
+                                });
                             });
                             row.col(|ui| {
-                                if is_next_upcoming {
-                                    ui.colored_label(SUCCESS_COLOR, leader_slot.slot.to_string());
-                                } else {
-                                    ui.monospace(leader_slot.slot.to_string());
-                                }
+                                right_aligned(ui, |ui| {
+                                    if is_next_upcoming {
+                                        ui.colored_label(
+                                            palette_color(ui.ctx(), StatusColor::Success),
+                                            leader_slot.slot.to_string(),
+                                        );
+                                    } else {
+                                        ui.monospace(leader_slot.slot.to_string());
+                                    }
+                                });
                             });
                             row.col(|ui| {
                                 let time_str = leader_slot
@@ -210,7 +307,10 @@ fn render_leader_schedule_table(ui: &mut egui::Ui, leader_info: &LeaderScheduleI
                                     .format("%Y-%m-%d %H:%M:%S %:z")
                                     .to_string();
                                 if is_next_upcoming {
-                                    ui.colored_label(SUCCESS_COLOR, time_str);
+                                    ui.colored_label(
+                                        palette_color(ui.ctx(), StatusColor::Success),
+                                        time_str,
+                                    );
                                 } else {
                                     ui.label(time_str);
                                 }
@@ -223,7 +323,7 @@ fn render_leader_schedule_table(ui: &mut egui::Ui, leader_info: &LeaderScheduleI
                                 );
                                 if is_next_upcoming {
                                     ui.colored_label(
-                                        SUCCESS_COLOR,
+                                        palette_color(ui.ctx(), StatusColor::Success),
                                         format!("⏰ {}", updated_time_diff),
                                     );
                                 } else {
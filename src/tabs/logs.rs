@@ -7,7 +7,10 @@ use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
 
 use crate::constants::*;
-use crate::utils::render_search_field;
+use crate::utils::{
+    accent_color, apply_row_stripes, icon_text, palette_color, record_table_rect,
+    render_search_field, truncate_chars, StatusColor,
+};
 
 /// A single log entry for RPC requests/responses.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +27,8 @@ pub struct LogEntry {
     pub content: String,
     /// Status or error code
     pub status: String,
+    /// Round-trip time of the request, for responses
+    pub duration_ms: Option<u64>,
 }
 
 /// Type of log entry.
@@ -45,11 +50,11 @@ impl LogEntryType {
         }
     }
 
-    pub fn color(&self) -> egui::Color32 {
+    pub fn color(&self, ctx: &egui::Context) -> egui::Color32 {
         match self {
-            LogEntryType::Request => LOG_REQUEST_COLOR,
-            LogEntryType::Response => LOG_RESPONSE_COLOR,
-            LogEntryType::Error => LOG_ERROR_COLOR,
+            LogEntryType::Request => accent_color(ctx, palette_color(ctx, StatusColor::Request)),
+            LogEntryType::Response => palette_color(ctx, StatusColor::Response),
+            LogEntryType::Error => palette_color(ctx, StatusColor::Error),
             LogEntryType::Update => egui::Color32::from_rgb(100, 149, 237),
         }
     }
@@ -84,12 +89,20 @@ pub fn log_request(store: &LogStore, operation: &str, url: &str, params: &str) {
         url: url.to_string(),
         content: params.to_string(),
         status: "Sent".to_string(),
+        duration_ms: None,
     };
     add_log_entry(store, entry);
 }
 
-/// Log an RPC response.
-pub fn log_response(store: &LogStore, operation: &str, url: &str, response: &str, status: &str) {
+/// Log an RPC response, with the request's round-trip time when measured.
+pub fn log_response(
+    store: &LogStore,
+    operation: &str,
+    url: &str,
+    response: &str,
+    status: &str,
+    duration_ms: Option<u64>,
+) {
     let entry = LogEntry {
         timestamp: Local::now(),
         entry_type: LogEntryType::Response,
@@ -97,6 +110,7 @@ pub fn log_response(store: &LogStore, operation: &str, url: &str, response: &str
         url: url.to_string(),
         content: response.to_string(),
         status: status.to_string(),
+        duration_ms,
     };
     add_log_entry(store, entry);
 }
@@ -110,6 +124,7 @@ pub fn log_error(store: &LogStore, operation: &str, url: &str, error: &str) {
         url: url.to_string(),
         content: error.to_string(),
         status: "Error".to_string(),
+        duration_ms: None,
     };
     add_log_entry(store, entry);
 }
@@ -123,24 +138,37 @@ pub fn log_update(store: &LogStore, operation: &str, message: &str, status: &str
         url: "updater".to_string(),
         content: message.to_string(),
         status: status.to_string(),
+        duration_ms: None,
     };
     add_log_entry(store, entry);
 }
 
+/// Auto-scroll preference of the logs view and its pause while browsing.
+#[derive(Debug, Clone, Copy)]
+pub struct AutoScroll {
+    /// Saved preference, changed only through the checkbox
+    pub enabled: bool,
+    /// Set while the user has scrolled up; never saved
+    pub paused: bool,
+}
+
 /// Render the logs tab content.
 pub fn render_logs_tab(
     ui: &mut egui::Ui,
     log_store: &LogStore,
     search_term: &mut String,
     should_focus_search: bool,
+    auto_scroll: &mut AutoScroll,
+    content_max_lines: usize,
     mut on_clear_logs: impl FnMut(),
 ) {
+    let mut copy_requested = false;
     ui.horizontal(|ui| {
         ui.heading("RPC Logs");
         ui.add_space(HEADER_SPACING_LARGE);
 
         // Search bar near headline
-        ui.label("🔍 Search:");
+        ui.label(icon_text(ui.ctx(), "🔍", "Search:"));
         ui.add_space(CONTENT_SPACING_SMALL);
         let _search_response = render_search_field(
             ui,
@@ -151,9 +179,20 @@ pub fn render_logs_tab(
         );
 
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-            if ui.button("🗑 Clear Logs").clicked() {
+            if ui.button(icon_text(ui.ctx(), "🗑", "Clear Logs")).clicked() {
                 on_clear_logs();
             }
+            copy_requested = ui
+                .button(icon_text(ui.ctx(), "📋", "Copy Logs"))
+                .on_hover_text("Copy the entries matching the search as plain text")
+                .clicked();
+            if ui
+                .checkbox(&mut auto_scroll.enabled, "Auto-scroll")
+                .on_hover_text("Keep the view at the bottom as new entries arrive")
+                .changed()
+            {
+                auto_scroll.paused = false;
+            }
         });
     });
     ui.add_space(HEADER_SPACING_TINY);
@@ -175,6 +214,10 @@ pub fn render_logs_tab(
     // Apply filtering
     let filtered_logs = filter_logs(&logs, search_term);
 
+    if copy_requested {
+        ui.ctx().copy_text(logs_as_text(&filtered_logs));
+    }
+
     ui.horizontal(|ui| {
         if search_term.is_empty() {
             ui.label(format!("📊 Showing {} log entries", logs.len()));
@@ -187,25 +230,43 @@ pub fn render_logs_tab(
         }
     });
 
-    // Create logs table with auto-scroll to bottom
-    egui::ScrollArea::vertical()
-        .auto_shrink(SCROLL_AUTO_SHRINK)
-        .stick_to_bottom(true)
-        .show(ui, |ui| {
-            render_logs_table(ui, &filtered_logs);
-        });
+    // Create logs table, optionally sticking to the bottom
+    let stick_to_bottom = auto_scroll.enabled && !auto_scroll.paused;
+    let output = record_table_rect(ui, |ui| {
+        render_logs_table(ui, &filtered_logs, content_max_lines, stick_to_bottom)
+    });
+
+    // Scrolling up pauses auto-scroll; scrolling back to the bottom resumes it
+    let scrolled =
+        ui.rect_contains_pointer(output.inner_rect) && ui.input(|i| i.smooth_scroll_delta.y != 0.0);
+    if scrolled && auto_scroll.enabled {
+        let max_offset = (output.content_size.y - output.inner_rect.height()).max(0.0);
+        auto_scroll.paused = output.state.offset.y < max_offset - 1.0;
+    }
 }
 
-/// Render the logs table.
-fn render_logs_table(ui: &mut egui::Ui, logs: &[LogEntry]) {
+/// Render the logs table. Content wraps within its column up to `content_max_lines`
+/// lines; every row is sized for that many lines, so only the visible rows are laid out.
+fn render_logs_table(
+    ui: &mut egui::Ui,
+    logs: &[LogEntry],
+    content_max_lines: usize,
+    stick_to_bottom: bool,
+) -> egui::scroll_area::ScrollAreaOutput<()> {
+    let striped = apply_row_stripes(ui);
+    let line_height = ui.text_style_height(&egui::TextStyle::Body);
+    let row_height = (line_height * content_max_lines.max(1) as f32).max(TABLE_ROW_HEIGHT_SMALL);
     TableBuilder::new(ui)
-        .striped(true)
+        .striped(striped)
         .resizable(true)
+        .auto_shrink(SCROLL_AUTO_SHRINK)
+        .stick_to_bottom(stick_to_bottom)
         .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
         .column(Column::auto().at_least(COLUMN_LOG_TYPE_WIDTH)) // Type icon
         .column(Column::auto().at_least(COLUMN_LOG_TIMESTAMP_WIDTH)) // Timestamp
         .column(Column::auto().at_least(COLUMN_LOG_OPERATION_WIDTH)) // Operation
         .column(Column::auto().at_least(COLUMN_LOG_STATUS_WIDTH)) // Status
+        .column(Column::auto().at_least(COLUMN_LOG_DURATION_WIDTH)) // Duration
         .column(Column::auto().at_least(COLUMN_LOG_URL_WIDTH)) // URL
         .column(Column::remainder().at_least(COLUMN_LOG_CONTENT_WIDTH)) // Content
         .header(TABLE_ROW_HEIGHT_LOGS, |mut header| {
@@ -221,6 +282,9 @@ fn render_logs_table(ui: &mut egui::Ui, logs: &[LogEntry]) {
             header.col(|ui| {
                 ui.heading("Status");
             });
+            header.col(|ui| {
+                ui.heading("Duration");
+            });
             header.col(|ui| {
                 ui.heading("URL");
             });
@@ -228,20 +292,55 @@ fn render_logs_table(ui: &mut egui::Ui, logs: &[LogEntry]) {
                 ui.heading("Content");
             });
         })
-        .body(|mut body| {
-            // Show logs in reverse order (newest first)
-            for log_entry in logs.iter().rev() {
-                body.row(TABLE_ROW_HEIGHT_SMALL, |mut row| {
-                    render_log_row(&mut row, log_entry);
-                });
-            }
-        });
+        .body(|body| {
+            let content_width = body
+                .widths()
+                .last()
+                .copied()
+                .unwrap_or(COLUMN_LOG_CONTENT_WIDTH);
+            body.rows(row_height, logs.len(), |mut row| {
+                // Show logs in reverse order (newest first)
+                let log_entry = &logs[logs.len() - 1 - row.index()];
+                render_log_row(&mut row, log_entry, content_width, content_max_lines);
+            });
+        })
 }
 
-/// Render a single log row.
-fn render_log_row(row: &mut egui_extras::TableRow<'_, '_>, entry: &LogEntry) {
+/// Lay out log content wrapped to `width`, elided after `max_lines` lines.
+/// URLs and base58 strings have no spaces, so lines may break anywhere.
+/// Only the first `LOG_CONTENT_LAYOUT_CHARS` characters are laid out, since
+/// full RPC responses can run to megabytes.
+fn content_galley(ui: &egui::Ui, content: &str, width: f32, max_lines: usize) -> Arc<egui::Galley> {
+    let shown = truncate_chars(content, LOG_CONTENT_LAYOUT_CHARS);
+    let mut text = shown.to_string();
+    if shown.len() < content.len() {
+        text.push('…');
+    }
+    let mut job = egui::text::LayoutJob::single_section(
+        text,
+        egui::TextFormat::simple(
+            egui::TextStyle::Body.resolve(ui.style()),
+            ui.visuals().text_color(),
+        ),
+    );
+    job.wrap = egui::text::TextWrapping {
+        max_width: width,
+        max_rows: max_lines.max(1),
+        break_anywhere: true,
+        overflow_character: Some('…'),
+    };
+    ui.fonts(|fonts| fonts.layout_job(job))
+}
+
+/// Render a single log row, wrapping its content to `content_width`.
+fn render_log_row(
+    row: &mut egui_extras::TableRow<'_, '_>,
+    entry: &LogEntry,
+    content_width: f32,
+    content_max_lines: usize,
+) {
     row.col(|ui| {
-        ui.colored_label(entry.entry_type.color(), entry.entry_type.icon());
+        ui.colored_label(entry.entry_type.color(ui.ctx()), entry.entry_type.icon());
     });
     row.col(|ui| {
         ui.label(entry.timestamp.format("%H:%M:%S").to_string());
@@ -250,22 +349,62 @@ fn render_log_row(row: &mut egui_extras::TableRow<'_, '_>, entry: &LogEntry) {
         ui.label(&entry.operation);
     });
     row.col(|ui| {
-        ui.colored_label(entry.entry_type.color(), &entry.status);
+        ui.colored_label(entry.entry_type.color(ui.ctx()), &entry.status);
+    });
+    row.col(|ui| match entry.duration_ms {
+        Some(duration_ms) => {
+            ui.label(format!("{} ms", duration_ms));
+        }
+        None => {
+            ui.label("—");
+        }
     });
     row.col(|ui| {
         ui.monospace(&entry.url);
     });
     row.col(|ui| {
-        // Truncate very long content for display
-        let display_content = if entry.content.len() > LOG_CONTENT_TRUNCATE_LENGTH {
-            format!("{}...", &entry.content[..LOG_CONTENT_DISPLAY_LENGTH])
-        } else {
-            entry.content.clone()
-        };
-        ui.label(display_content);
+        let content = content_galley(ui, &entry.content, content_width, content_max_lines);
+        // Cut short by the row limit or by the layout character cap
+        let elided = content.elided || content.text() != entry.content;
+        let response = ui.label(content);
+        if elided {
+            response.on_hover_text(&entry.content);
+        }
     });
 }
 
+/// Format log entries as plain text, oldest first, with untruncated content.
+fn logs_as_text(logs: &[LogEntry]) -> String {
+    logs.iter()
+        .map(|entry| {
+            let status = match entry.duration_ms {
+                Some(duration_ms) => format!("{} ({} ms)", entry.status, duration_ms),
+                None => entry.status.clone(),
+            };
+            format!(
+                "{} [{:?}] {} | {} | {} | {}\n",
+                entry.timestamp.format("%Y-%m-%d %H:%M:%S%.3f"),
+                entry.entry_type,
+                entry.operation,
+                status,
+                entry.url,
+                entry.content
+            )
+        })
+        .collect()
+}
+
+/// Number of log entries matching a search term, for the tab label badge.
+pub fn filtered_log_count(logs: &[LogEntry], search_term: &str) -> usize {
+    if search_term.is_empty() {
+        return logs.len();
+    }
+    let search_lower = search_term.to_lowercase();
+    logs.iter()
+        .filter(|log| log_matches(log, &search_lower))
+        .count()
+}
+
 /// Filter logs based on search term.
 fn filter_logs(logs: &[LogEntry], search_term: &str) -> Vec<LogEntry> {
     if search_term.is_empty() {
@@ -274,12 +413,15 @@ fn filter_logs(logs: &[LogEntry], search_term: &str) -> Vec<LogEntry> {
 
     let search_lower = search_term.to_lowercase();
     logs.iter()
-        .filter(|log| {
-            log.operation.to_lowercase().contains(&search_lower)
-                || log.url.to_lowercase().contains(&search_lower)
-                || log.content.to_lowercase().contains(&search_lower)
-                || log.status.to_lowercase().contains(&search_lower)
-        })
+        .filter(|log| log_matches(log, &search_lower))
         .cloned()
         .collect()
 }
+
+/// Whether a log entry contains a lowercased search term.
+fn log_matches(log: &LogEntry, search_lower: &str) -> bool {
+    log.operation.to_lowercase().contains(search_lower)
+        || log.url.to_lowercase().contains(search_lower)
+        || log.content.to_lowercase().contains(search_lower)
+        || log.status.to_lowercase().contains(search_lower)
+}
@@ -5,15 +5,19 @@
 //! - gossip_nodes: Gossip network node information
 //! - find_voters: Slot voter search functionality
 //! - leader_schedule: Validator leader schedule tracking
+//! - network: Cluster-wide metrics dashboard
 //! - update: Application update management
 
 pub mod find_voters;
 pub mod gossip_nodes;
 pub mod leader_schedule;
 pub mod logs;
+pub mod network;
 pub mod update;
 pub mod validators;
 
+use eframe::egui;
+
 /// Available tabs in the application.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AppTab {
@@ -21,6 +25,7 @@ pub enum AppTab {
     GossipNodes,
     FindVoters,
     LeaderSchedule,
+    Network,
     Logs,
     Update,
 }
@@ -32,17 +37,49 @@ impl AppTab {
             Self::GossipNodes => "🌐 Gossip Nodes",
             Self::FindVoters => "🔍 Find Voters",
             Self::LeaderSchedule => "⏰ Leader Schedule",
+            Self::Network => "📡 Network",
             Self::Logs => "📋 Logs",
             Self::Update => "🔄 Update",
         }
     }
 
+    /// Tab name without the emoji icon, for font stacks that lack emoji.
+    pub const fn name_plain(self) -> &'static str {
+        match self {
+            Self::Validators => "Validators",
+            Self::GossipNodes => "Gossip Nodes",
+            Self::FindVoters => "Find Voters",
+            Self::LeaderSchedule => "Leader Schedule",
+            Self::Network => "Network",
+            Self::Logs => "Logs",
+            Self::Update => "Update",
+        }
+    }
+
+    /// Tab name honoring the plain text labels setting.
+    pub fn label(self, ctx: &egui::Context) -> &'static str {
+        if crate::utils::plain_labels(ctx) {
+            self.name_plain()
+        } else {
+            self.name()
+        }
+    }
+
+    /// Tab label with a result count badge, e.g. "📊 Validators (42)".
+    pub fn label_with_count(self, ctx: &egui::Context, count: Option<usize>) -> String {
+        match count {
+            Some(count) => format!("{} ({})", self.label(ctx), count),
+            None => self.label(ctx).to_string(),
+        }
+    }
+
     pub const fn id(self) -> &'static str {
         match self {
             Self::Validators => "Validators",
             Self::GossipNodes => "GossipNodes",
             Self::FindVoters => "FindVoters",
             Self::LeaderSchedule => "LeaderSchedule",
+            Self::Network => "Network",
             Self::Logs => "Logs",
             Self::Update => "Update",
         }
@@ -54,6 +91,7 @@ impl AppTab {
             "GossipNodes" => Self::GossipNodes,
             "FindVoters" => Self::FindVoters,
             "LeaderSchedule" => Self::LeaderSchedule,
+            "Network" => Self::Network,
             "Logs" => Self::Logs,
             "Update" => Self::Update,
             _ => Self::Validators, // Default
@@ -0,0 +1,192 @@
+//! Network tab functionality for the Solana UI application.
+
+use chrono::{DateTime, Local};
+use eframe::egui;
+
+use crate::constants::*;
+use crate::solana::{NetworkOverview, ValidatorInfo};
+use crate::utils::{
+    active_stake, create_info_frame, format_stake, icon_text, nakamoto_coefficient, refresh_button,
+    render_error_frame, render_last_updated, render_partial_failures, staked_supply_percent,
+    version_distribution, Cluster,
+};
+
+/// Parameters for the network tab rendering.
+pub struct NetworkTabParams<'a> {
+    pub cluster: Cluster,
+    pub validators: &'a [ValidatorInfo],
+    pub overview: Option<&'a NetworkOverview>,
+    /// Total token supply in lamports, once fetched
+    pub total_supply: Option<u64>,
+    /// Why this tab's last fetch failed
+    pub error_message: Option<&'a str>,
+    pub is_loading: bool,
+    /// When the validators were last fetched
+    pub last_updated: Option<DateTime<Local>>,
+}
+
+/// Render the network tab content.
+pub fn render_network_tab(
+    ui: &mut egui::Ui,
+    params: NetworkTabParams,
+    mut on_refresh: impl FnMut(),
+) {
+    let NetworkTabParams {
+        cluster,
+        validators,
+        overview,
+        total_supply,
+        error_message,
+        is_loading,
+        last_updated,
+    } = params;
+    ui.horizontal(|ui| {
+        ui.heading("Network Overview");
+        ui.add_space(HEADER_SPACING_LARGE);
+        ui.label(cluster.name());
+        ui.add_space(HEADER_SPACING_TINY);
+        render_last_updated(ui, last_updated);
+
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            if refresh_button(
+                ui,
+                is_loading,
+                None,
+                icon_text(ui.ctx(), "🔄", "Refresh Network"),
+                "Refresh validators and network figures (Cmd+R / Ctrl+R)",
+            )
+            .clicked()
+            {
+                on_refresh();
+            }
+        });
+    });
+    ui.add_space(HEADER_SPACING_TINY);
+    ui.separator();
+    ui.add_space(HEADER_SPACING_SMALL);
+
+    if let Some(error) = error_message {
+        render_error_frame(ui, "Validators", error);
+        ui.add_space(HEADER_SPACING_MEDIUM);
+    }
+
+    if validators.is_empty() && overview.is_none() {
+        if is_loading {
+            ui.spinner();
+        } else {
+            ui.label("No network data. Click 'Refresh Network' to load it.");
+        }
+        return;
+    }
+
+    // Delinquent validators don't vote, so their stake is left out of the aggregates
+    let total_stake = active_stake(validators);
+    let delinquent: Vec<&ValidatorInfo> = validators.iter().filter(|v| v.delinquent).collect();
+    let unknown = || "—".to_string();
+
+    create_info_frame(ui).show(ui, |ui| {
+        egui::Grid::new("network_overview")
+            .num_columns(2)
+            .spacing([HEADER_SPACING_LARGE, CONTENT_SPACING_SMALL])
+            .show(ui, |ui| {
+                ui.label("Validators");
+                ui.label(validators.len().to_string());
+                ui.end_row();
+
+                if !delinquent.is_empty() {
+                    ui.label("Delinquent").on_hover_text(
+                        "Validators the RPC node reports as not voting; their stake is left \
+                         out of the figures below",
+                    );
+                    ui.label(format!(
+                        "{} ({})",
+                        delinquent.len(),
+                        format_stake(delinquent.iter().map(|v| v.activated_stake).sum())
+                    ));
+                    ui.end_row();
+                }
+
+                ui.label("Active stake");
+                ui.label(format_stake(total_stake));
+                ui.end_row();
+
+                ui.label("Supply staked");
+                ui.label(
+                    total_supply
+                        .and_then(|supply| staked_supply_percent(total_stake, supply))
+                        .map_or_else(unknown, |percent| format!("{:.2}%", percent)),
+                );
+                ui.end_row();
+
+                ui.label("Nakamoto coefficient").on_hover_text(
+                    "Fewest validators whose combined stake exceeds one third of the total",
+                );
+                ui.label(nakamoto_coefficient(validators).to_string());
+                ui.end_row();
+
+                ui.label("Transactions per second");
+                ui.label(
+                    overview
+                        .and_then(|overview| overview.tps)
+                        .map_or_else(unknown, |tps| format!("{:.0}", tps)),
+                );
+                ui.end_row();
+
+                ui.label("Epoch progress");
+                match overview {
+                    Some(overview) if overview.slots_in_epoch > 0 => {
+                        let progress = overview.slot_index as f32 / overview.slots_in_epoch as f32;
+                        ui.add(
+                            egui::ProgressBar::new(progress)
+                                .desired_width(NETWORK_PROGRESS_WIDTH)
+                                .text(format!(
+                                    "Epoch {}: {:.1}% ({} / {} slots)",
+                                    overview.epoch,
+                                    progress * 100.0,
+                                    overview.slot_index,
+                                    overview.slots_in_epoch
+                                )),
+                        );
+                    }
+                    _ => {
+                        ui.label(unknown());
+                    }
+                }
+                ui.end_row();
+            });
+    });
+    if let Some(overview) = overview {
+        render_partial_failures(
+            ui,
+            "network_failed_steps",
+            "some figures could not be fetched and are shown as —",
+            &overview.failed_steps,
+        );
+    }
+
+    if validators.is_empty() {
+        return;
+    }
+
+    ui.add_space(HEADER_SPACING_MEDIUM);
+    ui.heading("Version distribution");
+    ui.add_space(CONTENT_SPACING_SMALL);
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        egui::Grid::new("network_versions")
+            .num_columns(2)
+            .striped(true)
+            .spacing([HEADER_SPACING_LARGE, CONTENT_SPACING_SMALL])
+            .show(ui, |ui| {
+                for (version, count) in version_distribution(validators) {
+                    let share = count as f32 / validators.len() as f32;
+                    ui.monospace(version);
+                    ui.add(
+                        egui::ProgressBar::new(share)
+                            .desired_width(NETWORK_PROGRESS_WIDTH)
+                            .text(format!("{} validators ({:.1}%)", count, share * 100.0)),
+                    );
+                    ui.end_row();
+                }
+            });
+    });
+}
@@ -1,16 +1,23 @@
 //! Update tab for checking and installing application updates.
 
+use chrono::{DateTime, Local};
 use eframe::egui;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use crate::updater::{UpdateStatus, Updater, ReleaseInfo};
+use crate::config::update_check_cache_path;
+use crate::updater::{CachedUpdateCheck, UpdateStatus, Updater, ReleaseInfo};
 use crate::tabs::logs::LogStore;
+use crate::utils::{icon_text, palette_color, StatusColor};
 
 pub struct UpdateTab {
     updater: Option<Updater>,
     update_status: Arc<Mutex<Option<UpdateStatus>>>,
+    /// When the shown status was fetched, possibly in an earlier run
+    last_checked: Arc<Mutex<Option<DateTime<Local>>>>,
+    /// Where successful checks are saved; nothing is persisted when unset
+    cache_path: Option<PathBuf>,
     is_checking: bool,
     is_downloading: bool,
     download_progress: f32,
@@ -24,6 +31,8 @@ impl Default for UpdateTab {
         Self {
             updater: None, // Will be initialized later with log store
             update_status: Arc::new(Mutex::new(None)),
+            last_checked: Arc::new(Mutex::new(None)),
+            cache_path: None,
             is_checking: false,
             is_downloading: false,
             download_progress: 0.0,
@@ -36,9 +45,14 @@ impl Default for UpdateTab {
 
 impl UpdateTab {
     pub fn new(log_store: LogStore) -> Self {
+        // Show the last known result straight away; Check for Updates refreshes it
+        let cache_path = update_check_cache_path();
+        let cached = CachedUpdateCheck::load(&cache_path);
         Self {
             updater: Some(Updater::new(log_store)),
-            update_status: Arc::new(Mutex::new(None)),
+            update_status: Arc::new(Mutex::new(cached.as_ref().map(|c| c.status.clone()))),
+            last_checked: Arc::new(Mutex::new(cached.map(|c| c.checked_at))),
+            cache_path: Some(cache_path),
             is_checking: false,
             is_downloading: false,
             download_progress: 0.0,
@@ -48,9 +62,16 @@ impl UpdateTab {
         }
     }
 
+    /// Download updates to `dir` instead of the system Downloads folder.
+    pub fn set_download_dir(&mut self, dir: Option<PathBuf>) {
+        if let Some(updater) = &mut self.updater {
+            updater.set_download_dir(dir);
+        }
+    }
+
 
     pub fn ui(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
-        ui.heading("🔄 Application Updates");
+        ui.heading(icon_text(ctx, "🔄", "Application Updates"));
         ui.separator();
         
         // Show keyboard shortcut info
@@ -78,9 +99,9 @@ impl UpdateTab {
             let check_button = ui.add_enabled(
                 !self.is_checking && !self.is_downloading,
                 egui::Button::new(if self.is_checking {
-                    "🔄 Checking..."
+                    icon_text(ctx, "🔄", "Checking...")
                 } else {
-                    "🔍 Check for Updates"
+                    icon_text(ctx, "🔍", "Check for Updates")
                 })
             );
 
@@ -90,15 +111,18 @@ impl UpdateTab {
 
             if self.is_checking {
                 ui.spinner();
-            }
+            } else if let Ok(last_checked) = self.last_checked.try_lock()
+                && let Some(checked_at) = *last_checked {
+                    ui.weak(format!("Last checked {}", checked_at.format("%Y-%m-%d %H:%M")));
+                }
         });
 
         ui.add_space(10.0);
 
         // Display update status and reset checking state when done
         let update_status = self.update_status.clone();
-        if let Ok(status) = update_status.try_lock() {
-            if let Some(ref status) = *status {
+        if let Ok(status) = update_status.try_lock()
+            && let Some(ref status) = *status {
                 // Reset checking state once we have results
                 if self.is_checking {
                     self.is_checking = false;
@@ -107,7 +131,7 @@ impl UpdateTab {
                 match status {
                     UpdateStatus::UpToDate => {
                         ui.colored_label(
-                            egui::Color32::from_rgb(34, 139, 34),
+                            palette_color(ctx, StatusColor::Success),
                             "✅ You're running the latest version!"
                         );
                     }
@@ -116,30 +140,29 @@ impl UpdateTab {
                     }
                     UpdateStatus::CheckFailed(error) => {
                         ui.colored_label(
-                            egui::Color32::from_rgb(220, 20, 60),
+                            palette_color(ctx, StatusColor::Error),
                             format!("❌ Check failed: {}", error)
                         );
                     }
                 }
             }
-        }
 
         // Show error messages
         if let Some(ref error) = self.error_message {
             ui.add_space(10.0);
-            ui.colored_label(egui::Color32::from_rgb(220, 20, 60), format!("❌ {}", error));
+            ui.colored_label(palette_color(ctx, StatusColor::Error), format!("❌ {}", error));
         }
 
         // Show success messages
         if let Some(ref success) = self.success_message {
             ui.add_space(10.0);
-            ui.colored_label(egui::Color32::from_rgb(34, 139, 34), format!("✅ {}", success));
+            ui.colored_label(palette_color(ctx, StatusColor::Success), format!("✅ {}", success));
         }
 
         // Check download status and update UI accordingly
         let download_status = self.download_status.clone();
-        if let Ok(status) = download_status.try_lock() {
-            if let Some(ref result) = *status {
+        if let Ok(status) = download_status.try_lock()
+            && let Some(ref result) = *status {
                 // Reset downloading state when we have a result
                 if self.is_downloading {
                     self.is_downloading = false;
@@ -149,7 +172,7 @@ impl UpdateTab {
                     Ok(dmg_path) => {
                         ui.add_space(10.0);
                         ui.colored_label(
-                            egui::Color32::from_rgb(34, 139, 34),
+                            palette_color(ctx, StatusColor::Success),
                             format!("✅ Downloaded to: {}", dmg_path.display())
                         );
                         ui.label("📂 The Downloads folder should have opened automatically");
@@ -158,13 +181,12 @@ impl UpdateTab {
                     Err(error) => {
                         ui.add_space(10.0);
                         ui.colored_label(
-                            egui::Color32::from_rgb(220, 20, 60),
+                            palette_color(ctx, StatusColor::Error),
                             format!("❌ Download failed: {}", error)
                         );
                     }
                 }
             }
-        }
 
         // Download progress
         if self.is_downloading {
@@ -196,7 +218,7 @@ impl UpdateTab {
             ui.horizontal(|ui| {
                 ui.label("New version:");
                 ui.colored_label(
-                    egui::Color32::from_rgb(34, 139, 34),
+                    palette_color(ctx, StatusColor::Success),
                     &release.tag_name
                 );
             });
@@ -235,9 +257,9 @@ impl UpdateTab {
                 let install_button = ui.add_enabled(
                     !self.is_downloading,
                     egui::Button::new(if self.is_downloading {
-                        "📥 Downloading..."
+                        icon_text(ctx, "📥", "Downloading...")
                     } else {
-                        "🚀 Install Update"
+                        icon_text(ctx, "🚀", "Install Update")
                     })
                 );
 
@@ -251,20 +273,35 @@ impl UpdateTab {
     }
 
     fn check_for_updates(&mut self, ctx: &egui::Context) {
-        if let Some(updater) = &self.updater {
-            if !self.is_checking {
+        if let Some(updater) = &self.updater
+            && !self.is_checking {
                 self.is_checking = true;
                 self.error_message = None;
                 self.success_message = None;
+                // A result still in place would end the check on the next frame
+                if let Ok(mut status) = self.update_status.try_lock() {
+                    *status = None;
+                }
                 
                 let updater_clone = updater.clone();
                 let status_clone = self.update_status.clone();
+                let last_checked_clone = self.last_checked.clone();
+                let cache_path = self.cache_path.clone();
                 let ctx_clone = ctx.clone();
                 
                 std::thread::spawn(move || {
                     let rt = tokio::runtime::Runtime::new().unwrap();
                     rt.block_on(async move {
                         let status = updater_clone.check_for_updates().await;
+                        // Failures keep the last good result on disk for the next start
+                        if !matches!(status, UpdateStatus::CheckFailed(_)) {
+                            let cached = CachedUpdateCheck::new(status.clone());
+                            if let Some(path) = &cache_path
+                                && let Err(e) = cached.save(path) {
+                                    eprintln!("Failed to save update check: {}", e);
+                                }
+                            *last_checked_clone.lock().await = Some(cached.checked_at);
+                        }
                         {
                             let mut status_guard = status_clone.lock().await;
                             *status_guard = Some(status);
@@ -273,12 +310,11 @@ impl UpdateTab {
                     });
                 });
             }
-        }
     }
 
     fn install_update(&mut self, release: ReleaseInfo, ctx: &egui::Context) {
-        if let Some(updater) = &self.updater {
-            if !self.is_downloading {
+        if let Some(updater) = &self.updater
+            && !self.is_downloading {
                 self.is_downloading = true;
                 self.download_progress = 0.0;
                 self.error_message = None;
@@ -317,6 +353,5 @@ impl UpdateTab {
                     });
                 });
             }
-        }
     }
 }
\ No newline at end of file
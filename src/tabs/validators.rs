@@ -1,63 +1,633 @@
 //! Validators tab functionality for the Solana UI application.
 
+use chrono::{DateTime, Local};
 use eframe::egui;
 use egui_extras::{Column, TableBuilder};
+use solana_sdk::pubkey::Pubkey;
 use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
+use crate::config::{AliasStore, ExternalLink};
 use crate::constants::*;
-use crate::solana::ValidatorInfo;
+use crate::solana::{PartialFailure, ValidatorInfo, VoteAccountBalance};
+use crate::tabs::logs::{self, LogStore};
 use crate::utils::{
-    create_error_frame, create_info_frame, format_skip_rate, format_stake, render_search_field,
-    SortColumn, SortDirection, SortState,
+    active_stake, apply_row_stripes, commission_bucket, commission_distribution,
+    create_error_frame, create_info_frame, credits_by_epoch, epochs_active, format_skip_rate,
+    format_sol_exact, format_stake, gini_coefficient, icon_text, is_outdated_version,
+    outdated_version_threshold, palette_color, pubkey_column_width, pubkey_search_key,
+    record_table_rect, refresh_button, render_error_frame, render_hold_toggle, render_last_updated,
+    render_partial_failures, render_pubkey, render_recent_searches, render_row_cap_notice,
+    render_search_field, rendered_rows, right_aligned, staked_supply_percent,
+    writable_download_dir, DisplaySettings, SortColumn, SortDirection, SortState, StatusColor,
+    COMMISSION_BUCKETS, COMMISSION_TIER_NAMES,
 };
 
+/// A commission change observed between two validator refreshes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommissionChange {
+    /// Identity public key of the validator
+    pub identity: Pubkey,
+    /// Vote account public key
+    pub vote_account: Pubkey,
+    /// Commission before the refresh
+    pub previous: u8,
+    /// Commission after the refresh
+    pub current: u8,
+}
+
+impl CommissionChange {
+    /// Whether the commission went up.
+    pub const fn is_increase(&self) -> bool {
+        self.current > self.previous
+    }
+}
+
+/// Watchlist import controls shown in the validators header.
+#[derive(Debug, Default)]
+pub struct WatchlistImport {
+    /// Path of the CSV or newline-delimited file to import
+    pub path: String,
+    /// Set when the user clicked Import this frame
+    pub requested: bool,
+    /// Outcome of the last import
+    pub status: Option<String>,
+}
+
+/// How epoch credits history is included in a validators CSV export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EpochCreditsExport {
+    /// Leave the history out
+    #[default]
+    Omit,
+    /// One `epoch_credits` column holding the history as a JSON array
+    JsonColumn,
+    /// One row per validator and epoch instead of one per validator
+    LongFormat,
+}
+
+impl EpochCreditsExport {
+    /// All formats, in menu order.
+    pub const fn all() -> &'static [Self] {
+        &[Self::Omit, Self::JsonColumn, Self::LongFormat]
+    }
+
+    /// Menu label.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Omit => "Without epoch credits",
+            Self::JsonColumn => "Epoch credits as a JSON column",
+            Self::LongFormat => "Long format (one row per epoch)",
+        }
+    }
+
+    /// Description of the columns written, shown in the export menu.
+    const fn description(self) -> &'static str {
+        match self {
+            Self::Omit => "One row per validator with the table's columns.",
+            Self::JsonColumn => {
+                "One row per validator; the last column, epoch_credits, holds \
+                 [[epoch, credits, previous_credits], ...] as a quoted JSON array."
+            }
+            Self::LongFormat => {
+                "One row per validator and epoch: identity, vote_account, epoch, credits, \
+                 previous_credits and earned (credits − previous_credits)."
+            }
+        }
+    }
+}
+
+/// Where a validators CSV export goes.
+enum ExportTarget {
+    Clipboard,
+    File,
+}
+
+/// Validators CSV export options and the outcome of the last export.
+#[derive(Debug, Default)]
+pub struct ValidatorExport {
+    pub epoch_credits: EpochCreditsExport,
+    /// Outcome of the last export
+    pub status: Option<String>,
+}
+
+/// On-demand vote account balance lookup shown in the detail drawer.
+#[derive(Debug, Clone)]
+pub enum VoteBalanceState {
+    Loading,
+    Loaded {
+        balance: VoteAccountBalance,
+        fetched_at: Instant,
+    },
+    Error {
+        message: String,
+        fetched_at: Instant,
+    },
+}
+
+impl VoteBalanceState {
+    /// Whether the lookup is in flight or recent enough to show without refetching.
+    pub fn is_fresh(&self) -> bool {
+        match self {
+            Self::Loading => true,
+            Self::Loaded { fetched_at, .. } | Self::Error { fetched_at, .. } => {
+                fetched_at.elapsed() < Duration::from_secs(VOTE_BALANCE_CACHE_SECS)
+            }
+        }
+    }
+}
+
+/// Vote account lookup that scrolls to a row instead of filtering the table.
+#[derive(Debug, Default)]
+pub struct VoteAccountJump {
+    /// Vote account, or a prefix of one, to look up
+    pub input: String,
+    /// Set when the user submitted the lookup this frame
+    pub requested: bool,
+    /// Outcome of the last lookup, shown when it found nothing
+    pub status: Option<String>,
+}
+
+/// Key command for the row cursor, applied against the rows as next drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowCursorCommand {
+    Previous,
+    Next,
+    OpenMenu,
+    ShowDetails,
+}
+
+/// Keyboard row cursor and its actions menu, so the table works without a mouse.
+#[derive(Debug, Default)]
+pub struct RowCursor {
+    /// Vote account of the row the cursor is on
+    pub vote_account: Option<Pubkey>,
+    /// Set by a shortcut this frame
+    pub pending: Option<RowCursorCommand>,
+    /// Whether the actions menu is open below the cursor row
+    pub menu_open: bool,
+    /// Move keyboard focus into the menu the frame it opens
+    focus_menu: bool,
+}
+
+/// Row action chosen in a row's actions menu.
+enum RowAction {
+    /// Copied or opened in place; only the menu has to close
+    Handled,
+    ToggleWatchlist,
+    ShowDetails,
+    /// Edit the alias of this identity or vote account
+    EditAlias(Pubkey),
+}
+
+/// Validators dataset frozen at a point in time for before/after comparison.
+#[derive(Debug, Clone)]
+pub struct ValidatorSnapshot {
+    /// When the snapshot was taken
+    pub taken_at: DateTime<Local>,
+    pub validators: Vec<ValidatorInfo>,
+}
+
+/// In-memory snapshots and the one the live data is compared against.
+#[derive(Debug, Default)]
+pub struct ValidatorSnapshots {
+    pub snapshots: Vec<ValidatorSnapshot>,
+    /// Index into `snapshots` of the compare target
+    pub compare_to: Option<usize>,
+    /// Diff for the compare target, keyed by `SnapshotDiffKey`
+    diff_cache: Option<(SnapshotDiffKey, Vec<SnapshotDiff>)>,
+}
+
+/// Identifies what a cached diff was built from: the compare index, the
+/// snapshot time and the generation of the live validators.
+type SnapshotDiffKey = (usize, DateTime<Local>, u64);
+
+impl ValidatorSnapshots {
+    /// The diff of `validators` against the compare target, recomputed only
+    /// when the target or the validators' `generation` changes.
+    pub fn diff(
+        &mut self,
+        validators: &[ValidatorInfo],
+        generation: u64,
+    ) -> Option<&[SnapshotDiff]> {
+        let index = self.compare_to?;
+        let snapshot = self.snapshots.get(index)?;
+        let key = (index, snapshot.taken_at, generation);
+        if self
+            .diff_cache
+            .as_ref()
+            .is_none_or(|(cached, _)| *cached != key)
+        {
+            let diffs = diff_against_snapshot(&snapshot.validators, validators);
+            self.diff_cache = Some((key, diffs));
+        }
+        self.diff_cache.as_ref().map(|(_, diffs)| diffs.as_slice())
+    }
+}
+
+/// How a validator differs between a snapshot and the live data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotChange {
+    /// Present now but not in the snapshot
+    Added,
+    /// Present in the snapshot but gone now
+    Removed,
+    /// Present in both with at least one field changed
+    Changed {
+        stake: Option<(u64, u64)>,
+        commission: Option<(u8, u8)>,
+        skip_rate: Option<(Option<f64>, Option<f64>)>,
+        delinquent: Option<(bool, bool)>,
+    },
+}
+
+/// A single row of the snapshot compare view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotDiff {
+    pub identity: Pubkey,
+    pub vote_account: Pubkey,
+    pub change: SnapshotChange,
+}
+
+/// Whether the RPC node flagged a validator as delinquent, or its last vote
+/// trails the newest vote in its dataset by more than `DELINQUENT_SLOT_DISTANCE`.
+fn is_delinquent(validator: &ValidatorInfo, newest_vote: u64) -> bool {
+    validator.delinquent
+        || newest_vote.saturating_sub(validator.last_vote) > DELINQUENT_SLOT_DISTANCE
+}
+
+/// Diff the live validators against a snapshot by vote account. Stake changes
+/// come first, largest first, followed by added and removed validators.
+pub fn diff_against_snapshot(
+    snapshot: &[ValidatorInfo],
+    validators: &[ValidatorInfo],
+) -> Vec<SnapshotDiff> {
+    let newest_vote = |list: &[ValidatorInfo]| list.iter().map(|v| v.last_vote).max().unwrap_or(0);
+    let (snapshot_newest, live_newest) = (newest_vote(snapshot), newest_vote(validators));
+    let before: HashMap<Pubkey, &ValidatorInfo> =
+        snapshot.iter().map(|v| (v.vote_account, v)).collect();
+    let live: HashMap<Pubkey, &ValidatorInfo> =
+        validators.iter().map(|v| (v.vote_account, v)).collect();
+
+    let mut diffs: Vec<SnapshotDiff> = validators
+        .iter()
+        .filter_map(|validator| {
+            let change = match before.get(&validator.vote_account) {
+                None => SnapshotChange::Added,
+                Some(prior) => {
+                    let stake = (prior.activated_stake != validator.activated_stake)
+                        .then_some((prior.activated_stake, validator.activated_stake));
+                    let commission = (prior.commission != validator.commission)
+                        .then_some((prior.commission, validator.commission));
+                    let skip_rate_changed = match (prior.skip_rate, validator.skip_rate) {
+                        (Some(before), Some(after)) => {
+                            (before - after).abs() >= SNAPSHOT_SKIP_RATE_EPSILON
+                        }
+                        (before, after) => before.is_some() != after.is_some(),
+                    };
+                    let skip_rate =
+                        skip_rate_changed.then_some((prior.skip_rate, validator.skip_rate));
+                    let delinquent = (
+                        is_delinquent(prior, snapshot_newest),
+                        is_delinquent(validator, live_newest),
+                    );
+                    let delinquent = (delinquent.0 != delinquent.1).then_some(delinquent);
+                    if stake.is_none()
+                        && commission.is_none()
+                        && skip_rate.is_none()
+                        && delinquent.is_none()
+                    {
+                        return None;
+                    }
+                    SnapshotChange::Changed {
+                        stake,
+                        commission,
+                        skip_rate,
+                        delinquent,
+                    }
+                }
+            };
+            Some(SnapshotDiff {
+                identity: validator.identity,
+                vote_account: validator.vote_account,
+                change,
+            })
+        })
+        .collect();
+    diffs.extend(
+        snapshot
+            .iter()
+            .filter(|prior| !live.contains_key(&prior.vote_account))
+            .map(|prior| SnapshotDiff {
+                identity: prior.identity,
+                vote_account: prior.vote_account,
+                change: SnapshotChange::Removed,
+            }),
+    );
+
+    let stake_delta = |diff: &SnapshotDiff| match diff.change {
+        SnapshotChange::Changed {
+            stake: Some((old, new)),
+            ..
+        } => old.abs_diff(new),
+        _ => 0,
+    };
+    let rank = |diff: &SnapshotDiff| match diff.change {
+        SnapshotChange::Changed { .. } => 0,
+        SnapshotChange::Added => 1,
+        SnapshotChange::Removed => 2,
+    };
+    diffs.sort_by(|a, b| {
+        rank(a)
+            .cmp(&rank(b))
+            .then_with(|| stake_delta(b).cmp(&stake_delta(a)))
+    });
+    diffs
+}
+
+/// Compare freshly fetched validators against the prior commissions keyed by vote account.
+pub fn detect_commission_changes(
+    previous_commissions: &HashMap<Pubkey, u8>,
+    validators: &[ValidatorInfo],
+) -> Vec<CommissionChange> {
+    validators
+        .iter()
+        .filter_map(|validator| {
+            let previous = *previous_commissions.get(&validator.vote_account)?;
+            (previous != validator.commission).then_some(CommissionChange {
+                identity: validator.identity,
+                vote_account: validator.vote_account,
+                previous,
+                current: validator.commission,
+            })
+        })
+        .collect()
+}
+
+/// Vote accounts whose commission, stake or version differ from the previous
+/// refresh, plus validators that newly appeared. Empty on the first load.
+pub fn detect_changed_validators(
+    previous: &[ValidatorInfo],
+    validators: &[ValidatorInfo],
+) -> Vec<Pubkey> {
+    if previous.is_empty() {
+        return Vec::new();
+    }
+
+    let previous: HashMap<Pubkey, &ValidatorInfo> =
+        previous.iter().map(|v| (v.vote_account, v)).collect();
+    validators
+        .iter()
+        .filter(|validator| {
+            previous.get(&validator.vote_account).is_none_or(|prior| {
+                prior.commission != validator.commission
+                    || prior.activated_stake != validator.activated_stake
+                    || prior.version != validator.version
+            })
+        })
+        .map(|validator| validator.vote_account)
+        .collect()
+}
+
 /// Parameters for the validators tab rendering.
 pub struct ValidatorsTabParams<'a> {
     pub validators: &'a [ValidatorInfo],
+    /// Bumped each time the validators list is replaced
+    pub validators_generation: u64,
     pub sort_states: &'a [SortState],
     pub search_term: &'a mut String,
-    pub error_message: &'a Option<String>,
+    /// Recently submitted search terms, newest first
+    pub recent_searches: &'a [String],
+    /// Why this tab's last fetch failed
+    pub error_message: Option<&'a str>,
     pub is_loading: bool,
     pub should_focus_search: bool,
+    pub watchlist: &'a [String],
+    pub external_links: &'a [ExternalLink],
+    pub commission_changes: &'a HashMap<Pubkey, CommissionChange>,
+    /// When each validator last changed between refreshes, for the row highlight
+    pub changed_at: &'a HashMap<Pubkey, Instant>,
+    pub commission_alerts: &'a [CommissionChange],
+    pub commission_alert_notify: &'a mut bool,
+    /// Vote account of the validator shown in the detail drawer
+    pub selected_validator: &'a mut Option<Pubkey>,
+    /// Set when a row's context menu asks to edit the alias of a pubkey
+    pub alias_edit: &'a mut Option<Pubkey>,
+    /// Friendly names shown in place of pubkeys
+    pub aliases: &'a AliasStore,
+    /// Balance lookup for the validator shown in the detail drawer
+    pub vote_balance: Option<&'a VoteBalanceState>,
+    /// Total token supply in lamports, once fetched
+    pub total_supply: Option<u64>,
+    /// Current slot, once fetched, for the vote lag column
+    pub current_slot: Option<u64>,
+    /// When the validators were last fetched
+    pub last_updated: Option<DateTime<Local>>,
+    /// Validator count before the list was capped to `max_validators`
+    pub capped_from: Option<usize>,
+    /// Lookups of the last refresh that failed, leaving some columns at their defaults
+    pub failed_steps: &'a [PartialFailure],
+    /// Configured minimum version, overriding the most common one
+    pub min_validator_version: Option<&'a str>,
+    /// Only list validators running an outdated version
+    pub outdated_only: &'a mut bool,
+    /// Only list validators in this `COMMISSION_BUCKETS` bucket
+    pub commission_tier: &'a mut Option<usize>,
+    pub watchlist_import: &'a mut WatchlistImport,
+    pub snapshots: &'a mut ValidatorSnapshots,
+    pub jump: &'a mut VoteAccountJump,
+    /// Weight the commission chart by stake instead of validator count
+    pub commission_chart_stake_weighted: &'a mut bool,
+    pub export: &'a mut ValidatorExport,
+    /// Where exports are recorded
+    pub log_store: &'a LogStore,
+    /// Show a frozen copy of the data instead of the latest refresh
+    pub hold: &'a mut bool,
+    pub row_cursor: &'a mut RowCursor,
 }
 
 /// Render the validators tab content.
 pub fn render_validators_tab(
     ui: &mut egui::Ui,
     params: ValidatorsTabParams,
-    mut on_sort: impl FnMut(SortColumn, bool),
+    on_sort: impl FnMut(SortColumn, bool),
     mut on_refresh: impl FnMut(),
+    mut on_toggle_watchlist: impl FnMut(&Pubkey),
+    mut on_dismiss_alerts: impl FnMut(),
+    mut on_search_commit: impl FnMut(),
 ) {
     let ValidatorsTabParams {
         validators,
+        validators_generation,
         sort_states,
         search_term,
+        recent_searches,
         error_message,
         is_loading,
         should_focus_search,
+        watchlist,
+        external_links,
+        commission_changes,
+        changed_at,
+        commission_alerts,
+        commission_alert_notify,
+        selected_validator,
+        alias_edit,
+        aliases,
+        vote_balance,
+        total_supply,
+        current_slot,
+        last_updated,
+        capped_from,
+        failed_steps,
+        min_validator_version,
+        outdated_only,
+        commission_tier,
+        watchlist_import,
+        snapshots,
+        jump,
+        commission_chart_stake_weighted,
+        export,
+        log_store,
+        hold,
+        row_cursor,
     } = params;
+    let outdated_threshold = outdated_version_threshold(validators, min_validator_version);
+    let mut export_request = None;
     ui.horizontal(|ui| {
         ui.heading("Solana Validators");
         ui.add_space(HEADER_SPACING_LARGE);
 
         // Search bar near headline
-        ui.label("🔍 Search:");
+        ui.label(icon_text(ui.ctx(), "🔍", "Search:"));
         ui.add_space(CONTENT_SPACING_SMALL);
-        let _search_response = render_search_field(
+        let search_response = render_search_field(
             ui,
             search_term,
             "Search validators...",
             should_focus_search,
             SEARCH_FIELD_WIDTH,
         );
+        let picked = render_recent_searches(ui, search_term, recent_searches);
+        if picked || (search_response.lost_focus() && !search_term.trim().is_empty()) {
+            on_search_commit();
+        }
+
+        ui.add_space(HEADER_SPACING_TINY);
+        let outdated_hint = match outdated_threshold {
+            Some(threshold) => format!(
+                "Only show validators running a version older than {}",
+                format_version(threshold)
+            ),
+            None => "No validator versions known yet".to_string(),
+        };
+        ui.add_enabled(
+            outdated_threshold.is_some(),
+            egui::Checkbox::new(outdated_only, "Outdated only"),
+        )
+        .on_hover_text(outdated_hint);
+
+        ui.add_space(HEADER_SPACING_TINY);
+        egui::ComboBox::from_id_salt("validators_commission_tier")
+            .selected_text(
+                commission_tier.map_or("Any commission", |tier| COMMISSION_TIER_NAMES[tier]),
+            )
+            .show_ui(ui, |ui| {
+                ui.selectable_value(commission_tier, None, "Any commission");
+                for (tier, name) in COMMISSION_TIER_NAMES.iter().enumerate() {
+                    ui.selectable_value(commission_tier, Some(tier), *name);
+                }
+            })
+            .response
+            .on_hover_text("Only show validators in a commission tier");
+
+        ui.add_space(HEADER_SPACING_TINY);
+        let jump_response = ui
+            .add_sized(
+                [JUMP_FIELD_WIDTH, SEARCH_FIELD_HEIGHT],
+                egui::TextEdit::singleline(&mut jump.input).hint_text("Jump to vote account..."),
+            )
+            .on_hover_text("Scroll to and select the validator with this vote account (or prefix)");
+        let submitted = jump_response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+        if (ui.button("Go").clicked() || submitted) && !jump.input.trim().is_empty() {
+            jump.requested = true;
+        }
+        if let Some(status) = &jump.status {
+            ui.colored_label(WARNING_COLOR, status);
+        }
 
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-            let button = ui
-                .button("🔄 Refresh Validators")
-                .on_hover_text("Refresh validators data (Cmd+R / Ctrl+R)");
+            let button = refresh_button(
+                ui,
+                is_loading,
+                None,
+                icon_text(ui.ctx(), "🔄", "Refresh Validators"),
+                "Refresh validators data (Cmd+R / Ctrl+R)",
+            );
             if button.clicked() {
                 on_refresh();
             }
+            render_hold_toggle(ui, hold);
+
+            if ui
+                .add_enabled(
+                    !validators.is_empty(),
+                    egui::Button::new(icon_text(ui.ctx(), "📸", "Take Snapshot")),
+                )
+                .on_hover_text("Freeze the current validators to compare against later")
+                .clicked()
+            {
+                snapshots.snapshots.push(ValidatorSnapshot {
+                    taken_at: Local::now(),
+                    validators: validators.to_vec(),
+                });
+                snapshots.compare_to = Some(snapshots.snapshots.len() - 1);
+            }
+
+            ui.menu_button(icon_text(ui.ctx(), "📥", "Import Watchlist"), |ui| {
+                render_watchlist_import(ui, watchlist_import);
+            })
+            .response
+            .on_hover_text("Add validator identities from a CSV or newline-delimited file");
+
+            ui.add_enabled_ui(!validators.is_empty(), |ui| {
+                ui.menu_button(icon_text(ui.ctx(), "📋", "Copy Mapping"), |ui| {
+                    if ui.button("Copy as CSV").clicked() {
+                        ui.ctx().copy_text(identity_vote_mapping_csv(validators));
+                        ui.close();
+                    }
+                    if ui.button("Copy as JSON").clicked() {
+                        ui.ctx().copy_text(identity_vote_mapping_json(validators));
+                        ui.close();
+                    }
+                })
+                .response
+                .on_hover_text("Copy the identity → vote account mapping of all validators");
+
+                ui.menu_button(icon_text(ui.ctx(), "⬇", "Export CSV"), |ui| {
+                    for &format in EpochCreditsExport::all() {
+                        ui.radio_value(&mut export.epoch_credits, format, format.name());
+                    }
+                    ui.label(egui::RichText::new(export.epoch_credits.description()).weak());
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        if ui.button("Copy").clicked() {
+                            export_request = Some(ExportTarget::Clipboard);
+                            ui.close();
+                        }
+                        if ui.button("Save…").clicked() {
+                            export_request = Some(ExportTarget::File);
+                            ui.close();
+                        }
+                    });
+                    if let Some(status) = &export.status {
+                        ui.label(status);
+                    }
+                })
+                .response
+                .on_hover_text("Export the validators as currently filtered and sorted");
+            });
         });
     });
     ui.add_space(HEADER_SPACING_SMALL);
@@ -68,46 +638,890 @@ pub fn render_validators_tab(
     render_sort_info(ui, sort_states);
 
     if let Some(error) = error_message {
-        let frame = create_error_frame();
-
-        frame.show(ui, |ui| {
-            ui.colored_label(egui::Color32::RED, format!("❌ Error: {}", error));
-        });
+        render_error_frame(ui, "Validators", error);
         ui.add_space(HEADER_SPACING_MEDIUM);
     }
 
+    if !commission_alerts.is_empty() {
+        render_commission_alerts(
+            ui,
+            commission_alerts,
+            commission_alert_notify,
+            &mut on_dismiss_alerts,
+        );
+    }
+
     if validators.is_empty() && !is_loading {
         ui.label("No validators data. Click 'Refresh Validators' to load validators.");
         return;
     }
 
+    render_aggregate_panel(ui, validators, total_supply, commission_chart_stake_weighted);
+
+    if !snapshots.snapshots.is_empty() {
+        render_snapshot_compare(ui, snapshots, validators, validators_generation, aliases);
+    }
+
+    if let Some(vote_account) = *selected_validator {
+        match validators.iter().find(|v| v.vote_account == vote_account) {
+            Some(validator) => {
+                let mut open = true;
+                egui::SidePanel::right("validator_detail_drawer")
+                    .resizable(true)
+                    .default_width(DETAIL_DRAWER_WIDTH)
+                    .show_inside(ui, |ui| {
+                        render_validator_detail(ui, validator, validators, vote_balance, &mut open);
+                    });
+                if !open {
+                    *selected_validator = None;
+                }
+            }
+            // The validator dropped out of the latest refresh
+            None => *selected_validator = None,
+        }
+    }
+
+    // Keep repainting while a changed row is still fading out
+    if changed_at
+        .values()
+        .any(|at| at.elapsed().as_secs_f32() < REFRESH_FLASH_SECS)
+    {
+        ui.ctx().request_repaint();
+    }
+
     // Apply filtering
-    let filtered_validators = filter_validators(validators, search_term);
+    let case_sensitive = DisplaySettings::get(ui.ctx()).case_sensitive_pubkey_search;
+    let mut filtered_validators = filter_validators(validators, search_term, case_sensitive);
+    if *outdated_only {
+        filtered_validators.retain(|v| is_outdated_version(&v.version, outdated_threshold));
+    }
+    if let Some(tier) = *commission_tier {
+        filtered_validators.retain(|v| commission_bucket(v.commission) == tier);
+    }
 
     // Show filter results info
-    if !search_term.is_empty() {
-        ui.horizontal(|ui| {
+    ui.horizontal(|ui| {
+        if !search_term.is_empty() || *outdated_only || commission_tier.is_some() {
             ui.label(format!(
                 "📊 Showing {} of {} validators (filtered)",
                 filtered_validators.len(),
                 validators.len()
             ));
-        });
-    } else {
-        ui.horizontal(|ui| {
+        } else {
             ui.label(format!(
                 "📊 Showing {} validators",
                 filtered_validators.len()
             ));
-        });
-    }
+        }
+        render_last_updated(ui, last_updated);
+        render_row_cap_notice(ui, filtered_validators.len());
 
-    // Apply sorting
+        if let Some(total) = capped_from {
+            ui.colored_label(
+                WARNING_COLOR,
+                format!(
+                    "⚠ Capped to the top {} of {} by stake",
+                    validators.len(),
+                    total
+                ),
+            )
+            .on_hover_text("Set max_validators in the config file to change or remove the cap");
+        }
+    });
+    render_partial_failures(
+        ui,
+        "validators_failed_steps",
+        "validator versions could not be fetched and are shown as Unknown",
+        failed_steps,
+    );
+
+    // Apply sorting, then float pubkey prefix matches above mid-string matches
     let mut sorted_validators = filtered_validators;
     sort_validators(&mut sorted_validators, sort_states);
+    rank_search_matches(&mut sorted_validators, search_term, case_sensitive);
+
+    if let Some(target) = export_request {
+        let csv = validators_csv(&sorted_validators, export.epoch_credits);
+        export.status = Some(match target {
+            ExportTarget::Clipboard => {
+                ui.ctx().copy_text(csv);
+                let status = format!("Copied {} validators", sorted_validators.len());
+                logs::log_update(log_store, "validators_export", &status, "Copied");
+                status
+            }
+            ExportTarget::File => {
+                let file_name = format!(
+                    "solana-ui-validators-{}.csv",
+                    Local::now().format("%Y%m%d-%H%M%S")
+                );
+                let mut dialog = rfd::FileDialog::new()
+                    .set_title("Export validators")
+                    .set_file_name(&file_name)
+                    .add_filter("CSV", &["csv"]);
+                if let Ok(dir) = writable_download_dir(None) {
+                    dialog = dialog.set_directory(dir);
+                }
+                match dialog.save_file().map(|path| {
+                    std::fs::write(&path, csv)
+                        .map(|()| path)
+                        .map_err(|e| e.to_string())
+                }) {
+                    None => "Export cancelled".to_string(),
+                    Some(Ok(path)) => {
+                        logs::log_update(
+                            log_store,
+                            "validators_export",
+                            &format!(
+                                "Saved {} validators to {}",
+                                sorted_validators.len(),
+                                path.display()
+                            ),
+                            "Saved",
+                        );
+                        format!("Saved {}", path.display())
+                    }
+                    Some(Err(e)) => {
+                        logs::log_error(log_store, "validators_export", &file_name, &e);
+                        format!("Export failed: {}", e)
+                    }
+                }
+            }
+        });
+    }
+
+    // Resolve a vote account lookup against the rows as currently shown
+    let rendered_validators = rendered_rows(ui.ctx(), &sorted_validators);
+    let mut scroll_to = None;
+    if jump.requested {
+        jump.requested = false;
+        let query = jump.input.trim();
+        match rendered_validators
+            .iter()
+            .find(|v| v.vote_account.to_string().starts_with(query))
+        {
+            Some(validator) => {
+                *selected_validator = Some(validator.vote_account);
+                scroll_to = Some(validator.vote_account);
+                jump.status = None;
+            }
+            None if sorted_validators
+                .iter()
+                .any(|v| v.vote_account.to_string().starts_with(query)) =>
+            {
+                jump.status = Some("Past the rendered row limit; refine your search".to_string());
+            }
+            None if validators
+                .iter()
+                .any(|v| v.vote_account.to_string().starts_with(query)) =>
+            {
+                jump.status = Some("Hidden by the current filters".to_string());
+            }
+            None => jump.status = Some("No validator with this vote account".to_string()),
+        }
+    }
+    if let Some(command) = row_cursor.pending.take() {
+        scroll_to =
+            apply_row_cursor_command(row_cursor, command, rendered_validators, selected_validator)
+                .or(scroll_to);
+    }
 
     // Create table
-    render_validators_table(ui, &sorted_validators, sort_states, on_sort);
+    let cursor_rect = record_table_rect(ui, |ui| {
+        render_validators_table(
+            ui,
+            ValidatorsTableParams {
+                validators: rendered_validators,
+                sort_states,
+                watchlist,
+                external_links,
+                commission_changes,
+                changed_at,
+                selected_validator,
+                alias_edit,
+                aliases,
+                outdated_threshold,
+                current_slot,
+                scroll_to,
+                row_cursor: &mut row_cursor.vote_account,
+            },
+            on_sort,
+            &mut on_toggle_watchlist,
+        )
+    });
+
+    if row_cursor.menu_open {
+        let cursor_row = row_cursor.vote_account.and_then(|vote_account| {
+            rendered_validators
+                .iter()
+                .find(|v| v.vote_account == vote_account)
+        });
+        let Some(validator) = cursor_row else {
+            // The cursor row was filtered out or dropped by a refresh
+            row_cursor.menu_open = false;
+            return;
+        };
+        let anchor = cursor_rect.unwrap_or(ui.max_rect()).left_bottom();
+        let is_watchlisted = watchlist.contains(&validator.identity.to_string());
+        if let Some(action) = render_row_actions_menu(
+            ui,
+            anchor,
+            validator,
+            is_watchlisted,
+            external_links,
+            row_cursor,
+        ) {
+            apply_row_action(
+                action,
+                validator,
+                &mut on_toggle_watchlist,
+                selected_validator,
+                alias_edit,
+            );
+        }
+    }
+}
+
+/// Apply a row cursor key against the rows as currently shown. Returns the row
+/// to scroll into view, if the cursor moved.
+fn apply_row_cursor_command(
+    cursor: &mut RowCursor,
+    command: RowCursorCommand,
+    rows: &[ValidatorInfo],
+    selected_validator: &mut Option<Pubkey>,
+) -> Option<Pubkey> {
+    // Arrow keys and Enter belong to the menu's buttons while it is open
+    if cursor.menu_open {
+        return None;
+    }
+    // Start from the drawer's validator when the cursor hasn't been placed yet
+    let position = cursor
+        .vote_account
+        .or(*selected_validator)
+        .and_then(|vote_account| rows.iter().position(|v| v.vote_account == vote_account));
+    match command {
+        RowCursorCommand::Previous | RowCursorCommand::Next => {
+            let index = match (command, position) {
+                (_, None) => 0,
+                (RowCursorCommand::Previous, Some(index)) => index.saturating_sub(1),
+                (_, Some(index)) => (index + 1).min(rows.len().saturating_sub(1)),
+            };
+            cursor.vote_account = rows.get(index).map(|v| v.vote_account);
+            cursor.vote_account
+        }
+        RowCursorCommand::OpenMenu => {
+            cursor.vote_account = position.map(|index| rows[index].vote_account);
+            cursor.menu_open = position.is_some();
+            cursor.focus_menu = cursor.menu_open;
+            cursor.vote_account
+        }
+        RowCursorCommand::ShowDetails => {
+            if let Some(index) = position {
+                cursor.vote_account = Some(rows[index].vote_account);
+                *selected_validator = cursor.vote_account;
+            }
+            None
+        }
+    }
+}
+
+/// Render the keyboard-opened actions menu below the cursor row. Escape, a
+/// click anywhere or choosing an entry closes it.
+fn render_row_actions_menu(
+    ui: &egui::Ui,
+    anchor: egui::Pos2,
+    validator: &ValidatorInfo,
+    is_watchlisted: bool,
+    external_links: &[ExternalLink],
+    cursor: &mut RowCursor,
+) -> Option<RowAction> {
+    let focus_first = std::mem::take(&mut cursor.focus_menu);
+    let mut action = None;
+    egui::Area::new(egui::Id::new("validator_row_actions"))
+        .order(egui::Order::Foreground)
+        .fixed_pos(anchor)
+        .show(ui.ctx(), |ui| {
+            egui::Frame::menu(ui.style()).show(ui, |ui| {
+                action =
+                    render_row_actions(ui, validator, is_watchlisted, external_links, focus_first);
+            });
+        });
+
+    let dismissed = ui.input(|i| i.key_pressed(egui::Key::Escape) || i.pointer.any_click());
+    if action.is_some() || dismissed {
+        cursor.menu_open = false;
+    }
+    action
+}
+
+/// Render the banner listing commission increases on watchlisted validators.
+fn render_commission_alerts(
+    ui: &mut egui::Ui,
+    alerts: &[CommissionChange],
+    notify: &mut bool,
+    on_dismiss: &mut impl FnMut(),
+) {
+    let frame = create_error_frame(ui.ctx());
+
+    frame.show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.colored_label(
+                COMMISSION_ALERT_COLOR,
+                format!(
+                    "⚠ Commission increased on {} watchlisted validator(s)",
+                    alerts.len()
+                ),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button("Dismiss").clicked() {
+                    on_dismiss();
+                }
+                ui.checkbox(notify, icon_text(ui.ctx(), "🔔", "Notify"))
+                    .on_hover_text("Request window attention when a new alert arrives");
+            });
+        });
+        for alert in alerts {
+            ui.monospace(format!(
+                "{}  {}% → {}%",
+                alert.identity, alert.previous, alert.current
+            ));
+        }
+    });
+    ui.add_space(HEADER_SPACING_MEDIUM);
+}
+
+/// Render the snapshot picker and the diff of the live data against it.
+fn render_snapshot_compare(
+    ui: &mut egui::Ui,
+    snapshots: &mut ValidatorSnapshots,
+    validators: &[ValidatorInfo],
+    validators_generation: u64,
+    aliases: &AliasStore,
+) {
+    let snapshot_label = |index: usize, snapshot: &ValidatorSnapshot| {
+        format!(
+            "#{} at {} ({} validators)",
+            index + 1,
+            snapshot.taken_at.format("%H:%M:%S"),
+            snapshot.validators.len()
+        )
+    };
+
+    egui::CollapsingHeader::new(icon_text(
+        ui.ctx(),
+        "📸",
+        &format!("Compare with snapshot ({})", snapshots.snapshots.len()),
+    ))
+    .id_salt("validator_snapshot_compare")
+    .default_open(true)
+    .show(ui, |ui| {
+        ui.horizontal(|ui| {
+            let selected_text = snapshots
+                .compare_to
+                .and_then(|index| Some(snapshot_label(index, snapshots.snapshots.get(index)?)))
+                .unwrap_or_else(|| "Choose a snapshot".to_string());
+            egui::ComboBox::from_id_salt("validator_snapshot_picker")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    for (index, snapshot) in snapshots.snapshots.iter().enumerate() {
+                        ui.selectable_value(
+                            &mut snapshots.compare_to,
+                            Some(index),
+                            snapshot_label(index, snapshot),
+                        );
+                    }
+                });
+            if ui.button("Clear Snapshots").clicked() {
+                *snapshots = ValidatorSnapshots::default();
+            }
+        });
+
+        let Some(diffs) = snapshots.diff(validators, validators_generation) else {
+            return;
+        };
+        if diffs.is_empty() {
+            ui.label("No changes since this snapshot.");
+            return;
+        }
+
+        ui.label(format!(
+            "{} validator(s) differ from the snapshot",
+            diffs.len()
+        ));
+        egui::ScrollArea::vertical()
+            .id_salt("validator_snapshot_diffs")
+            .max_height(SNAPSHOT_DIFF_HEIGHT)
+            .show(ui, |ui| {
+                egui::Grid::new("validator_snapshot_diff_grid")
+                    .num_columns(5)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for header in ["Identity", "Stake", "Commission", "Skip Rate", "Delinquent"]
+                        {
+                            ui.strong(header);
+                        }
+                        ui.end_row();
+
+                        for diff in diffs {
+                            render_pubkey(ui, aliases, &diff.identity.to_string());
+                            match &diff.change {
+                                SnapshotChange::Added => {
+                                    ui.colored_label(
+                                        palette_color(ui.ctx(), StatusColor::Success),
+                                        "Added",
+                                    );
+                                    for _ in 0..3 {
+                                        ui.label("");
+                                    }
+                                }
+                                SnapshotChange::Removed => {
+                                    ui.colored_label(WARNING_COLOR, "Removed");
+                                    for _ in 0..3 {
+                                        ui.label("");
+                                    }
+                                }
+                                SnapshotChange::Changed {
+                                    stake,
+                                    commission,
+                                    skip_rate,
+                                    delinquent,
+                                } => {
+                                    let unchanged = || "—".to_string();
+                                    ui.label(stake.map_or_else(unchanged, |(old, new)| {
+                                        format!("{} → {}", format_stake(old), format_stake(new))
+                                    }));
+                                    ui.label(commission.map_or_else(unchanged, |(old, new)| {
+                                        format!("{}% → {}%", old, new)
+                                    }));
+                                    ui.label(skip_rate.map_or_else(unchanged, |(old, new)| {
+                                        format!(
+                                            "{} → {}",
+                                            format_skip_rate(old),
+                                            format_skip_rate(new)
+                                        )
+                                    }));
+                                    ui.label(delinquent.map_or("—", |(_, new)| {
+                                        if new {
+                                            "Now delinquent"
+                                        } else {
+                                            "Recovered"
+                                        }
+                                    }));
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    });
+    ui.add_space(HEADER_SPACING_SMALL);
+}
+
+/// Build a two-column CSV of identity → vote account for all validators.
+pub fn identity_vote_mapping_csv(validators: &[ValidatorInfo]) -> String {
+    let mut csv = String::from("identity,vote_account\n");
+    for validator in validators {
+        csv.push_str(&format!(
+            "{},{}\n",
+            validator.identity, validator.vote_account
+        ));
+    }
+    csv
+}
+
+/// Build a CSV of the given validators, with their epoch credits history in
+/// the requested format.
+pub fn validators_csv(validators: &[ValidatorInfo], epoch_credits: EpochCreditsExport) -> String {
+    if epoch_credits == EpochCreditsExport::LongFormat {
+        let mut csv = String::from("identity,vote_account,epoch,credits,previous_credits,earned\n");
+        for validator in validators {
+            for &(epoch, credits, previous) in &validator.epoch_credits {
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{}\n",
+                    validator.identity,
+                    validator.vote_account,
+                    epoch,
+                    credits,
+                    previous,
+                    credits.saturating_sub(previous)
+                ));
+            }
+        }
+        return csv;
+    }
+
+    let mut csv = String::from(
+        "identity,vote_account,commission,last_vote,root_slot,vote_credits,skip_rate,\
+         activated_stake_sol,version,delinquent",
+    );
+    if epoch_credits == EpochCreditsExport::JsonColumn {
+        csv.push_str(",epoch_credits");
+    }
+    csv.push('\n');
+    for validator in validators {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            validator.identity,
+            validator.vote_account,
+            validator.commission,
+            validator.last_vote,
+            validator.root_slot,
+            validator.vote_credits,
+            validator
+                .skip_rate
+                .map(|rate| format!("{:.2}", rate))
+                .unwrap_or_default(),
+            format_sol_exact(validator.activated_stake),
+            validator.version,
+            validator.delinquent
+        ));
+        if epoch_credits == EpochCreditsExport::JsonColumn {
+            // The array holds only numbers, so quoting it is enough to escape its commas
+            let json = serde_json::to_string(&validator.epoch_credits).unwrap_or_default();
+            csv.push_str(&format!(",\"{}\"", json));
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Build a JSON array of `{identity, vote_account}` objects for all validators.
+pub fn identity_vote_mapping_json(validators: &[ValidatorInfo]) -> String {
+    let mapping: Vec<serde_json::Value> = validators
+        .iter()
+        .map(|validator| {
+            serde_json::json!({
+                "identity": validator.identity.to_string(),
+                "vote_account": validator.vote_account.to_string(),
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&mapping).unwrap_or_default()
+}
+
+/// Render the watchlist import menu contents.
+fn render_watchlist_import(ui: &mut egui::Ui, watchlist_import: &mut WatchlistImport) {
+    ui.label("File of identities (first CSV column or one per line):");
+    ui.horizontal(|ui| {
+        ui.add_sized(
+            [SMALL_SEARCH_FIELD_WIDTH, SEARCH_FIELD_HEIGHT],
+            egui::TextEdit::singleline(&mut watchlist_import.path)
+                .hint_text("/path/to/watchlist.csv"),
+        );
+        if ui
+            .add_enabled(
+                !watchlist_import.path.trim().is_empty(),
+                egui::Button::new("Import"),
+            )
+            .clicked()
+        {
+            watchlist_import.requested = true;
+        }
+    });
+    if let Some(status) = &watchlist_import.status {
+        ui.label(status);
+    }
+}
+
+/// Render cluster-wide aggregates for the fetched validators.
+fn render_aggregate_panel(
+    ui: &mut egui::Ui,
+    validators: &[ValidatorInfo],
+    total_supply: Option<u64>,
+    commission_chart_stake_weighted: &mut bool,
+) {
+    if validators.is_empty() {
+        return;
+    }
+
+    let total_stake = active_stake(validators);
+    let frame = create_info_frame(ui);
+
+    frame.show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(format!("Validators: {}", validators.len()));
+            ui.separator();
+            ui.label(format!("Total stake: {}", format_stake(total_stake)));
+            ui.separator();
+            if let Some(supply) = total_supply
+                && let Some(percent) = staked_supply_percent(total_stake, supply)
+            {
+                ui.label(format!("{:.2}% of supply staked", percent))
+                    .on_hover_text(format!(
+                        "Derived: total activated stake of current validators ({}) \
+                         divided by total supply from getSupply ({})",
+                        format_stake(total_stake),
+                        format_stake(supply)
+                    ));
+                ui.separator();
+            }
+            ui.label(format!("Stake Gini: {:.3}", gini_coefficient(validators)))
+                .on_hover_text(
+                    "Gini coefficient of activated stake: 0 means evenly spread, \
+                     values near 1 mean stake is concentrated in few validators",
+                );
+        });
+        ui.add_space(CONTENT_SPACING_SMALL);
+        render_commission_chart(ui, validators, commission_chart_stake_weighted);
+    });
+    ui.add_space(HEADER_SPACING_SMALL);
+}
+
+/// Render the commission distribution as a bar chart, one bar per commission bucket.
+fn render_commission_chart(
+    ui: &mut egui::Ui,
+    validators: &[ValidatorInfo],
+    stake_weighted: &mut bool,
+) {
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("Commission distribution").strong());
+        ui.checkbox(stake_weighted, "Stake-weighted")
+            .on_hover_text("Size bars by activated stake instead of validator count");
+    });
+
+    let values = commission_distribution(validators, *stake_weighted);
+    let total: u64 = values.iter().sum();
+    let max_value = values.iter().copied().max().unwrap_or(0).max(1);
+
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(COMMISSION_CHART_WIDTH, COMMISSION_CHART_HEIGHT),
+        egui::Sense::hover(),
+    );
+    let painter = ui.painter_at(rect);
+    let font = egui::TextStyle::Small.resolve(ui.style());
+    let text_color = ui.visuals().text_color();
+    // Leave room for the bucket label below and the value above each bar
+    let label_height = font.size + CONTENT_SPACING_SMALL;
+    let bars_bottom = rect.bottom() - label_height;
+    let max_bar_height = bars_bottom - rect.top() - label_height;
+    let bar_width = rect.width() / values.len() as f32;
+
+    for (index, (value, (label, _, _))) in values.iter().zip(COMMISSION_BUCKETS).enumerate() {
+        let left = rect.left() + index as f32 * bar_width;
+        let height = max_bar_height * (*value as f32 / max_value as f32);
+        let bar = egui::Rect::from_min_max(
+            egui::pos2(left + COMMISSION_CHART_BAR_GAP / 2.0, bars_bottom - height),
+            egui::pos2(left + bar_width - COMMISSION_CHART_BAR_GAP / 2.0, bars_bottom),
+        );
+        painter.rect_filled(bar, 0.0, COMMISSION_CHART_COLOR);
+
+        let value_text = if *stake_weighted {
+            format!("{:.1}%", *value as f64 / total.max(1) as f64 * 100.0)
+        } else {
+            value.to_string()
+        };
+        painter.text(
+            egui::pos2(bar.center().x, bar.top()),
+            egui::Align2::CENTER_BOTTOM,
+            value_text,
+            font.clone(),
+            text_color,
+        );
+        painter.text(
+            egui::pos2(bar.center().x, rect.bottom()),
+            egui::Align2::CENTER_BOTTOM,
+            label,
+            font.clone(),
+            text_color,
+        );
+    }
+}
+
+/// 1-based rank of a validator by activated stake, highest stake first.
+fn stake_rank(validator: &ValidatorInfo, validators: &[ValidatorInfo]) -> usize {
+    validators
+        .iter()
+        .filter(|v| v.activated_stake > validator.activated_stake)
+        .count()
+        + 1
+}
+
+/// Render the detail drawer for the selected validator.
+fn render_validator_detail(
+    ui: &mut egui::Ui,
+    validator: &ValidatorInfo,
+    validators: &[ValidatorInfo],
+    vote_balance: Option<&VoteBalanceState>,
+    open: &mut bool,
+) {
+    ui.horizontal(|ui| {
+        ui.heading("Validator Details");
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            if ui.button(icon_text(ui.ctx(), "✖", "Close")).clicked() {
+                *open = false;
+            }
+        });
+    });
+    ui.separator();
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        for (title, pubkey) in [
+            ("Identity", validator.identity),
+            ("Vote Account", validator.vote_account),
+        ] {
+            ui.label(egui::RichText::new(title).strong());
+            ui.horizontal(|ui| {
+                ui.monospace(pubkey.to_string());
+                if ui
+                    .small_button(icon_text(ui.ctx(), "📋", "Copy"))
+                    .on_hover_text("Copy to clipboard")
+                    .clicked()
+                {
+                    ui.ctx().copy_text(pubkey.to_string());
+                }
+            });
+            ui.add_space(CONTENT_SPACING_SMALL);
+        }
+        ui.add_space(HEADER_SPACING_TINY);
+
+        egui::Grid::new("validator_detail_grid")
+            .num_columns(2)
+            .spacing([HEADER_SPACING_LARGE, CONTENT_SPACING_SMALL])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Commission");
+                ui.label(format!("{}%", validator.commission));
+                ui.end_row();
+
+                ui.label("Activated Stake");
+                ui.label(format_stake(validator.activated_stake));
+                ui.end_row();
+
+                ui.label("Stake Rank");
+                ui.label(format!(
+                    "#{} of {}",
+                    stake_rank(validator, validators),
+                    validators.len()
+                ));
+                ui.end_row();
+
+                ui.label("Skip Rate");
+                ui.label(format_skip_rate(validator.skip_rate));
+                ui.end_row();
+
+                ui.label("Version");
+                ui.label(&validator.version);
+                ui.end_row();
+
+                ui.label("Last Vote Slot");
+                ui.label(validator.last_vote.to_string());
+                ui.end_row();
+
+                ui.label("Root Slot");
+                ui.label(validator.root_slot.to_string());
+                ui.end_row();
+
+                ui.label("Vote Credits");
+                ui.label(validator.vote_credits.to_string());
+                ui.end_row();
+
+                ui.label("Vote Balance");
+                match vote_balance {
+                    Some(VoteBalanceState::Loaded { balance, .. }) => {
+                        ui.label(format_stake(balance.lamports))
+                            .on_hover_text(format!(
+                                "Rent-exempt minimum: {}",
+                                format_stake(balance.rent_exempt_minimum)
+                            ));
+                    }
+                    Some(VoteBalanceState::Error { message, .. }) => {
+                        ui.colored_label(
+                            palette_color(ui.ctx(), StatusColor::Error),
+                            "Unavailable",
+                        )
+                        .on_hover_text(message);
+                    }
+                    Some(VoteBalanceState::Loading) | None => {
+                        ui.spinner();
+                    }
+                }
+                ui.end_row();
+            });
+        ui.add_space(HEADER_SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Credits per Epoch").strong());
+        render_epoch_credits_chart(ui, &validator.epoch_credits);
+    });
+}
+
+/// Render epoch credits history as a small bar chart, one bar per epoch, with a
+/// trend line through the bar tops. Epochs missing from the history leave a gap.
+fn render_epoch_credits_chart(ui: &mut egui::Ui, epoch_credits: &[(u64, u64, u64)]) {
+    let earned = credits_by_epoch(epoch_credits);
+    if earned.is_empty() {
+        ui.label("No epoch credits history.");
+        return;
+    }
+    let max_earned = earned
+        .iter()
+        .filter_map(|(_, credits)| *credits)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let missing = earned
+        .iter()
+        .filter(|(_, credits)| credits.is_none())
+        .count();
+
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(ui.available_width(), CREDITS_CHART_HEIGHT),
+        egui::Sense::hover(),
+    );
+    let painter = ui.painter_at(rect);
+    painter.rect_stroke(
+        rect,
+        FRAME_CORNER_RADIUS,
+        egui::Stroke::new(
+            FRAME_STROKE_WIDTH,
+            ui.visuals().widgets.noninteractive.bg_stroke.color,
+        ),
+        egui::StrokeKind::Inside,
+    );
+
+    let bar_width = rect.width() / earned.len() as f32;
+    let pointer = ui.input(|i| i.pointer.hover_pos());
+    let mut hovered = None;
+    let mut trend = Vec::with_capacity(earned.len());
+    for (index, (epoch, credits)) in earned.iter().enumerate() {
+        let left = rect.left() + index as f32 * bar_width;
+        if let Some(credits) = credits {
+            let height = rect.height() * (*credits as f32 / max_earned as f32);
+            let bar = egui::Rect::from_min_max(
+                egui::pos2(left + CREDITS_CHART_BAR_GAP / 2.0, rect.bottom() - height),
+                egui::pos2(
+                    left + bar_width - CREDITS_CHART_BAR_GAP / 2.0,
+                    rect.bottom(),
+                ),
+            );
+            painter.rect_filled(bar, 0.0, CREDITS_CHART_COLOR);
+            trend.push(egui::pos2(bar.center().x, bar.top()));
+        }
+
+        let column = egui::Rect::from_x_y_ranges(left..=left + bar_width, rect.y_range());
+        if pointer.is_some_and(|pos| column.contains(pos)) {
+            hovered = Some((*epoch, *credits));
+        }
+    }
+    if trend.len() > 1 {
+        painter.add(egui::Shape::line(
+            trend,
+            egui::Stroke::new(CREDITS_CHART_TREND_WIDTH, ui.visuals().strong_text_color()),
+        ));
+    }
+
+    let (first_epoch, _) = earned[0];
+    let (last_epoch, _) = earned[earned.len() - 1];
+    match hovered {
+        Some((epoch, Some(credits))) => ui.label(format!("Epoch {}: {} credits", epoch, credits)),
+        Some((epoch, None)) => ui.label(format!("Epoch {}: no credits recorded", epoch)),
+        None if missing > 0 => ui.label(format!(
+            "Epochs {}–{} (max {} credits, {} without credits)",
+            first_epoch, last_epoch, max_earned, missing
+        )),
+        None => ui.label(format!(
+            "Epochs {}–{} (max {} credits)",
+            first_epoch, last_epoch, max_earned
+        )),
+    };
 }
 
 /// Render sorting information.
@@ -129,33 +1543,130 @@ fn render_sort_info(ui: &mut egui::Ui, sort_states: &[SortState]) {
     ui.add_space(HEADER_SPACING_MEDIUM);
 }
 
-/// Filter validators based on search term.
-fn filter_validators(validators: &[ValidatorInfo], search_term: &str) -> Vec<ValidatorInfo> {
-    if search_term.is_empty() {
-        return validators.to_vec();
+/// How a search term matches a validator's identity or vote account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PubkeyMatch {
+    Exact,
+    Prefix,
+    Substring,
+}
+
+/// Match a search term normalized with `pubkey_search_key` against the validator's pubkeys.
+fn pubkey_match(
+    validator: &ValidatorInfo,
+    search_key: &str,
+    case_sensitive: bool,
+) -> Option<PubkeyMatch> {
+    [validator.identity, validator.vote_account]
+        .iter()
+        .map(|pubkey| pubkey_search_key(&pubkey.to_string(), case_sensitive))
+        .filter_map(|pubkey| {
+            if pubkey == search_key {
+                Some(PubkeyMatch::Exact)
+            } else if pubkey.starts_with(search_key) {
+                Some(PubkeyMatch::Prefix)
+            } else if pubkey.contains(search_key) {
+                Some(PubkeyMatch::Substring)
+            } else {
+                None
+            }
+        })
+        .min_by_key(|pubkey_match| *pubkey_match as u8)
+}
+
+/// Stable-partition validators so pubkey prefix matches come before other matches,
+/// preserving the active sort order within each group.
+fn rank_search_matches(validators: &mut [ValidatorInfo], search_term: &str, case_sensitive: bool) {
+    let search_key = pubkey_search_key(search_term.trim(), case_sensitive);
+    if search_key.is_empty() {
+        return;
     }
 
-    let search_lower = search_term.to_lowercase();
+    validators.sort_by_key(|validator| {
+        pubkey_match(validator, &search_key, case_sensitive) != Some(PubkeyMatch::Prefix)
+    });
+}
+
+/// Number of validators the tab lists for a search term, outdated filter and
+/// commission tier, for the tab label badge.
+pub fn filtered_validator_count(
+    validators: &[ValidatorInfo],
+    search_term: &str,
+    case_sensitive: bool,
+    outdated_only: bool,
+    min_validator_version: Option<&str>,
+    commission_tier: Option<usize>,
+) -> usize {
+    if search_term.trim().is_empty() && !outdated_only && commission_tier.is_none() {
+        return validators.len();
+    }
+    let search = ValidatorSearch::new(validators, search_term, case_sensitive);
+    let threshold =
+        outdated_only.then(|| outdated_version_threshold(validators, min_validator_version));
     validators
         .iter()
-        .filter(|validator| {
-            // Search in identity, vote account, version, and other text fields
-            validator
-                .identity
-                .to_string()
-                .to_lowercase()
-                .contains(&search_lower)
-                || validator
-                    .vote_account
-                    .to_string()
-                    .to_lowercase()
-                    .contains(&search_lower)
-                || validator.version.to_lowercase().contains(&search_lower)
-                || validator.commission.to_string().contains(&search_lower)
-                || validator.last_vote.to_string().contains(&search_lower)
-                || validator.root_slot.to_string().contains(&search_lower)
-                || validator.vote_credits.to_string().contains(&search_lower)
-        })
+        .filter(|v| search.matches(v))
+        .filter(|v| threshold.is_none_or(|threshold| is_outdated_version(&v.version, threshold)))
+        .filter(|v| commission_tier.is_none_or(|tier| commission_bucket(v.commission) == tier))
+        .count()
+}
+
+/// A search term prepared for matching validators.
+/// A full identity or vote account pubkey acts as a lookup and matches only that validator.
+/// Pubkeys follow the case-sensitivity setting; text fields like version ignore case.
+struct ValidatorSearch {
+    lower: String,
+    key: String,
+    case_sensitive: bool,
+    /// Some validator matches the term as a full pubkey
+    exact_lookup: bool,
+}
+
+impl ValidatorSearch {
+    fn new(validators: &[ValidatorInfo], search_term: &str, case_sensitive: bool) -> Self {
+        let lower = search_term.trim().to_lowercase();
+        let key = pubkey_search_key(search_term.trim(), case_sensitive);
+        let exact_lookup = !lower.is_empty()
+            && validators.iter().any(|validator| {
+                pubkey_match(validator, &key, case_sensitive) == Some(PubkeyMatch::Exact)
+            });
+        Self {
+            lower,
+            key,
+            case_sensitive,
+            exact_lookup,
+        }
+    }
+
+    fn matches(&self, validator: &ValidatorInfo) -> bool {
+        if self.lower.is_empty() {
+            return true;
+        }
+        let pubkey_match = pubkey_match(validator, &self.key, self.case_sensitive);
+        if self.exact_lookup {
+            return pubkey_match == Some(PubkeyMatch::Exact);
+        }
+
+        // Search in identity, vote account, version, and other text fields
+        pubkey_match.is_some()
+            || validator.version.to_lowercase().contains(&self.lower)
+            || validator.commission.to_string().contains(&self.lower)
+            || validator.last_vote.to_string().contains(&self.lower)
+            || validator.root_slot.to_string().contains(&self.lower)
+            || validator.vote_credits.to_string().contains(&self.lower)
+    }
+}
+
+/// Filter validators based on search term.
+fn filter_validators(
+    validators: &[ValidatorInfo],
+    search_term: &str,
+    case_sensitive: bool,
+) -> Vec<ValidatorInfo> {
+    let search = ValidatorSearch::new(validators, search_term, case_sensitive);
+    validators
+        .iter()
+        .filter(|validator| search.matches(validator))
         .cloned()
         .collect()
 }
@@ -164,11 +1675,25 @@ fn filter_validators(validators: &[ValidatorInfo], search_term: &str) -> Vec<Val
 pub fn sort_validators(validators: &mut [ValidatorInfo], sort_states: &[SortState]) {
     validators.sort_by(|a, b| {
         for sort_state in sort_states {
+            // Validators without skip rate or credits history sort last in either direction
+            let missing = match sort_state.column {
+                SortColumn::SkipRate => a.skip_rate.is_none().cmp(&b.skip_rate.is_none()),
+                SortColumn::EpochsActive => {
+                    a.epoch_credits.is_empty().cmp(&b.epoch_credits.is_empty())
+                }
+                _ => Ordering::Equal,
+            };
+            if missing != Ordering::Equal {
+                return missing;
+            }
+
             let comparison = match sort_state.column {
                 SortColumn::Identity => a.identity.cmp(&b.identity),
                 SortColumn::VoteAccount => a.vote_account.cmp(&b.vote_account),
                 SortColumn::Commission => a.commission.cmp(&b.commission),
                 SortColumn::LastVote => a.last_vote.cmp(&b.last_vote),
+                // A later vote means a smaller lag behind the current slot
+                SortColumn::VoteLag => b.last_vote.cmp(&a.last_vote),
                 SortColumn::RootSlot => a.root_slot.cmp(&b.root_slot),
                 SortColumn::VoteCredits => a.vote_credits.cmp(&b.vote_credits),
                 SortColumn::ActivatedStake => a.activated_stake.cmp(&b.activated_stake),
@@ -177,6 +1702,9 @@ pub fn sort_validators(validators: &mut [ValidatorInfo], sort_states: &[SortStat
                     .skip_rate
                     .partial_cmp(&b.skip_rate)
                     .unwrap_or(Ordering::Equal),
+                SortColumn::EpochsActive => {
+                    epochs_active(&a.epoch_credits).cmp(&epochs_active(&b.epoch_credits))
+                }
             };
 
             let final_comparison = match sort_state.direction {
@@ -209,60 +1737,378 @@ pub fn get_sort_indicator(sort_states: &[SortState], column: SortColumn) -> Stri
     }
 }
 
+/// Parameters for rendering the validators table.
+struct ValidatorsTableParams<'a> {
+    validators: &'a [ValidatorInfo],
+    sort_states: &'a [SortState],
+    watchlist: &'a [String],
+    external_links: &'a [ExternalLink],
+    commission_changes: &'a HashMap<Pubkey, CommissionChange>,
+    changed_at: &'a HashMap<Pubkey, Instant>,
+    selected_validator: &'a mut Option<Pubkey>,
+    alias_edit: &'a mut Option<Pubkey>,
+    aliases: &'a AliasStore,
+    outdated_threshold: Option<[u32; 3]>,
+    current_slot: Option<u64>,
+    /// Vote account whose row should be scrolled into view this frame
+    scroll_to: Option<Pubkey>,
+    /// Vote account of the keyboard cursor row, moved along by clicks
+    row_cursor: &'a mut Option<Pubkey>,
+}
+
+/// Table headers paired with the column they sort by, in display order.
+const TABLE_HEADERS: [(SortColumn, &str); 11] = [
+    (SortColumn::Identity, "Identity"),
+    (SortColumn::VoteAccount, "Vote Account"),
+    (SortColumn::Commission, "Commission"),
+    (SortColumn::LastVote, "Last Vote Slot"),
+    (SortColumn::VoteLag, "Vote Lag"),
+    (SortColumn::RootSlot, "Root Slot"),
+    (SortColumn::VoteCredits, "Vote Credits"),
+    (SortColumn::SkipRate, "Skip Rate"),
+    (SortColumn::EpochsActive, "Epochs Active"),
+    (SortColumn::ActivatedStake, "Activated Stake"),
+    (SortColumn::Version, "Version"),
+];
+
+/// One-line explanation of a column's metric, shown when hovering its header.
+const fn column_description(column: SortColumn) -> &'static str {
+    match column {
+        SortColumn::Identity => "Node identity key that signs blocks and gossip messages",
+        SortColumn::VoteAccount => "Account the validator votes from and delegators stake to",
+        SortColumn::Commission => "Share of staking rewards the validator keeps",
+        SortColumn::LastVote => "Most recent slot the validator voted on",
+        SortColumn::VoteLag => "Slots between the current slot and the validator's last vote",
+        SortColumn::RootSlot => {
+            "Latest slot the validator considers final and will never roll back"
+        }
+        SortColumn::VoteCredits => "Total credits earned for landed votes; rewards scale with them",
+        SortColumn::SkipRate => {
+            "Estimated share of slots without a vote credit in the latest epoch"
+        }
+        SortColumn::EpochsActive => {
+            "Estimated epochs the validator has voted, from its credits history; \
+             RPC returns at most the last 64 epochs, so older validators show 64+"
+        }
+        SortColumn::ActivatedStake => "Delegated stake currently active on the vote account",
+        SortColumn::Version => "Solana software version the node reports over gossip",
+    }
+}
+
+/// Number of leading columns that stay pinned during horizontal scroll.
+const FROZEN_COLUMN_COUNT: usize = 1;
+
 /// Render the validators table.
+///
+/// The table is split in two so the Identity column stays visible while the
+/// remaining columns scroll horizontally. Both halves lay out rows at the fixed
+/// `TABLE_ROW_HEIGHT` and follow one shared vertical scroll offset, so their rows
+/// stay aligned. Returns where the keyboard cursor row was drawn.
 fn render_validators_table(
     ui: &mut egui::Ui,
-    validators: &[ValidatorInfo],
-    sort_states: &[SortState],
+    params: ValidatorsTableParams,
     mut on_sort: impl FnMut(SortColumn, bool),
-) {
-    TableBuilder::new(ui)
-        .striped(true)
-        .resizable(true)
-        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-        .column(Column::auto().at_least(COLUMN_PUBKEY_WIDTH)) // Identity (full base58)
-        .column(Column::auto().at_least(COLUMN_PUBKEY_WIDTH)) // Vote Account (full base58)
-        .column(Column::auto().at_least(COLUMN_COMMISSION_WIDTH)) // Commission
-        .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Last Vote Slot
-        .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Root Slot
-        .column(Column::auto().at_least(COLUMN_VOTE_CREDITS_WIDTH)) // Vote Credits
-        .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Skip Rate
-        .column(Column::auto().at_least(COLUMN_VOTE_CREDITS_WIDTH)) // Activated Stake
-        .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Version
-        .header(TABLE_HEADER_HEIGHT, |mut header| {
-            render_table_headers(&mut header, sort_states, on_sort);
-        })
-        .body(|mut body| {
-            for validator in validators.iter() {
-                body.row(TABLE_ROW_HEIGHT, |mut row| {
-                    render_validator_row(&mut row, validator);
+    on_toggle_watchlist: &mut impl FnMut(&Pubkey),
+) -> Option<egui::Rect> {
+    let ValidatorsTableParams {
+        validators,
+        sort_states,
+        watchlist,
+        external_links,
+        commission_changes,
+        changed_at,
+        selected_validator,
+        alias_edit,
+        aliases,
+        outdated_threshold,
+        current_slot,
+        scroll_to,
+        row_cursor,
+    } = params;
+    let (frozen_headers, scrollable_headers) = TABLE_HEADERS.split_at(FROZEN_COLUMN_COUNT);
+    let row_flags = |validator: &ValidatorInfo| {
+        let is_watchlisted = watchlist.contains(&validator.identity.to_string());
+        let commission_change = commission_changes
+            .get(&validator.vote_account)
+            .filter(|change| change.is_increase());
+        let flash = flash_color(changed_at.get(&validator.vote_account));
+        (is_watchlisted, commission_change, flash)
+    };
+    let is_highlighted = |validator: &ValidatorInfo, is_watchlisted: bool, has_alert: bool| {
+        *selected_validator == Some(validator.vote_account)
+            || *row_cursor == Some(validator.vote_account)
+            || (is_watchlisted && has_alert)
+    };
+    let scroll_to_row = scroll_to.and_then(|vote_account| {
+        validators
+            .iter()
+            .position(|validator| validator.vote_account == vote_account)
+    });
+    // Offset both halves were drawn at last frame; whichever half scrolled leads
+    let offset_id = ui.id().with("validators_table_offset");
+    let shared_offset = ui.data(|data| data.get_temp::<f32>(offset_id).unwrap_or_default());
+    let mut frozen_offset = shared_offset;
+    let mut scrollable_offset = shared_offset;
+    let mut clicked = None;
+    let mut row_action = None;
+    let mut cursor_rect = None;
+
+    ui.horizontal_top(|ui| {
+        ui.spacing_mut().item_spacing.x = 0.0;
+        let striped = apply_row_stripes(ui);
+        let pubkey_width = pubkey_column_width(ui.ctx());
+
+        let mut frozen_table = TableBuilder::new(ui)
+            .id_salt("validators_frozen_columns")
+            .striped(striped)
+            .resizable(true)
+            .vertical_scroll_offset(shared_offset)
+            // The scrollable half shows the scroll bar for both
+            .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysHidden)
+            .sense(egui::Sense::click())
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center));
+        if let Some(index) = scroll_to_row {
+            frozen_table = frozen_table.scroll_to_row(index, Some(egui::Align::Center));
+        }
+        frozen_offset = frozen_table
+            .column(Column::auto().at_least(pubkey_width)) // Identity
+            .header(TABLE_HEADER_HEIGHT, |mut header| {
+                render_table_headers(&mut header, frozen_headers, sort_states, &mut on_sort);
+            })
+            .body(|body| {
+                body.rows(TABLE_ROW_HEIGHT, validators.len(), |mut row| {
+                    let validator = &validators[row.index()];
+                    let (is_watchlisted, commission_change, flash) = row_flags(validator);
+                    row.set_selected(is_highlighted(
+                        validator,
+                        is_watchlisted,
+                        commission_change.is_some(),
+                    ));
+                    render_identity_cell(&mut row, validator, aliases, is_watchlisted, flash);
+                    if *row_cursor == Some(validator.vote_account) {
+                        cursor_rect = Some(row.response().rect);
+                    }
+                    if row.response().clicked() {
+                        clicked = Some(validator.vote_account);
+                    }
+                    row.response().context_menu(|ui| {
+                        if let Some(action) =
+                            render_row_actions(ui, validator, is_watchlisted, external_links, false)
+                        {
+                            row_action = Some((validator, action));
+                            ui.close();
+                        }
+                    });
                 });
-            }
-        });
+            })
+            .state
+            .offset
+            .y;
+
+        egui::ScrollArea::horizontal()
+            .id_salt("validators_scrollable_columns")
+            .show(ui, |ui| {
+                let mut scrollable_table = TableBuilder::new(ui)
+                    .id_salt("validators_scrollable_table")
+                    .striped(striped)
+                    .resizable(true)
+                    .vertical_scroll_offset(shared_offset)
+                    .sense(egui::Sense::click())
+                    .cell_layout(egui::Layout::left_to_right(egui::Align::Center));
+                if let Some(index) = scroll_to_row {
+                    scrollable_table =
+                        scrollable_table.scroll_to_row(index, Some(egui::Align::Center));
+                }
+                scrollable_offset = scrollable_table
+                    .column(Column::auto().at_least(pubkey_width)) // Vote Account
+                    .column(Column::auto().at_least(COLUMN_COMMISSION_WIDTH)) // Commission
+                    .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Last Vote Slot
+                    .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Vote Lag
+                    .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Root Slot
+                    .column(Column::auto().at_least(COLUMN_VOTE_CREDITS_WIDTH)) // Vote Credits
+                    .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Skip Rate
+                    .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Epochs Active
+                    .column(Column::auto().at_least(COLUMN_VOTE_CREDITS_WIDTH)) // Activated Stake
+                    .column(Column::auto().at_least(COLUMN_FEATURE_WIDTH)) // Version
+                    .header(TABLE_HEADER_HEIGHT, |mut header| {
+                        render_table_headers(
+                            &mut header,
+                            scrollable_headers,
+                            sort_states,
+                            &mut on_sort,
+                        );
+                    })
+                    .body(|body| {
+                        body.rows(TABLE_ROW_HEIGHT, validators.len(), |mut row| {
+                            let validator = &validators[row.index()];
+                            let (is_watchlisted, commission_change, flash) = row_flags(validator);
+                            row.set_selected(is_highlighted(
+                                validator,
+                                is_watchlisted,
+                                commission_change.is_some(),
+                            ));
+                            render_validator_row(
+                                &mut row,
+                                validator,
+                                aliases,
+                                commission_change,
+                                outdated_threshold,
+                                current_slot,
+                                flash,
+                            );
+                            if row.response().clicked() {
+                                clicked = Some(validator.vote_account);
+                            }
+                            row.response().context_menu(|ui| {
+                                if let Some(action) = render_row_actions(
+                                    ui,
+                                    validator,
+                                    is_watchlisted,
+                                    external_links,
+                                    false,
+                                ) {
+                                    row_action = Some((validator, action));
+                                    ui.close();
+                                }
+                            });
+                        });
+                    })
+                    .state
+                    .offset
+                    .y;
+            });
+    });
+
+    let next_offset = if frozen_offset != shared_offset {
+        frozen_offset
+    } else {
+        scrollable_offset
+    };
+    if next_offset != shared_offset {
+        ui.data_mut(|data| data.insert_temp(offset_id, next_offset));
+        // Bring the other half along before the next input arrives
+        ui.ctx().request_repaint();
+    }
+
+    if let Some(vote_account) = clicked {
+        *selected_validator = if *selected_validator == Some(vote_account) {
+            None
+        } else {
+            Some(vote_account)
+        };
+        *row_cursor = Some(vote_account);
+    }
+    if let Some((validator, action)) = row_action {
+        apply_row_action(
+            action,
+            validator,
+            on_toggle_watchlist,
+            selected_validator,
+            alias_edit,
+        );
+    }
+    cursor_rect
+}
+
+/// Render the entries of a validator row's actions menu, shared by the
+/// right-click menu and the keyboard-opened one. Copying and opening links
+/// happen right away; other choices are returned for the caller to apply.
+fn render_row_actions(
+    ui: &mut egui::Ui,
+    validator: &ValidatorInfo,
+    is_watchlisted: bool,
+    external_links: &[ExternalLink],
+    focus_first: bool,
+) -> Option<RowAction> {
+    let mut action = None;
+    let copy_identity = ui.button(icon_text(ui.ctx(), "📋", "Copy identity"));
+    if focus_first {
+        copy_identity.request_focus();
+    }
+    if copy_identity.clicked() {
+        ui.ctx().copy_text(validator.identity.to_string());
+        action = Some(RowAction::Handled);
+    }
+    if ui
+        .button(icon_text(ui.ctx(), "📋", "Copy vote account"))
+        .clicked()
+    {
+        ui.ctx().copy_text(validator.vote_account.to_string());
+        action = Some(RowAction::Handled);
+    }
+    let watchlist_label = if is_watchlisted {
+        icon_text(ui.ctx(), "☆", "Remove from watchlist")
+    } else {
+        icon_text(ui.ctx(), "★", "Add to watchlist")
+    };
+    if ui.button(watchlist_label).clicked() {
+        action = Some(RowAction::ToggleWatchlist);
+    }
+    if ui
+        .button(icon_text(ui.ctx(), "ℹ", "View details"))
+        .clicked()
+    {
+        action = Some(RowAction::ShowDetails);
+    }
+
+    ui.separator();
+    for (title, pubkey) in [
+        ("identity", validator.identity),
+        ("vote account", validator.vote_account),
+    ] {
+        if ui
+            .button(icon_text(ui.ctx(), "🏷", &format!("Set {} alias…", title)))
+            .clicked()
+        {
+            action = Some(RowAction::EditAlias(pubkey));
+        }
+    }
+
+    if !external_links.is_empty() {
+        ui.separator();
+    }
+    for link in external_links {
+        let link_label = icon_text(ui.ctx(), "🔗", &format!("Open on {}", link.name));
+        if ui.button(link_label).clicked() {
+            ui.ctx().open_url(egui::OpenUrl::new_tab(link.url_for(
+                &validator.identity.to_string(),
+                &validator.vote_account.to_string(),
+            )));
+            action = Some(RowAction::Handled);
+        }
+    }
+    action
+}
+
+/// Carry out a row action chosen for `validator` in either actions menu.
+fn apply_row_action(
+    action: RowAction,
+    validator: &ValidatorInfo,
+    on_toggle_watchlist: &mut impl FnMut(&Pubkey),
+    selected_validator: &mut Option<Pubkey>,
+    alias_edit: &mut Option<Pubkey>,
+) {
+    match action {
+        RowAction::Handled => {}
+        RowAction::ToggleWatchlist => on_toggle_watchlist(&validator.identity),
+        RowAction::ShowDetails => *selected_validator = Some(validator.vote_account),
+        RowAction::EditAlias(pubkey) => *alias_edit = Some(pubkey),
+    }
 }
 
 /// Render table headers with sorting.
 fn render_table_headers(
     header: &mut egui_extras::TableRow<'_, '_>,
+    headers: &[(SortColumn, &str)],
     sort_states: &[SortState],
-    mut on_sort: impl FnMut(SortColumn, bool),
+    on_sort: &mut impl FnMut(SortColumn, bool),
 ) {
-    let headers = [
-        (SortColumn::Identity, "Identity"),
-        (SortColumn::VoteAccount, "Vote Account"),
-        (SortColumn::Commission, "Commission"),
-        (SortColumn::LastVote, "Last Vote Slot"),
-        (SortColumn::RootSlot, "Root Slot"),
-        (SortColumn::VoteCredits, "Vote Credits"),
-        (SortColumn::SkipRate, "Skip Rate"),
-        (SortColumn::ActivatedStake, "Activated Stake"),
-        (SortColumn::Version, "Version"),
-    ];
-
-    for (sort_column, title) in headers {
+    for &(sort_column, title) in headers {
         header.col(|ui| {
             let text = format!("{}{}", title, get_sort_indicator(sort_states, sort_column));
-            let response = ui.button(text);
+            let response = ui
+                .button(text)
+                .on_hover_text(column_description(sort_column));
             if response.clicked() {
                 let shift_pressed = ui.input(|i| i.modifiers.shift);
                 on_sort(sort_column, shift_pressed);
@@ -271,33 +2117,349 @@ fn render_table_headers(
     }
 }
 
-/// Render a single validator row.
-fn render_validator_row(row: &mut egui_extras::TableRow<'_, '_>, validator: &ValidatorInfo) {
-    row.col(|ui| {
-        ui.monospace(validator.identity.to_string());
+/// Render the pinned Identity cell of a validator row.
+fn render_identity_cell(
+    row: &mut egui_extras::TableRow<'_, '_>,
+    validator: &ValidatorInfo,
+    aliases: &AliasStore,
+    is_watchlisted: bool,
+    flash: Option<egui::Color32>,
+) {
+    flash_col(row, flash, |ui| {
+        if is_watchlisted {
+            ui.label("★").on_hover_text("On watchlist");
+        }
+        if validator.delinquent {
+            ui.colored_label(palette_color(ui.ctx(), StatusColor::Error), "⚠")
+                .on_hover_text("Delinquent: the RPC node reports it is not voting");
+        }
+        render_pubkey(ui, aliases, &validator.identity.to_string());
     });
-    row.col(|ui| {
-        ui.monospace(validator.vote_account.to_string());
+}
+
+/// Render the horizontally scrollable cells of a validator row.
+fn render_validator_row(
+    row: &mut egui_extras::TableRow<'_, '_>,
+    validator: &ValidatorInfo,
+    aliases: &AliasStore,
+    commission_change: Option<&CommissionChange>,
+    outdated_threshold: Option<[u32; 3]>,
+    current_slot: Option<u64>,
+    flash: Option<egui::Color32>,
+) {
+    flash_col(row, flash, |ui| {
+        render_pubkey(ui, aliases, &validator.vote_account.to_string());
     });
-    row.col(|ui| {
-        ui.label(format!("{}%", validator.commission));
+    flash_num_col(row, flash, |ui| {
+        if let Some(change) = commission_change {
+            ui.colored_label(
+                COMMISSION_ALERT_COLOR,
+                format!("⚠ {}% → {}%", change.previous, change.current),
+            )
+            .on_hover_text("Commission increased since the previous refresh");
+        } else {
+            ui.label(format!("{}%", validator.commission));
+        }
     });
-    row.col(|ui| {
+    flash_num_col(row, flash, |ui| {
         ui.label(validator.last_vote.to_string());
     });
-    row.col(|ui| {
+    flash_num_col(row, flash, |ui| match current_slot {
+        Some(current_slot) => {
+            let lag = current_slot.saturating_sub(validator.last_vote);
+            if lag > DELINQUENT_SLOT_DISTANCE {
+                ui.colored_label(
+                    palette_color(ui.ctx(), StatusColor::Stalled),
+                    format!("⚠ {}", lag),
+                )
+                .on_hover_text(format!(
+                    "More than {} slots behind the current slot",
+                    DELINQUENT_SLOT_DISTANCE
+                ));
+            } else {
+                ui.label(lag.to_string());
+            }
+        }
+        None => {
+            ui.label("—");
+        }
+    });
+    flash_num_col(row, flash, |ui| {
         ui.label(validator.root_slot.to_string());
     });
-    row.col(|ui| {
+    flash_num_col(row, flash, |ui| {
         ui.label(validator.vote_credits.to_string());
     });
-    row.col(|ui| {
+    flash_num_col(row, flash, |ui| {
         ui.label(format_skip_rate(validator.skip_rate));
     });
-    row.col(|ui| {
+    flash_num_col(row, flash, |ui| {
+        match epochs_active(&validator.epoch_credits) {
+            Some(epochs) if epochs >= EPOCH_CREDITS_HISTORY_LEN => {
+                ui.label(format!("{}+", EPOCH_CREDITS_HISTORY_LEN));
+            }
+            Some(epochs) => {
+                ui.label(epochs.to_string());
+            }
+            None => {
+                ui.label("—");
+            }
+        }
+    });
+    flash_num_col(row, flash, |ui| {
         ui.label(format_stake(validator.activated_stake));
     });
+    flash_col(row, flash, |ui| match outdated_threshold {
+        Some(threshold) if is_outdated_version(&validator.version, outdated_threshold) => {
+            ui.colored_label(WARNING_COLOR, format!("⚠ {}", validator.version))
+                .on_hover_text(format!(
+                    "Outdated: older than {}",
+                    format_version(threshold)
+                ));
+        }
+        _ => {
+            ui.label(&validator.version);
+        }
+    });
+}
+
+/// Add a cell, painting the refresh highlight behind its content.
+fn flash_col(
+    row: &mut egui_extras::TableRow<'_, '_>,
+    flash: Option<egui::Color32>,
+    add_contents: impl FnOnce(&mut egui::Ui),
+) {
     row.col(|ui| {
-        ui.label(&validator.version);
+        if let Some(color) = flash {
+            ui.painter().rect_filled(ui.max_rect(), 0.0, color);
+        }
+        add_contents(ui);
     });
 }
+
+/// Add a right-aligned numeric cell with the refresh highlight.
+fn flash_num_col(
+    row: &mut egui_extras::TableRow<'_, '_>,
+    flash: Option<egui::Color32>,
+    add_contents: impl FnOnce(&mut egui::Ui),
+) {
+    flash_col(row, flash, |ui| right_aligned(ui, add_contents));
+}
+
+/// Highlight for a row that changed in the last refresh, fading out over
+/// `REFRESH_FLASH_SECS`.
+fn flash_color(changed_at: Option<&Instant>) -> Option<egui::Color32> {
+    let remaining = 1.0 - changed_at?.elapsed().as_secs_f32() / REFRESH_FLASH_SECS;
+    (remaining > 0.0).then(|| REFRESH_FLASH_COLOR.gamma_multiply(remaining))
+}
+
+/// Format a parsed version as `major.minor.patch`.
+fn format_version([major, minor, patch]: [u32; 3]) -> String {
+    format!("{}.{}.{}", major, minor, patch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(seed: u8) -> ValidatorInfo {
+        ValidatorInfo {
+            identity: Pubkey::new_from_array([seed; 32]),
+            vote_account: Pubkey::new_from_array([seed + 100; 32]),
+            commission: 5,
+            last_vote: 1_000,
+            root_slot: 968,
+            vote_credits: 42,
+            epoch_credits: vec![(500, 1_000, 400), (501, 1_700, 1_000)],
+            activated_stake: 1_500_000_000,
+            version: "2.1.14".to_string(),
+            skip_rate: Some(1.25),
+            delinquent: false,
+        }
+    }
+
+    #[test]
+    fn test_filtered_validator_count_matches_filter() {
+        let mut other = validator(2);
+        other.version = "1.18.0".to_string();
+        let validators = [validator(1), other];
+        let count =
+            |term: &str| filtered_validator_count(&validators, term, true, false, None, None);
+
+        for term in ["", "2.1", &validators[0].identity.to_string(), "no match"] {
+            assert_eq!(
+                count(term),
+                filter_validators(&validators, term, true).len()
+            );
+        }
+        // A full pubkey is a lookup of that validator only
+        assert_eq!(count(&validators[1].vote_account.to_string()), 1);
+        assert_eq!(count("2.1"), 1);
+        assert_eq!(count("no match"), 0);
+    }
+
+    /// Split a CSV into header and data lines.
+    fn csv_lines(csv: &str) -> (&str, Vec<&str>) {
+        let mut lines = csv.lines();
+        (lines.next().unwrap(), lines.collect())
+    }
+
+    #[test]
+    fn test_identity_vote_mapping_csv() {
+        let validator = validator(1);
+        assert_eq!(
+            identity_vote_mapping_csv(std::slice::from_ref(&validator)),
+            format!(
+                "identity,vote_account\n{},{}\n",
+                validator.identity, validator.vote_account
+            )
+        );
+    }
+
+    #[test]
+    fn test_validators_csv_omit() {
+        let csv = validators_csv(&[validator(1), validator(2)], EpochCreditsExport::Omit);
+        let (header, rows) = csv_lines(&csv);
+        assert_eq!(
+            header,
+            "identity,vote_account,commission,last_vote,root_slot,vote_credits,skip_rate,\
+             activated_stake_sol,version,delinquent"
+        );
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|row| row.split(',').count() == 10));
+        assert!(rows[0].ends_with(",5,1000,968,42,1.25,1.500000000,2.1.14,false"));
+    }
+
+    #[test]
+    fn test_validators_csv_json_column() {
+        let csv = validators_csv(&[validator(1)], EpochCreditsExport::JsonColumn);
+        let (header, rows) = csv_lines(&csv);
+        assert!(header.ends_with(",delinquent,epoch_credits"));
+        assert_eq!(header.split(',').count(), 11);
+        // The quoted array keeps the row at eleven columns despite its commas
+        assert!(rows[0].ends_with(",false,\"[[500,1000,400],[501,1700,1000]]\""));
+        let (unquoted, array) = rows[0].split_once('"').unwrap();
+        assert_eq!(unquoted.split(',').count(), 11);
+        assert!(array.ends_with('"'));
+    }
+
+    #[test]
+    fn test_validators_csv_long_format() {
+        let validator = validator(1);
+        let csv = validators_csv(
+            std::slice::from_ref(&validator),
+            EpochCreditsExport::LongFormat,
+        );
+        let (header, rows) = csv_lines(&csv);
+        assert_eq!(
+            header,
+            "identity,vote_account,epoch,credits,previous_credits,earned"
+        );
+        let prefix = format!("{},{}", validator.identity, validator.vote_account);
+        assert_eq!(
+            rows,
+            [
+                format!("{},500,1000,400,600", prefix),
+                format!("{},501,1700,1000,700", prefix),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_against_snapshot() {
+        let (kept, removed, added, unchanged) =
+            (validator(1), validator(2), validator(3), validator(4));
+        let snapshot = [kept.clone(), removed.clone(), unchanged.clone()];
+        let mut changed = kept.clone();
+        changed.activated_stake = 2_000_000_000;
+        changed.commission = 7;
+        let diffs = diff_against_snapshot(&snapshot, &[changed, added.clone(), unchanged]);
+
+        assert_eq!(
+            diffs,
+            [
+                SnapshotDiff {
+                    identity: kept.identity,
+                    vote_account: kept.vote_account,
+                    change: SnapshotChange::Changed {
+                        stake: Some((1_500_000_000, 2_000_000_000)),
+                        commission: Some((5, 7)),
+                        skip_rate: None,
+                        delinquent: None,
+                    },
+                },
+                SnapshotDiff {
+                    identity: added.identity,
+                    vote_account: added.vote_account,
+                    change: SnapshotChange::Added,
+                },
+                SnapshotDiff {
+                    identity: removed.identity,
+                    vote_account: removed.vote_account,
+                    change: SnapshotChange::Removed,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_against_snapshot_delinquency_flip() {
+        let (before, other) = (validator(1), validator(2));
+        let mut flagged = before.clone();
+        flagged.delinquent = true;
+        let diffs =
+            diff_against_snapshot(&[before.clone(), other.clone()], &[flagged, other.clone()]);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(
+            diffs[0].change,
+            SnapshotChange::Changed {
+                stake: None,
+                commission: None,
+                skip_rate: None,
+                delinquent: Some((false, true)),
+            }
+        );
+
+        // Trailing the newest vote by more than the delinquency distance counts too
+        let mut ahead = other;
+        ahead.last_vote = before.last_vote + DELINQUENT_SLOT_DISTANCE + 1;
+        let diffs = diff_against_snapshot(std::slice::from_ref(&before), &[before.clone(), ahead]);
+        assert!(diffs
+            .iter()
+            .any(|diff| diff.vote_account == before.vote_account
+                && matches!(
+                    diff.change,
+                    SnapshotChange::Changed {
+                        delinquent: Some((false, true)),
+                        ..
+                    }
+                )));
+    }
+
+    #[test]
+    fn test_snapshot_diff_cached_per_generation() {
+        let snapshot = validator(1);
+        let mut snapshots = ValidatorSnapshots {
+            snapshots: vec![ValidatorSnapshot {
+                taken_at: Local::now(),
+                validators: vec![snapshot.clone()],
+            }],
+            compare_to: Some(0),
+            ..Default::default()
+        };
+        let mut changed = snapshot.clone();
+        changed.commission = 9;
+
+        let diff_len =
+            |snapshots: &mut ValidatorSnapshots, validators: &[ValidatorInfo], generation| {
+                snapshots.diff(validators, generation).map(<[_]>::len)
+            };
+        assert_eq!(diff_len(&mut snapshots, &[changed.clone()], 1), Some(1));
+        assert_eq!(diff_len(&mut snapshots, &[changed.clone()], 1), Some(1));
+        // A new generation recomputes it
+        assert_eq!(diff_len(&mut snapshots, &[snapshot], 2), Some(0));
+
+        snapshots.compare_to = None;
+        assert_eq!(diff_len(&mut snapshots, &[changed], 3), None);
+    }
+}
@@ -2,50 +2,383 @@
 //!
 //! This module provides the main ValidatorApp struct and orchestrates all tabs.
 
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::future::Future;
+use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use eframe::egui;
+use solana_sdk::pubkey::Pubkey;
 use tokio::sync::Mutex;
 
-use crate::config::ConfigManager;
+use crate::config::{AliasStore, ConfigManager, SearchField, StartupOverrides};
+use crate::constants::{
+    BACKGROUND_REPAINT_SECS, BUSY_OVERLAY_POLL_MS, CAPTURE_TIMEOUT_FRAMES,
+    ENDPOINT_PING_TIMEOUT_SECS, HEADER_SPACING_TINY, SEARCH_FIELD_HEIGHT, SEARCH_FIELD_WIDTH,
+    SETTINGS_WINDOW_WIDTH, WARNING_COLOR,
+};
+use crate::metrics_log::{self, MetricsRow};
+use crate::settings;
+use crate::shortcuts::{self, ShortcutAction, SHORTCUTS};
 use crate::solana::{
-    GossipNodeInfo, LeaderScheduleInfo, SlotVoterInfo, SolanaClient, ValidatorInfo,
+    self, GossipNodeInfo, LeaderScheduleInfo, NetworkOverview, PartialFailure, SlotVoterInfo,
+    SolanaClient, SolanaError, ValidatorInfo, ValidatorsFetch,
 };
+use crate::status_server::{self, StatusInfo, StatusServerState};
 use crate::tabs::{
-    find_voters::{self, FindVotersTabParams},
-    gossip_nodes,
-    leader_schedule::{self, LeaderScheduleTabParams},
+    find_voters::{self, FindVotersTabParams, RollingVoters, VotersTableCache},
+    gossip_nodes::{self, GossipNodesTabParams},
+    leader_schedule::{self, LeaderScheduleState, LeaderScheduleTabParams},
     logs,
+    network::{self, NetworkTabParams},
     update::UpdateTab,
-    validators::{self, ValidatorsTabParams},
+    validators::{
+        self, CommissionChange, RowCursor, ValidatorExport, ValidatorSnapshots,
+        ValidatorsTabParams, VoteAccountJump, VoteBalanceState, WatchlistImport,
+    },
     AppTab,
 };
-use crate::utils::{Cluster, SortColumn, SortDirection, SortState, StatusManager};
+use crate::utils::{
+    self, Cluster, DisplaySettings, FetchOperation, GossipSortColumn, SortColumn, SortDirection,
+    SortState, StatusColor, StatusManager,
+};
 
 /// Type alias for slot information: (current_slot, latest_slot, current_epoch)
-type SlotInfo = (Option<u64>, Option<u64>, Option<u64>);
+pub type SlotInfo = (Option<u64>, Option<u64>, Option<u64>);
 
 /// Constants for UI layout and behavior
 mod ui_constants {
     pub const MAX_SORT_COLUMNS: usize = 3;
     pub const UI_UPDATE_INTERVAL_SECS: u64 = 1;
+    pub const ROLLING_VOTERS_INTERVAL_SECS: u64 = 5;
+    pub const RETRY_DELAY_SECS: u64 = 2;
+    pub const RPC_VERSION_INTERVAL_SECS: u64 = 300;
 }
 
-/// Main application struct managing all UI state and data.
-pub struct ValidatorApp {
-    // Data stores
+/// Run a client operation, retrying once after a short delay when the error is transient.
+async fn with_retry<T, Fut>(
+    client: &SolanaClient,
+    operation_name: &str,
+    operation: impl FnMut() -> Fut,
+) -> solana::Result<T>
+where
+    Fut: Future<Output = solana::Result<T>>,
+{
+    retry_transient(
+        client.log_store(),
+        operation_name,
+        client.rpc_url(),
+        Duration::from_secs(ui_constants::RETRY_DELAY_SECS),
+        operation,
+    )
+    .await
+}
+
+/// Retry policy behind [`with_retry`]: one more attempt after `delay` for retryable
+/// errors, none for the rest. The transient error is logged before retrying.
+async fn retry_transient<T, Fut>(
+    log_store: &logs::LogStore,
+    operation_name: &str,
+    rpc_url: &str,
+    delay: Duration,
+    mut operation: impl FnMut() -> Fut,
+) -> solana::Result<T>
+where
+    Fut: Future<Output = solana::Result<T>>,
+{
+    match operation().await {
+        Err(e) if e.is_retryable() => {
+            logs::log_error(
+                log_store,
+                operation_name,
+                rpc_url,
+                &format!(
+                    "Retrying in {}s after transient error: {}",
+                    delay.as_secs(),
+                    e
+                ),
+            );
+            tokio::time::sleep(delay).await;
+            operation().await
+        }
+        result => result,
+    }
+}
+
+/// Append a metrics row to the CSV log on the blocking pool, logging a failed write.
+async fn append_metrics_row(path: PathBuf, row: MetricsRow, log_store: logs::LogStore) {
+    let written = tokio::task::spawn_blocking(move || {
+        metrics_log::append_row(&path, &row).map_err(|e| (path, e))
+    })
+    .await;
+    if let Ok(Err((path, e))) = written {
+        logs::log_error(
+            &log_store,
+            "metrics_log",
+            &path.display().to_string(),
+            &e.to_string(),
+        );
+    }
+}
+
+/// Write a captured image as a PNG in the downloads folder, named after the tab.
+fn save_table_png(image: &egui::ColorImage, tab: AppTab) -> Result<PathBuf, String> {
+    let [width, height] = image.size;
+    let size = resvg::tiny_skia::IntSize::from_wh(width as u32, height as u32)
+        .ok_or("Captured image is empty")?;
+    // Both store premultiplied RGBA, so the bytes carry over as-is
+    let pixmap = resvg::tiny_skia::Pixmap::from_vec(image.as_raw().to_vec(), size)
+        .ok_or("Captured image has an unexpected size")?;
+    let path = utils::download_path(&format!(
+        "solana-ui-{}-{}.png",
+        tab.id().to_lowercase(),
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ))?;
+    pixmap.save_png(&path).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// Outcome of testing a custom RPC URL before switching to it.
+#[derive(Debug, Clone)]
+enum ConnectionTest {
+    Running,
+    Succeeded(String),
+    Failed(String),
+}
+
+/// Outcome of pinging one endpoint from the endpoint ping window.
+#[derive(Debug, Clone)]
+enum PingOutcome {
+    Running,
+    Reached { slot: u64, latency: Duration },
+    Failed(String),
+}
+
+/// A configured endpoint and how it answered the latest ping.
+#[derive(Debug, Clone)]
+struct EndpointPing {
+    cluster: Cluster,
+    url: String,
+    outcome: PingOutcome,
+}
+
+/// Endpoints of the latest ping run; tasks of an older run don't write here.
+#[derive(Debug, Clone, Default)]
+struct EndpointPings {
+    /// Counts ping runs, so results of a superseded run can be told apart
+    generation: u64,
+    endpoints: Vec<EndpointPing>,
+}
+
+/// Reachability of the RPC node, checked before the initial data fetch.
+#[derive(Debug, Clone)]
+enum Connectivity {
+    Unchecked,
+    Checking,
+    Online,
+    Offline(String),
+}
+
+/// Data wiped by a clear button, held while its confirmation dialog is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClearTarget {
+    Logs,
+    FindVoters,
+    LeaderSchedule,
+}
+
+impl ClearTarget {
+    /// What gets cleared, for the confirmation prompt.
+    fn description(self) -> &'static str {
+        match self {
+            ClearTarget::Logs => "all log entries",
+            ClearTarget::FindVoters => "the Find Voters results and searches",
+            ClearTarget::LeaderSchedule => "the leader schedule results and searches",
+        }
+    }
+}
+
+/// Where a captured image of the active tab goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaptureTarget {
+    Clipboard,
+    Png,
+}
+
+/// Progress of a table capture; the screenshot arrives a frame after it is requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TableCapture {
+    Idle,
+    /// Picked from the menu; requested on the next frame, once the menu has closed
+    Requested(CaptureTarget),
+    /// Screenshot requested, waiting for the frame to come back
+    Waiting {
+        target: CaptureTarget,
+        /// Frames drawn since the request; gives up after `CAPTURE_TIMEOUT_FRAMES`
+        frames: u32,
+    },
+}
+
+/// Validators and gossip nodes frozen by the Hold toggle, shown instead of the
+/// live session data until released.
+struct HeldData {
+    validators: Vec<ValidatorInfo>,
+    /// Session `validators_generation` the held validators were copied at
+    validators_generation: u64,
+    gossip_nodes: Vec<GossipNodeInfo>,
+    validators_refreshed_at: Option<chrono::DateTime<chrono::Local>>,
+    gossip_refreshed_at: Option<chrono::DateTime<chrono::Local>>,
+}
+
+/// Alias being edited in the alias dialog.
+struct AliasEditor {
+    pubkey: Pubkey,
+    name: String,
+    /// Why the last save failed
+    error: Option<String>,
+}
+
+/// A long-running fetch the busy overlay waits on.
+struct BusyTask {
+    /// What is running, shown in the overlay
+    label: String,
+    operation: FetchOperation,
+    handle: tokio::task::JoinHandle<()>,
+}
+
+/// RPC client and data stores for one cluster.
+///
+/// Background tasks hold clones of the stores, so a session keeps receiving
+/// results while another one is shown.
+struct ClusterSession {
+    cluster: Cluster,
+    client: SolanaClient,
     validators: Arc<Mutex<Vec<ValidatorInfo>>>,
+    /// Bumped each time `validators` is replaced, while its lock is held
+    validators_generation: Arc<Mutex<u64>>,
     gossip_nodes: Arc<Mutex<Vec<GossipNodeInfo>>>,
+    slot_info: Arc<Mutex<SlotInfo>>,
+    total_supply: Arc<Mutex<Option<u64>>>,
+    /// Validator count returned by RPC when the stored list was capped
+    validators_capped_from: Arc<Mutex<Option<usize>>>,
+    /// Lookups of the last validators refresh that failed
+    validators_failed_steps: Arc<Mutex<Vec<PartialFailure>>>,
+    rpc_version: Arc<Mutex<Option<String>>>,
+    network_overview: Arc<Mutex<Option<NetworkOverview>>>,
+    status_info: Arc<Mutex<StatusInfo>>,
+    /// When gossip nodes were last fetched successfully
+    gossip_refreshed_at: Arc<Mutex<Option<chrono::DateTime<chrono::Local>>>>,
+    /// Vote account balance lookups made from the detail drawer
+    vote_balances: Arc<Mutex<HashMap<Pubkey, VoteBalanceState>>>,
     slot_voter_result: Arc<Mutex<Option<SlotVoterInfo>>>,
+    /// Number of the find voters fetch that stored `slot_voter_result`
+    slot_voter_fetch: Arc<Mutex<u64>>,
     leader_schedule_result: Arc<Mutex<Option<LeaderScheduleInfo>>>,
-    slot_info: Arc<Mutex<SlotInfo>>,
+    leader_schedule_state: Arc<Mutex<LeaderScheduleState>>,
+
+    // Commission tracking across refreshes (keyed by vote account)
+    commission_history: Arc<Mutex<HashMap<Pubkey, u8>>>,
+    commission_changes: Arc<Mutex<HashMap<Pubkey, CommissionChange>>>,
+    commission_alerts: Arc<Mutex<Vec<CommissionChange>>>,
+    notified_alert_count: usize,
+    /// When each validator last changed between refreshes (keyed by vote account)
+    validator_changed_at: Arc<Mutex<HashMap<Pubkey, Instant>>>,
+    /// Latest failure of each background fetch, cleared by its next success
+    fetch_errors: Arc<Mutex<BTreeMap<FetchOperation, String>>>,
+}
+
+impl ClusterSession {
+    fn new(cluster: Cluster, client: SolanaClient) -> Self {
+        Self {
+            cluster,
+            client,
+            validators: Arc::new(Mutex::new(Vec::new())),
+            validators_generation: Arc::new(Mutex::new(0)),
+            gossip_nodes: Arc::new(Mutex::new(Vec::new())),
+            slot_info: Arc::new(Mutex::new((None, None, None))),
+            total_supply: Arc::new(Mutex::new(None)),
+            validators_capped_from: Arc::new(Mutex::new(None)),
+            validators_failed_steps: Arc::new(Mutex::new(Vec::new())),
+            rpc_version: Arc::new(Mutex::new(None)),
+            network_overview: Arc::new(Mutex::new(None)),
+            status_info: Arc::new(Mutex::new(StatusInfo {
+                cluster: cluster.name().to_string(),
+                ..StatusInfo::default()
+            })),
+            gossip_refreshed_at: Arc::new(Mutex::new(None)),
+            vote_balances: Arc::new(Mutex::new(HashMap::new())),
+            slot_voter_result: Arc::new(Mutex::new(None)),
+            slot_voter_fetch: Arc::new(Mutex::new(0)),
+            leader_schedule_result: Arc::new(Mutex::new(None)),
+            leader_schedule_state: Arc::new(Mutex::new(LeaderScheduleState::Idle)),
+            commission_history: Arc::new(Mutex::new(HashMap::new())),
+            commission_changes: Arc::new(Mutex::new(HashMap::new())),
+            commission_alerts: Arc::new(Mutex::new(Vec::new())),
+            notified_alert_count: 0,
+            validator_changed_at: Arc::new(Mutex::new(HashMap::new())),
+            fetch_errors: Arc::new(Mutex::new(BTreeMap::new())),
+        }
+    }
+
+    /// Data the status server reports while this session is shown.
+    fn status_server_state(&self) -> StatusServerState {
+        StatusServerState {
+            validators: Arc::clone(&self.validators),
+            slot_info: Arc::clone(&self.slot_info),
+            status_info: Arc::clone(&self.status_info),
+        }
+    }
+}
+
+/// Main application struct managing all UI state and data.
+pub struct ValidatorApp {
+    // Data stores
+    /// Open cluster sessions; the tabs show the one at `active_session`
+    sessions: Vec<ClusterSession>,
+    active_session: usize,
+    /// Sources the status server reports on, pointed at the active session
+    status_server_state: Option<Arc<Mutex<StatusServerState>>>,
+    rolling_voters: Arc<Mutex<RollingVoters>>,
     log_store: logs::LogStore,
 
+    /// User-triggered fetches whose tasks finished since the last frame
+    completed_fetches: Arc<Mutex<BTreeSet<FetchOperation>>>,
+    /// Heavy fetch in flight, for the busy overlay
+    busy_task: Option<BusyTask>,
+
     // UI state
     current_tab: AppTab,
+    /// Last tab other than Logs, restored by the logs toggle shortcut
+    previous_tab: AppTab,
     sort_states: Vec<SortState>,
-    error_message: Option<String>,
+    /// Column the gossip nodes table is sorted by
+    gossip_sort: Option<(GossipSortColumn, SortDirection)>,
+    selected_validator: Option<Pubkey>,
+    /// Validator the detail drawer showed last frame, to spot it opening
+    drawer_validator: Option<Pubkey>,
+    /// Set when the drawer opened and its balance lookup has not started yet
+    vote_balance_pending: bool,
+    watchlist_import: WatchlistImport,
+    validator_snapshots: ValidatorSnapshots,
+    vote_account_jump: VoteAccountJump,
+    /// Keyboard row cursor of the validators table
+    row_cursor: RowCursor,
+    validator_export: ValidatorExport,
+    commission_chart_stake_weighted: bool,
+    validators_outdated_only: bool,
+    /// Commission tier the validators tab is filtered to
+    validators_commission_tier: Option<usize>,
+    /// Data frozen by the Hold toggle on the validators and gossip tabs
+    held: Option<HeldData>,
+    /// Last validators and gossip nodes read from the session, shown while a
+    /// fetch holds the lock so the tables don't blank out mid-update
+    shown_validators: Arc<Vec<ValidatorInfo>>,
+    /// Session `validators_generation` of `shown_validators`; `None` until first copied
+    shown_validators_generation: Option<u64>,
+    shown_gossip_nodes: Arc<Vec<GossipNodeInfo>>,
+    /// Failures per fetch in the active session, copied from its `fetch_errors` each frame
+    error_messages: BTreeMap<FetchOperation, String>,
 
     // Search fields
     identity_search: String,
@@ -61,29 +394,110 @@ pub struct ValidatorApp {
     gossip_nodes_search: String,
     find_voters_search: String,
     logs_search: String,
+    /// Auto-scroll paused by scrolling up in the Logs tab, for this run only
+    logs_scroll_paused: bool,
 
     // Search focus state
     should_focus_search: bool,
 
+    // Keyboard shortcut overlay visibility
+    show_shortcuts: bool,
+
+    // Settings window visibility
+    show_settings: bool,
+
+    // Clear awaiting confirmation
+    pending_clear: Option<ClearTarget>,
+
+    // Image capture of the active tab, cropped to its table
+    table_capture: TableCapture,
+    table_region: egui::Rect,
+
+    // Custom RPC endpoint entry
+    custom_rpc_input: String,
+    /// Custom RPC window opened by picking "Custom…" with no saved URL
+    show_custom_rpc_entry: bool,
+    connection_test: Arc<Mutex<Option<ConnectionTest>>>,
+
+    // Latency and slot of every configured endpoint
+    endpoint_pings: Arc<Mutex<EndpointPings>>,
+    ping_generation: u64,
+    show_endpoint_pings: bool,
+
+    // Startup reachability check
+    connectivity: Arc<Mutex<Connectivity>>,
+    initial_fetch_done: bool,
+    /// Whether the Validators tab has been shown since the startup fetch
+    validators_tab_shown: bool,
+
     // Tabs
     update_tab: UpdateTab,
+    voters_table_cache: VotersTableCache,
+    /// Find voters fetches started across all sessions, numbering each one
+    voter_fetch_count: u64,
+    /// Vote account the find voters tab answers yes/no for
+    voter_target_account: String,
+    rolling_voters_mode: bool,
+    last_rolling_scan: Option<Instant>,
+    last_rpc_version_check: Option<Instant>,
+    last_slot_poll: Option<Instant>,
+    /// When the last metrics CSV row was written
+    last_metrics_log: Arc<Mutex<Option<Instant>>>,
+    /// Whether the window was focused and visible this frame
+    window_active: bool,
+    last_network_overview_fetch: Option<Instant>,
+    /// Window title last sent to the viewport
+    window_title: String,
+    /// Accent last applied to the egui selection style
+    applied_accent: Option<[u8; 3]>,
 
     // Backend services
     rt: Option<tokio::runtime::Runtime>,
     status_manager: StatusManager,
-    solana_client: SolanaClient,
-    selected_cluster: Cluster,
     config_manager: ConfigManager,
     last_config_save: Instant,
+    aliases: AliasStore,
+    /// Pubkey whose alias a table row asked to edit this frame
+    alias_edit_request: Option<Pubkey>,
+    alias_editor: Option<AliasEditor>,
 }
 
 impl Default for ValidatorApp {
     fn default() -> Self {
-        let rt = tokio::runtime::Runtime::new().unwrap();
+        Self::with_overrides(StartupOverrides::default())
+    }
+}
+
+impl ValidatorApp {
+    pub fn new(_cc: &eframe::CreationContext<'_>, overrides: StartupOverrides) -> Self {
+        Self::with_overrides(overrides)
+    }
+
+    /// Build the app, starting on the command-line cluster when one was given.
+    /// The overrides only pick the startup session; the config file keeps its values.
+    fn with_overrides(overrides: StartupOverrides) -> Self {
         let config_manager = ConfigManager::new();
         let config = config_manager.config();
+        // RPC calls run on the blocking pool, so a few async workers are plenty
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(config.runtime_worker_threads.max(1))
+            .enable_all()
+            .build()
+            .unwrap();
+        let aliases = AliasStore::load();
 
         let log_store = logs::create_log_store();
+        let mut update_tab = UpdateTab::new(log_store.clone());
+        update_tab.set_download_dir(config.download_dir.clone());
+        let mut cluster = overrides.cluster.unwrap_or(config.selected_cluster);
+        let mut rpc_url = overrides
+            .rpc_url
+            .unwrap_or_else(|| cluster.endpoint(&config.custom_rpc_url).to_string());
+        if rpc_url.is_empty() {
+            eprintln!("--cluster custom needs --rpc-url or a saved custom RPC URL; using config");
+            cluster = config.selected_cluster;
+            rpc_url = cluster.endpoint(&config.custom_rpc_url).to_string();
+        }
 
         // Add sample log entries to demonstrate functionality
         logs::log_request(
@@ -92,40 +506,74 @@ impl Default for ValidatorApp {
             "system",
             &format!(
                 "Application started with {} cluster ({})",
-                config.selected_cluster.name(),
-                config.selected_cluster.url()
+                cluster.name(),
+                rpc_url
             ),
         );
         logs::log_request(
             &log_store,
             "get_cluster_nodes",
-            config.selected_cluster.url(),
-            &format!("endpoint: {}", config.selected_cluster.url()),
+            &rpc_url,
+            &format!("endpoint: {}", rpc_url),
         );
         logs::log_response(
             &log_store,
             "get_cluster_nodes",
-            config.selected_cluster.url(),
+            &rpc_url,
             "200 nodes found",
             "200 OK",
+            None,
         );
         logs::log_request(
             &log_store,
             "get_vote_accounts",
-            config.selected_cluster.url(),
-            &format!("endpoint: {}", config.selected_cluster.url()),
+            &rpc_url,
+            &format!("endpoint: {}", rpc_url),
+        );
+
+        let session = ClusterSession::new(
+            cluster,
+            SolanaClient::new(rpc_url, log_store.clone(), config.rpc_commitments.clone()),
         );
 
+        // Optional local status endpoint for headless monitoring of the shown session
+        let status_server_state = config.status_server_enabled.then(|| {
+            let state = Arc::new(Mutex::new(session.status_server_state()));
+            rt.spawn(status_server::serve(
+                config.status_server_port,
+                Arc::clone(&state),
+                log_store.clone(),
+            ));
+            state
+        });
+
         Self {
-            validators: Arc::new(Mutex::new(Vec::new())),
-            gossip_nodes: Arc::new(Mutex::new(Vec::new())),
-            slot_voter_result: Arc::new(Mutex::new(None)),
-            leader_schedule_result: Arc::new(Mutex::new(None)),
-            slot_info: Arc::new(Mutex::new((None, None, None))),
+            sessions: vec![session],
+            active_session: 0,
+            status_server_state,
+            rolling_voters: Arc::new(Mutex::new(RollingVoters::default())),
             log_store: log_store.clone(),
-            error_message: None,
+            completed_fetches: Arc::new(Mutex::new(BTreeSet::new())),
+            busy_task: None,
+            error_messages: BTreeMap::new(),
             rt: Some(rt),
             sort_states: Vec::new(),
+            gossip_sort: None,
+            selected_validator: None,
+            drawer_validator: None,
+            vote_balance_pending: false,
+            watchlist_import: WatchlistImport::default(),
+            validator_snapshots: ValidatorSnapshots::default(),
+            vote_account_jump: VoteAccountJump::default(),
+            row_cursor: RowCursor::default(),
+            validator_export: ValidatorExport::default(),
+            commission_chart_stake_weighted: false,
+            validators_outdated_only: false,
+            validators_commission_tier: None,
+            held: None,
+            shown_validators: Arc::default(),
+            shown_validators_generation: None,
+            shown_gossip_nodes: Arc::default(),
             identity_search: config.last_identity_search.clone(),
             vote_account_search: config.last_vote_account_search.clone(),
             slot_search: config.last_slot_search.clone(),
@@ -137,131 +585,424 @@ impl Default for ValidatorApp {
             gossip_nodes_search: String::new(),
             find_voters_search: String::new(),
             logs_search: String::new(),
+            logs_scroll_paused: false,
             should_focus_search: false,
-            update_tab: UpdateTab::new(log_store.clone()),
+            show_shortcuts: false,
+            show_settings: false,
+            pending_clear: None,
+            table_capture: TableCapture::Idle,
+            table_region: egui::Rect::NOTHING,
+            custom_rpc_input: config.custom_rpc_url.clone(),
+            show_custom_rpc_entry: false,
+            connection_test: Arc::new(Mutex::new(None)),
+            endpoint_pings: Arc::new(Mutex::new(EndpointPings::default())),
+            ping_generation: 0,
+            show_endpoint_pings: false,
+            connectivity: Arc::new(Mutex::new(Connectivity::Unchecked)),
+            initial_fetch_done: false,
+            validators_tab_shown: false,
+            update_tab,
+            voters_table_cache: VotersTableCache::default(),
+            voter_fetch_count: 0,
+            voter_target_account: String::new(),
+            rolling_voters_mode: false,
+            last_rolling_scan: None,
+            last_rpc_version_check: None,
+            last_slot_poll: None,
+            last_metrics_log: Arc::new(Mutex::new(None)),
+            window_active: true,
+            last_network_overview_fetch: None,
+            window_title: String::new(),
+            applied_accent: None,
             status_manager: StatusManager::default(),
-            solana_client: SolanaClient::new(
-                config.selected_cluster.url().to_string(),
-                log_store.clone(),
-            ),
-            selected_cluster: config.selected_cluster,
             current_tab: AppTab::from_id(&config.last_selected_tab),
+            previous_tab: AppTab::Validators,
             config_manager,
             last_config_save: Instant::now(),
+            aliases,
+            alias_edit_request: None,
+            alias_editor: None,
         }
     }
-}
 
-impl ValidatorApp {
-    pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
-        Self::default()
+    /// The cluster session shown in the tabs.
+    fn session(&self) -> &ClusterSession {
+        &self.sessions[self.active_session]
+    }
+
+    fn session_mut(&mut self) -> &mut ClusterSession {
+        &mut self.sessions[self.active_session]
     }
 
     // Data fetching methods
     pub fn refresh_validators(&mut self) {
-        if self.status_manager.validators_loading {
+        if self.status_manager.is_fetching(FetchOperation::Validators) {
             return;
         }
 
-        self.status_manager.start_validators_refresh();
-        self.error_message = None;
-
-        let validators_clone = Arc::clone(&self.validators);
-        let client = self.solana_client.clone();
+        self.status_manager.start_fetch(FetchOperation::Validators);
+
+        let validators_clone = Arc::clone(&self.session().validators);
+        let validators_generation_clone = Arc::clone(&self.session().validators_generation);
+        let total_supply_clone = Arc::clone(&self.session().total_supply);
+        let validators_capped_from_clone = Arc::clone(&self.session().validators_capped_from);
+        let validators_failed_steps_clone = Arc::clone(&self.session().validators_failed_steps);
+        let max_validators = self.config_manager.config().max_validators;
+        let include_delinquent = self.config_manager.config().include_delinquent_validators;
+        let commission_history_clone = Arc::clone(&self.session().commission_history);
+        let commission_changes_clone = Arc::clone(&self.session().commission_changes);
+        let commission_alerts_clone = Arc::clone(&self.session().commission_alerts);
+        let validator_changed_at_clone = Arc::clone(&self.session().validator_changed_at);
+        let watchlist = self.config_manager.config().watchlist.clone();
+        let status_info_clone = Arc::clone(&self.session().status_info);
+        let completed_fetches_clone = Arc::clone(&self.completed_fetches);
+        let fetch_errors_clone = Arc::clone(&self.session().fetch_errors);
+        let network_overview_clone = Arc::clone(&self.session().network_overview);
+        let last_metrics_log_clone = Arc::clone(&self.last_metrics_log);
+        let metrics_log = self.metrics_log_target();
+        let cluster = self.session().cluster.name();
+        let log_store = self.log_store.clone();
+        let client = self.session().client.clone();
 
         if let Some(rt) = &self.rt {
             rt.spawn(async move {
-                match client.fetch_validators().await {
-                    Ok(new_validators) => {
+                let mut metrics_row = None;
+                match with_retry(&client, "get_vote_accounts", || {
+                    client.fetch_validators(include_delinquent)
+                })
+                .await
+                {
+                    Ok(ValidatorsFetch {
+                        validators: mut new_validators,
+                        failed_steps,
+                    }) => {
+                        *validators_failed_steps_clone.lock().await = failed_steps;
+                        // Drop low-stake validators before anything else holds on to them
+                        *validators_capped_from_clone.lock().await =
+                            max_validators.and_then(|limit| {
+                                utils::cap_validators_by_stake(&mut new_validators, limit)
+                            });
+
+                        let changes = {
+                            let mut history = commission_history_clone.lock().await;
+                            let changes =
+                                validators::detect_commission_changes(&history, &new_validators);
+                            *history = new_validators
+                                .iter()
+                                .map(|v| (v.vote_account, v.commission))
+                                .collect();
+                            changes
+                        };
+
+                        // Alert on commission increases for watchlisted validators
+                        let mut alerts = commission_alerts_clone.lock().await;
+                        for change in changes.iter().filter(|c| {
+                            c.is_increase() && watchlist.contains(&c.identity.to_string())
+                        }) {
+                            logs::log_error(
+                                &log_store,
+                                "commission_alert",
+                                "system",
+                                &format!(
+                                    "Commission of {} increased from {}% to {}%",
+                                    change.identity, change.previous, change.current
+                                ),
+                            );
+                            alerts.push(change.clone());
+                        }
+
+                        let mut commission_changes = commission_changes_clone.lock().await;
+                        *commission_changes = changes
+                            .into_iter()
+                            .map(|change| (change.vote_account, change))
+                            .collect();
+
                         let mut validators = validators_clone.lock().await;
+                        let now = Instant::now();
+                        *validator_changed_at_clone.lock().await =
+                            validators::detect_changed_validators(&validators, &new_validators)
+                                .into_iter()
+                                .map(|vote_account| (vote_account, now))
+                                .collect();
                         *validators = new_validators;
+                        *validators_generation_clone.lock().await += 1;
+
+                        let mut status_info = status_info_clone.lock().await;
+                        status_info.last_refresh = Some(chrono::Local::now());
+                        status_info.last_error = None;
+                        fetch_errors_clone
+                            .lock()
+                            .await
+                            .remove(&FetchOperation::Validators);
+
+                        // Record what this refresh stored, at most once per interval
+                        if let Some((path, interval)) = metrics_log {
+                            let mut last_metrics_log = last_metrics_log_clone.lock().await;
+                            if last_metrics_log.is_none_or(|last| last.elapsed() >= interval) {
+                                *last_metrics_log = Some(Instant::now());
+                                let overview = network_overview_clone.lock().await.clone();
+                                let row = MetricsRow::new(cluster, &validators, overview.as_ref());
+                                metrics_row = Some((path, row));
+                            }
+                        }
                     }
                     Err(e) => {
                         eprintln!("Error fetching validators: {}", e);
+                        status_info_clone.lock().await.last_error = Some(e.to_string());
+                        fetch_errors_clone
+                            .lock()
+                            .await
+                            .insert(FetchOperation::Validators, e.to_string());
+                    }
+                }
+
+                // Supply backs the derived "% of supply staked" figure
+                match client.fetch_total_supply().await {
+                    Ok(supply) => {
+                        *total_supply_clone.lock().await = Some(supply);
+                    }
+                    Err(e) => {
+                        eprintln!("Error fetching supply: {}", e);
                     }
                 }
+                if let Some((path, row)) = metrics_row {
+                    append_metrics_row(path, row, log_store).await;
+                }
+                completed_fetches_clone
+                    .lock()
+                    .await
+                    .insert(FetchOperation::Validators);
             });
         }
     }
 
     pub fn refresh_gossip_nodes(&mut self) {
-        if self.status_manager.validators_loading {
+        if self.status_manager.is_fetching(FetchOperation::GossipNodes) {
             return;
         }
 
-        self.status_manager.start_validators_refresh();
-        self.error_message = None;
+        self.status_manager.start_fetch(FetchOperation::GossipNodes);
 
-        let gossip_nodes_clone = Arc::clone(&self.gossip_nodes);
-        let client = self.solana_client.clone();
+        let gossip_nodes_clone = Arc::clone(&self.session().gossip_nodes);
+        let gossip_refreshed_at_clone = Arc::clone(&self.session().gossip_refreshed_at);
+        let completed_fetches_clone = Arc::clone(&self.completed_fetches);
+        let fetch_errors_clone = Arc::clone(&self.session().fetch_errors);
+        let status_info_clone = Arc::clone(&self.session().status_info);
+        let client = self.session().client.clone();
 
         if let Some(rt) = &self.rt {
             rt.spawn(async move {
-                match client.fetch_cluster_nodes().await {
+                match with_retry(&client, "get_cluster_nodes", || {
+                    client.fetch_cluster_nodes()
+                })
+                .await
+                {
                     Ok(new_nodes) => {
                         let mut gossip_nodes = gossip_nodes_clone.lock().await;
                         *gossip_nodes = new_nodes;
+                        *gossip_refreshed_at_clone.lock().await = Some(chrono::Local::now());
+                        fetch_errors_clone
+                            .lock()
+                            .await
+                            .remove(&FetchOperation::GossipNodes);
                     }
                     Err(e) => {
                         eprintln!("Error fetching gossip nodes: {}", e);
+                        status_info_clone.lock().await.last_error = Some(e.to_string());
+                        fetch_errors_clone
+                            .lock()
+                            .await
+                            .insert(FetchOperation::GossipNodes, e.to_string());
                     }
                 }
+                completed_fetches_clone
+                    .lock()
+                    .await
+                    .insert(FetchOperation::GossipNodes);
             });
         }
     }
 
-    pub fn search_voters_in_slot(&mut self, slot: u64) {
-        if self.status_manager.validators_loading {
+    pub fn search_voters_in_slots(&mut self, start_slot: u64, end_slot: u64) {
+        if self.status_manager.is_fetching(FetchOperation::FindVoters) || self.busy_overlay_active()
+        {
             return;
         }
+        let term = self.slot_search.clone();
+        self.remember_search(SearchField::FindVotersSlot, &term);
+
+        self.status_manager.start_fetch(FetchOperation::FindVoters);
+        self.voter_fetch_count += 1;
+        let fetch_number = self.voter_fetch_count;
+
+        let slot_voter_result_clone = Arc::clone(&self.session().slot_voter_result);
+        let slot_voter_fetch_clone = Arc::clone(&self.session().slot_voter_fetch);
+        let completed_fetches_clone = Arc::clone(&self.completed_fetches);
+        let fetch_errors_clone = Arc::clone(&self.session().fetch_errors);
+        let status_info_clone = Arc::clone(&self.session().status_info);
+        let client = self.session().client.clone();
+        let max_range = self.config_manager.config().max_find_voters_range;
+        let detail = self.config_manager.config().find_voters_detail;
 
-        self.status_manager.start_validators_refresh();
-        self.error_message = None;
+        if let Some(rt) = &self.rt {
+            let handle = rt.spawn(async move {
+                let result = if start_slot == end_slot {
+                    client.find_voters_in_slot(start_slot, detail).await
+                } else {
+                    client
+                        .find_voters_in_slot_range(start_slot, end_slot, max_range, detail)
+                        .await
+                };
+                match result {
+                    Ok(voter_info) => {
+                        let mut result = slot_voter_result_clone.lock().await;
+                        *result = Some(voter_info);
+                        *slot_voter_fetch_clone.lock().await = fetch_number;
+                        fetch_errors_clone
+                            .lock()
+                            .await
+                            .remove(&FetchOperation::FindVoters);
+                    }
+                    Err(e) => {
+                        eprintln!(
+                            "Error finding voters in slots {}-{}: {}",
+                            start_slot, end_slot, e
+                        );
+                        status_info_clone.lock().await.last_error = Some(e.to_string());
+                        fetch_errors_clone
+                            .lock()
+                            .await
+                            .insert(FetchOperation::FindVoters, e.to_string());
+                    }
+                }
+                completed_fetches_clone
+                    .lock()
+                    .await
+                    .insert(FetchOperation::FindVoters);
+            });
+            let label = if start_slot == end_slot {
+                format!("Finding voters in slot {}", start_slot)
+            } else {
+                format!("Finding voters in slots {}–{}", start_slot, end_slot)
+            };
+            self.busy_task = Some(BusyTask {
+                label,
+                operation: FetchOperation::FindVoters,
+                handle,
+            });
+        }
+    }
 
-        let slot_voter_result_clone = Arc::clone(&self.slot_voter_result);
-        let client = self.solana_client.clone();
+    /// Scan the latest finalized block and add its voters to the rolling window.
+    pub fn scan_rolling_voters(&mut self) {
+        let rolling_voters_clone = Arc::clone(&self.rolling_voters);
+        let client = self.session().client.clone();
+        let detail = self.config_manager.config().find_voters_detail;
 
         if let Some(rt) = &self.rt {
             rt.spawn(async move {
-                match client.find_voters_in_slot(slot).await {
+                // Errors are already in the Logs tab; the next scan tries again
+                let Ok(slot) = client.fetch_finalized_slot().await else {
+                    return;
+                };
+                if rolling_voters_clone.lock().await.has_scanned(slot) {
+                    return;
+                }
+                match client.find_voters_in_slot(slot, detail).await {
                     Ok(voter_info) => {
-                        let mut result = slot_voter_result_clone.lock().await;
-                        *result = Some(voter_info);
+                        rolling_voters_clone.lock().await.push(voter_info);
                     }
                     Err(e) => {
-                        eprintln!("Error finding voters in slot {}: {}", slot, e);
+                        // Skipped slots have no block; the next scan moves on
+                        eprintln!("Error scanning rolling voters in slot {}: {}", slot, e);
                     }
                 }
             });
         }
     }
 
-    pub fn fetch_leader_schedule(&mut self, identity: &str, epoch: Option<u64>) {
-        if self.status_manager.validators_loading {
+    /// Feed the rolling voters window on its refresh cadence while the view is open
+    /// and the window is in the foreground.
+    fn tick_rolling_voters(&mut self) {
+        if !self.rolling_voters_mode
+            || self.current_tab != AppTab::FindVoters
+            || !self.window_active
+        {
             return;
         }
+        if self.last_rolling_scan.is_some_and(|last| {
+            last.elapsed() < Duration::from_secs(ui_constants::ROLLING_VOTERS_INTERVAL_SECS)
+        }) {
+            return;
+        }
+        self.last_rolling_scan = Some(Instant::now());
+        self.scan_rolling_voters();
+    }
 
-        self.status_manager.start_validators_refresh();
-        self.error_message = None;
-
-        let leader_schedule_result_clone = Arc::clone(&self.leader_schedule_result);
-        let client = self.solana_client.clone();
+    pub fn fetch_leader_schedule(&mut self, identity: &str, epoch: Option<u64>) {
+        if self
+            .status_manager
+            .is_fetching(FetchOperation::LeaderSchedule)
+            || self.busy_overlay_active()
+        {
+            return;
+        }
+        self.remember_search(SearchField::LeaderIdentity, identity);
+
+        self.status_manager
+            .start_fetch(FetchOperation::LeaderSchedule);
+
+        let leader_schedule_result_clone = Arc::clone(&self.session().leader_schedule_result);
+        let leader_schedule_state_clone = Arc::clone(&self.session().leader_schedule_state);
+        let completed_fetches_clone = Arc::clone(&self.completed_fetches);
+        let fetch_errors_clone = Arc::clone(&self.session().fetch_errors);
+        let status_info_clone = Arc::clone(&self.session().status_info);
+        if let Ok(mut state) = self.session().leader_schedule_state.try_lock() {
+            *state = LeaderScheduleState::Loading;
+        }
+        let client = self.session().client.clone();
         let identity_clone = identity.to_string();
 
         if let Some(rt) = &self.rt {
-            rt.spawn(async move {
+            let handle = rt.spawn(async move {
                 match client.fetch_leader_schedule(&identity_clone, epoch).await {
                     Ok(leader_info) => {
+                        let state = if leader_info.leader_slots.is_empty() {
+                            LeaderScheduleState::Empty
+                        } else {
+                            LeaderScheduleState::Loaded
+                        };
                         let mut result = leader_schedule_result_clone.lock().await;
                         *result = Some(leader_info);
+                        *leader_schedule_state_clone.lock().await = state;
+                        fetch_errors_clone
+                            .lock()
+                            .await
+                            .remove(&FetchOperation::LeaderSchedule);
                     }
                     Err(e) => {
                         eprintln!(
                             "Error fetching leader schedule for {}: {}",
                             identity_clone, e
                         );
+                        *leader_schedule_state_clone.lock().await =
+                            LeaderScheduleState::Error(e.to_string());
+                        status_info_clone.lock().await.last_error = Some(e.to_string());
+                        fetch_errors_clone
+                            .lock()
+                            .await
+                            .insert(FetchOperation::LeaderSchedule, e.to_string());
                     }
                 }
+                completed_fetches_clone
+                    .lock()
+                    .await
+                    .insert(FetchOperation::LeaderSchedule);
+            });
+            self.busy_task = Some(BusyTask {
+                label: "Fetching leader schedule".to_string(),
+                operation: FetchOperation::LeaderSchedule,
+                handle,
             });
         }
     }
@@ -273,25 +1014,309 @@ impl ValidatorApp {
 
         self.status_manager.start_slot_refresh();
 
-        let slot_info_clone = Arc::clone(&self.slot_info);
-        let client = self.solana_client.clone();
+        let slot_info_clone = Arc::clone(&self.session().slot_info);
+        let status_info_clone = Arc::clone(&self.session().status_info);
+        let client = self.session().client.clone();
 
         if let Some(rt) = &self.rt {
             rt.spawn(async move {
-                match client.fetch_slot_info().await {
+                match with_retry(&client, "get_slot + get_epoch_info", || {
+                    client.fetch_slot_info()
+                })
+                .await
+                {
                     Ok((current_slot, latest_slot, current_epoch)) => {
                         let mut slot_info = slot_info_clone.lock().await;
                         *slot_info = (Some(current_slot), Some(latest_slot), Some(current_epoch));
                     }
                     Err(e) => {
                         eprintln!("Error fetching slot info: {}", e);
+                        status_info_clone.lock().await.last_error = Some(e.to_string());
+                    }
+                }
+            });
+        }
+    }
+
+    /// Fetch the RPC node version on cluster change and then periodically.
+    fn refresh_rpc_version(&mut self) {
+        self.last_rpc_version_check = Some(Instant::now());
+
+        let rpc_version_clone = Arc::clone(&self.session().rpc_version);
+        let client = self.session().client.clone();
+
+        if let Some(rt) = &self.rt {
+            rt.spawn(async move {
+                match client.fetch_version().await {
+                    Ok(version) => {
+                        *rpc_version_clone.lock().await = Some(version);
+                    }
+                    Err(e) => {
+                        eprintln!("Error fetching RPC version: {}", e);
+                    }
+                }
+            });
+        }
+    }
+
+    /// Fetch epoch progress and throughput for the network tab.
+    fn refresh_network_overview(&mut self) {
+        self.last_network_overview_fetch = Some(Instant::now());
+
+        let network_overview_clone = Arc::clone(&self.session().network_overview);
+        let client = self.session().client.clone();
+
+        if let Some(rt) = &self.rt {
+            rt.spawn(async move {
+                match with_retry(
+                    &client,
+                    "get_epoch_info + get_recent_performance_samples",
+                    || client.fetch_network_overview(),
+                )
+                .await
+                {
+                    Ok(overview) => {
+                        *network_overview_clone.lock().await = Some(overview);
+                    }
+                    Err(e) => {
+                        eprintln!("Error fetching network overview: {}", e);
                     }
                 }
             });
         }
     }
 
+    /// Look up the drawer validator's vote account balance once when the drawer opens on
+    /// it, unless a recent lookup is cached.
+    fn fetch_vote_balance_on_open(&mut self) {
+        if self.drawer_validator != self.selected_validator {
+            self.drawer_validator = self.selected_validator;
+            self.vote_balance_pending = self.selected_validator.is_some();
+        }
+        // Wait for a bulk refresh to finish rather than adding to its load
+        if !self.vote_balance_pending || self.status_manager.is_fetching(FetchOperation::Validators)
+        {
+            return;
+        }
+        let Some(vote_account) = self.selected_validator else {
+            return;
+        };
+        let vote_balances_clone = Arc::clone(&self.session().vote_balances);
+        let Ok(mut vote_balances) = vote_balances_clone.try_lock() else {
+            return;
+        };
+        self.vote_balance_pending = false;
+        if vote_balances
+            .get(&vote_account)
+            .is_some_and(VoteBalanceState::is_fresh)
+        {
+            return;
+        }
+        let Some(rt) = &self.rt else {
+            return;
+        };
+        vote_balances.insert(vote_account, VoteBalanceState::Loading);
+        drop(vote_balances);
+
+        let client = self.session().client.clone();
+        rt.spawn(async move {
+            let state = match client.fetch_vote_account_balance(vote_account).await {
+                Ok(balance) => VoteBalanceState::Loaded {
+                    balance,
+                    fetched_at: Instant::now(),
+                },
+                Err(e) => VoteBalanceState::Error {
+                    message: e.to_string(),
+                    fetched_at: Instant::now(),
+                },
+            };
+            vote_balances_clone.lock().await.insert(vote_account, state);
+        });
+    }
+
+    /// Check that the RPC node is reachable, then run the initial fetches once it is.
+    /// A failed check waits for the user to retry rather than polling.
+    fn tick_connectivity(&mut self) {
+        let connectivity = match self.connectivity.try_lock() {
+            Ok(guard) => guard.clone(),
+            Err(_) => return,
+        };
+        match connectivity {
+            Connectivity::Unchecked => self.check_connectivity(),
+            Connectivity::Online if !self.initial_fetch_done => {
+                self.initial_fetch_done = true;
+                self.refresh_validators();
+                self.refresh_slot_info();
+            }
+            _ => {}
+        }
+    }
+
+    /// Load validators the first time the Validators tab is shown without any,
+    /// e.g. when the startup fetch failed. Waits until the node is reachable and
+    /// any running validators fetch has finished, and only ever fires once.
+    fn tick_validators_first_show(&mut self) {
+        if self.validators_tab_shown
+            || self.current_tab != AppTab::Validators
+            || !self.initial_fetch_done
+            || self.status_manager.is_fetching(FetchOperation::Validators)
+            || matches!(
+                self.connectivity.try_lock().as_deref(),
+                Ok(Connectivity::Offline(_)) | Err(_)
+            )
+        {
+            return;
+        }
+        self.validators_tab_shown = true;
+        if self.latest_validators().is_empty() {
+            self.refresh_validators();
+        }
+    }
+
+    /// Send a new window title whenever the selected cluster changes.
+    fn update_window_title(&mut self, ctx: &egui::Context) {
+        let title = format!(
+            "{} — {}",
+            crate::constants::WINDOW_TITLE,
+            self.session().cluster.name()
+        );
+        if title != self.window_title {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Title(title.clone()));
+            self.window_title = title;
+        }
+    }
+
+    /// Display settings from the config, as the tabs read them while rendering.
+    fn display_settings(&self) -> DisplaySettings {
+        let config = self.config_manager.config();
+        DisplaySettings {
+            plain_labels: config.plain_labels,
+            abbreviate_pubkeys: config.abbreviate_pubkeys,
+            case_sensitive_pubkey_search: config.case_sensitive_pubkey_search,
+            stale_after_secs: config.stale_after_secs,
+            max_rendered_rows: config.max_rendered_rows.unwrap_or(0),
+            accent_color: config.accent_color,
+            colorblind_palette: config.colorblind_palette,
+            table_stripes: config.table_stripes,
+        }
+    }
+
+    /// Recolor selection highlights when the configured accent changes.
+    fn apply_accent_color(&mut self, ctx: &egui::Context) {
+        let accent = self.config_manager.config().accent_color;
+        if accent == self.applied_accent {
+            return;
+        }
+        ctx.style_mut(|style| {
+            style.visuals.selection.bg_fill = match accent {
+                Some([r, g, b]) => egui::Color32::from_rgb(r, g, b),
+                None if style.visuals.dark_mode => egui::Visuals::dark().selection.bg_fill,
+                None => egui::Visuals::light().selection.bg_fill,
+            };
+        });
+        self.applied_accent = accent;
+    }
+
+    /// Refresh slot info every `slot_poll_secs` once the initial fetch has run,
+    /// or every `background_slot_poll_secs` while the window is in the background.
+    fn tick_slot_poll(&mut self) {
+        let config = self.config_manager.config();
+        let poll_secs = if self.window_active {
+            config.slot_poll_secs
+        } else {
+            config.background_slot_poll_secs
+        };
+        if poll_secs == 0 || !self.initial_fetch_done {
+            return;
+        }
+        if self
+            .last_slot_poll
+            .is_some_and(|last| last.elapsed() < Duration::from_secs(poll_secs))
+        {
+            return;
+        }
+        self.last_slot_poll = Some(Instant::now());
+        self.refresh_slot_info();
+    }
+
+    /// CSV file and minimum row spacing for the metrics log, when it is enabled.
+    fn metrics_log_target(&self) -> Option<(PathBuf, Duration)> {
+        let config = self.config_manager.config();
+        config.metrics_log_enabled.then(|| {
+            let path = config
+                .metrics_log_path
+                .clone()
+                .unwrap_or_else(crate::config::default_metrics_log_path);
+            let interval = Duration::from_secs(config.metrics_log_interval_secs.max(1));
+            (path, interval)
+        })
+    }
+
+    /// Probe the RPC node with getHealth/getVersion in the background.
+    fn check_connectivity(&mut self) {
+        if let Ok(mut connectivity) = self.connectivity.try_lock() {
+            *connectivity = Connectivity::Checking;
+        }
+
+        let connectivity_clone = Arc::clone(&self.connectivity);
+        let client = self.session().client.clone();
+
+        if let Some(rt) = &self.rt {
+            rt.spawn(async move {
+                // Only transport failures mean we are offline; an unhealthy or
+                // rate-limiting node still answered, so let the fetches report it.
+                let outcome = match client.test_connection().await {
+                    Err(e @ (SolanaError::Network(_) | SolanaError::Timeout)) => {
+                        Connectivity::Offline(e.to_string())
+                    }
+                    _ => Connectivity::Online,
+                };
+                *connectivity_clone.lock().await = outcome;
+            });
+        }
+    }
+
+    /// Render the banner shown while the RPC node cannot be reached.
+    fn render_offline_banner(&mut self, ui: &mut egui::Ui) {
+        let error = match self.connectivity.try_lock().as_deref() {
+            Ok(Connectivity::Offline(error)) => error.clone(),
+            _ => return,
+        };
+
+        let mut retry = false;
+        utils::create_error_frame(ui.ctx()).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.colored_label(
+                    utils::palette_color(ui.ctx(), StatusColor::Error),
+                    format!(
+                        "📡 No network connection: could not reach {} ({})",
+                        self.session().cluster.name(),
+                        self.cluster_url(self.session().cluster)
+                    ),
+                );
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    retry = ui
+                        .button(utils::icon_text(ui.ctx(), "🔄", "Retry"))
+                        .clicked();
+                });
+            });
+            ui.label(egui::RichText::new(error).weak());
+        });
+        ui.add_space(8.0);
+
+        if retry {
+            self.check_connectivity();
+        }
+    }
+
     // Configuration methods
+    fn cluster_url(&self, cluster: Cluster) -> String {
+        cluster
+            .endpoint(&self.config_manager.config().custom_rpc_url)
+            .to_string()
+    }
+
+    /// Switch to a cluster. Re-selecting `Custom` reconnects, since its URL may have changed.
     pub fn change_cluster(&mut self, new_cluster: Cluster) {
         // Always log cluster change attempts for debugging
         logs::log_request(
@@ -300,13 +1325,15 @@ impl ValidatorApp {
             "system",
             &format!(
                 "Attempt to change from {} to {} (current != new: {})",
-                self.selected_cluster.name(),
+                self.session().cluster.name(),
                 new_cluster.name(),
-                self.selected_cluster != new_cluster
+                self.session().cluster != new_cluster
             ),
         );
 
-        if self.selected_cluster != new_cluster {
+        if self.session().cluster != new_cluster || new_cluster == Cluster::Custom {
+            let new_url = self.cluster_url(new_cluster);
+
             // Log the cluster change
             logs::log_request(
                 &self.log_store,
@@ -314,15 +1341,53 @@ impl ValidatorApp {
                 "system",
                 &format!(
                     "Changing from {} to {} ({})",
-                    self.selected_cluster.name(),
+                    self.session().cluster.name(),
                     new_cluster.name(),
-                    new_cluster.url()
+                    new_url
                 ),
             );
 
-            self.selected_cluster = new_cluster;
-            self.solana_client =
-                SolanaClient::new(new_cluster.url().to_string(), self.log_store.clone());
+            let client = SolanaClient::new(
+                new_url,
+                self.log_store.clone(),
+                self.config_manager.config().rpc_commitments.clone(),
+            );
+            let session = self.session_mut();
+            session.cluster = new_cluster;
+            session.client = client;
+            if let Some(rt) = &self.rt {
+                // Wait for the lock: a skipped update would report the old cluster indefinitely
+                let status_info = Arc::clone(&self.session().status_info);
+                let cluster_name = new_cluster.name().to_string();
+                rt.spawn(async move {
+                    status_info.lock().await.cluster = cluster_name;
+                });
+            }
+            if let Ok(mut rolling_voters) = self.rolling_voters.try_lock() {
+                rolling_voters.clear();
+            }
+            if let Ok(mut total_supply) = self.session().total_supply.try_lock() {
+                *total_supply = None;
+            }
+            if let Ok(mut rpc_version) = self.session().rpc_version.try_lock() {
+                *rpc_version = None;
+            }
+            if let Ok(mut network_overview) = self.session().network_overview.try_lock() {
+                *network_overview = None;
+            }
+            self.last_network_overview_fetch = None;
+            self.validator_snapshots = ValidatorSnapshots::default();
+            self.held = None;
+            self.shown_validators = Arc::default();
+            self.shown_validators_generation = None;
+            self.shown_gossip_nodes = Arc::default();
+
+            // Re-check reachability against the new endpoint
+            if let Ok(mut connectivity) = self.connectivity.try_lock()
+                && matches!(*connectivity, Connectivity::Offline(_))
+            {
+                *connectivity = Connectivity::Unchecked;
+            }
 
             // Save cluster change to config
             self.config_manager.update_cluster(new_cluster);
@@ -332,6 +1397,7 @@ impl ValidatorApp {
             self.refresh_validators();
             self.refresh_gossip_nodes();
             self.refresh_slot_info();
+            self.refresh_rpc_version();
 
             logs::log_response(
                 &self.log_store,
@@ -339,6 +1405,7 @@ impl ValidatorApp {
                 "system",
                 &format!("Successfully switched to {} cluster", new_cluster.name()),
                 "200 OK",
+                None,
             );
         } else {
             logs::log_response(
@@ -350,20 +1417,484 @@ impl ValidatorApp {
                     new_cluster.name()
                 ),
                 "No Change",
+                None,
             );
         }
     }
 
-    fn save_current_state(&mut self) {
-        self.config_manager
-            .update_leader_schedule(&self.leader_identity_search, &self.leader_epoch_search);
-        self.config_manager.update_search_filters(
-            &self.identity_search,
-            &self.vote_account_search,
-            &self.slot_search,
-            &self.voter_account_search,
-            &self.gossip_identity_search,
-        );
+    /// Rebuild the active session's client against the same URL, drop its cached
+    /// figures and refetch the current tab. Lists stay on screen until the
+    /// refetch replaces them.
+    pub fn reconnect(&mut self) {
+        let rpc_url = self.session().client.rpc_url().to_string();
+        logs::log_request(
+            &self.log_store,
+            "reconnect",
+            "system",
+            &format!("Rebuilding the RPC client for {}", rpc_url),
+        );
+
+        let client = SolanaClient::new(
+            rpc_url,
+            self.log_store.clone(),
+            self.config_manager.config().rpc_commitments.clone(),
+        );
+        self.session_mut().client = client;
+
+        // Drop figures the old client fetched, waiting out any fetch that holds a lock.
+        // The validator and gossip lists stay: they remain on screen until the refetch
+        // replaces them, and clearing them would blank tabs that aren't refetched now.
+        if let Some(rt) = &self.rt {
+            let session = self.session();
+            let slot_info = Arc::clone(&session.slot_info);
+            let total_supply = Arc::clone(&session.total_supply);
+            let rpc_version = Arc::clone(&session.rpc_version);
+            let network_overview = Arc::clone(&session.network_overview);
+            let vote_balances = Arc::clone(&session.vote_balances);
+            rt.spawn(async move {
+                *slot_info.lock().await = (None, None, None);
+                *total_supply.lock().await = None;
+                *rpc_version.lock().await = None;
+                *network_overview.lock().await = None;
+                vote_balances.lock().await.clear();
+            });
+        }
+        self.last_network_overview_fetch = None;
+
+        // Re-check reachability if the old client had given up on the node
+        if let Ok(mut connectivity) = self.connectivity.try_lock()
+            && matches!(*connectivity, Connectivity::Offline(_))
+        {
+            *connectivity = Connectivity::Unchecked;
+        }
+
+        self.refresh_slot_info();
+        self.refresh_rpc_version();
+        self.handle_tab_refresh();
+    }
+
+    /// Open a session for `cluster` next to the existing ones and show it.
+    fn open_session(&mut self, cluster: Cluster) {
+        let client = SolanaClient::new(
+            self.cluster_url(cluster),
+            self.log_store.clone(),
+            self.config_manager.config().rpc_commitments.clone(),
+        );
+        self.sessions.push(ClusterSession::new(cluster, client));
+        self.switch_session(self.sessions.len() - 1);
+
+        self.refresh_validators();
+        self.refresh_gossip_nodes();
+        self.refresh_slot_info();
+        self.refresh_rpc_version();
+    }
+
+    /// Close the active session, showing the one before it. The last session stays open.
+    fn close_session(&mut self) {
+        if self.sessions.len() < 2 {
+            return;
+        }
+        self.sessions.remove(self.active_session);
+        self.switch_session(self.active_session.saturating_sub(1));
+    }
+
+    /// Show the session at `index`, resetting view state tied to the previous cluster.
+    fn switch_session(&mut self, index: usize) {
+        if index >= self.sessions.len() {
+            return;
+        }
+        self.active_session = index;
+        let cluster = self.session().cluster;
+        logs::log_request(
+            &self.log_store,
+            "session_switch",
+            "system",
+            &format!("Showing session #{} ({})", index + 1, cluster.name()),
+        );
+
+        if let Ok(mut rolling_voters) = self.rolling_voters.try_lock() {
+            rolling_voters.clear();
+        }
+        self.last_network_overview_fetch = None;
+        self.validator_snapshots = ValidatorSnapshots::default();
+        self.held = None;
+        self.shown_validators = Arc::default();
+        self.shown_validators_generation = None;
+        self.shown_gossip_nodes = Arc::default();
+        self.selected_validator = None;
+        self.row_cursor = RowCursor::default();
+        if let Ok(mut connectivity) = self.connectivity.try_lock()
+            && matches!(*connectivity, Connectivity::Offline(_))
+        {
+            *connectivity = Connectivity::Unchecked;
+        }
+
+        // Report on the shown session, including after the previous one was closed
+        if let (Some(state), Some(rt)) = (&self.status_server_state, &self.rt) {
+            let state = Arc::clone(state);
+            let session_state = self.session().status_server_state();
+            rt.spawn(async move {
+                *state.lock().await = session_state;
+            });
+        }
+
+        self.config_manager.update_cluster(cluster);
+        self.config_manager.auto_save();
+    }
+
+    /// Render the session switcher menu contents.
+    fn render_sessions_menu(&mut self, ui: &mut egui::Ui) {
+        let mut switch_to = None;
+        for (index, session) in self.sessions.iter().enumerate() {
+            if ui
+                .selectable_label(
+                    index == self.active_session,
+                    format!("#{} {}", index + 1, session.cluster.name()),
+                )
+                .clicked()
+            {
+                switch_to = Some(index);
+                ui.close();
+            }
+        }
+        if let Some(index) = switch_to {
+            self.switch_session(index);
+        }
+
+        ui.separator();
+        let mut open = None;
+        ui.menu_button("Open Session", |ui| {
+            let has_custom_url = !self.config_manager.config().custom_rpc_url.is_empty();
+            for &cluster in Cluster::all() {
+                let enabled = cluster != Cluster::Custom || has_custom_url;
+                if ui
+                    .add_enabled(enabled, egui::Button::new(cluster.name()))
+                    .clicked()
+                {
+                    open = Some(cluster);
+                    ui.close();
+                }
+            }
+        });
+        if let Some(cluster) = open {
+            self.open_session(cluster);
+        }
+
+        if ui
+            .add_enabled(
+                self.sessions.len() > 1,
+                egui::Button::new("Close Current Session"),
+            )
+            .clicked()
+        {
+            self.close_session();
+            ui.close();
+        }
+    }
+
+    /// Test the typed custom RPC URL without touching the active client.
+    fn test_custom_rpc(&mut self) {
+        let client = SolanaClient::new(
+            self.custom_rpc_input.trim().to_string(),
+            self.log_store.clone(),
+            self.config_manager.config().rpc_commitments.clone(),
+        );
+        let connection_test_clone = Arc::clone(&self.connection_test);
+        if let Ok(mut connection_test) = self.connection_test.try_lock() {
+            *connection_test = Some(ConnectionTest::Running);
+        }
+
+        if let Some(rt) = &self.rt {
+            rt.spawn(async move {
+                let outcome = match client.test_connection().await {
+                    Ok(version) => ConnectionTest::Succeeded(version),
+                    Err(e) => ConnectionTest::Failed(e.to_string()),
+                };
+                *connection_test_clone.lock().await = Some(outcome);
+            });
+        }
+    }
+
+    /// Ping every built-in cluster, the saved custom URL and any other session
+    /// endpoint concurrently, and open the window listing the results.
+    fn ping_all_endpoints(&mut self) {
+        self.show_endpoint_pings = true;
+        let custom_url = self.config_manager.config().custom_rpc_url.clone();
+        let mut endpoints: Vec<EndpointPing> = Vec::new();
+        let configured = Cluster::all()
+            .iter()
+            .filter(|&&cluster| cluster != Cluster::Custom || !custom_url.is_empty())
+            .map(|&cluster| (cluster, cluster.endpoint(&custom_url)));
+        let sessions = self
+            .sessions
+            .iter()
+            .map(|session| (session.cluster, session.client.rpc_url()));
+        for (cluster, url) in configured.chain(sessions) {
+            if !endpoints.iter().any(|endpoint| endpoint.url == url) {
+                endpoints.push(EndpointPing {
+                    cluster,
+                    url: url.to_string(),
+                    outcome: PingOutcome::Running,
+                });
+            }
+        }
+
+        let Some(rt) = &self.rt else {
+            return;
+        };
+        self.ping_generation += 1;
+        let generation = self.ping_generation;
+        let timeout = Duration::from_secs(ENDPOINT_PING_TIMEOUT_SECS);
+        let clients: Vec<SolanaClient> = endpoints
+            .iter()
+            .map(|endpoint| {
+                SolanaClient::new(
+                    endpoint.url.clone(),
+                    self.log_store.clone(),
+                    self.config_manager.config().rpc_commitments.clone(),
+                )
+            })
+            .collect();
+        let pings_clone = Arc::clone(&self.endpoint_pings);
+        rt.spawn(async move {
+            // List the endpoints before any ping can report back
+            *pings_clone.lock().await = EndpointPings {
+                generation,
+                endpoints,
+            };
+            for (index, client) in clients.into_iter().enumerate() {
+                let pings_clone = Arc::clone(&pings_clone);
+                tokio::spawn(async move {
+                    let outcome = match client.ping(timeout).await {
+                        Ok((slot, latency)) => PingOutcome::Reached { slot, latency },
+                        Err(e) => PingOutcome::Failed(e.to_string()),
+                    };
+                    let mut pings = pings_clone.lock().await;
+                    // A newer run replaced the list while this ping was in flight
+                    if pings.generation != generation {
+                        return;
+                    }
+                    if let Some(endpoint) = pings.endpoints.get_mut(index) {
+                        endpoint.outcome = outcome;
+                    }
+                });
+            }
+        });
+    }
+
+    /// Show the latency and current slot of each pinged endpoint.
+    fn render_endpoint_pings_window(&mut self, ctx: &egui::Context) {
+        if !self.show_endpoint_pings {
+            return;
+        }
+        let pings = self
+            .endpoint_pings
+            .try_lock()
+            .map(|guard| guard.endpoints.clone())
+            .unwrap_or_default();
+        let running = pings
+            .iter()
+            .any(|ping| matches!(ping.outcome, PingOutcome::Running));
+        let fastest = pings
+            .iter()
+            .filter_map(|ping| match ping.outcome {
+                PingOutcome::Reached { latency, .. } => Some(latency),
+                _ => None,
+            })
+            .min();
+
+        let mut open = true;
+        let mut ping_again = false;
+        egui::Window::new(utils::icon_text(ctx, "📶", "Endpoint Ping"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "getHealth and getSlot against each configured endpoint, {}s timeout each",
+                    ENDPOINT_PING_TIMEOUT_SECS
+                ));
+                ui.add_space(HEADER_SPACING_TINY);
+                egui::Grid::new("endpoint_pings")
+                    .num_columns(4)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Endpoint");
+                        ui.strong("URL");
+                        ui.strong("Latency");
+                        ui.strong("Slot");
+                        ui.end_row();
+
+                        for ping in &pings {
+                            ui.label(ping.cluster.name());
+                            ui.monospace(&ping.url);
+                            match &ping.outcome {
+                                PingOutcome::Running => {
+                                    ui.spinner();
+                                    ui.label("");
+                                }
+                                PingOutcome::Reached { slot, latency } => {
+                                    let text = format!("{} ms", latency.as_millis());
+                                    if Some(*latency) == fastest {
+                                        ui.colored_label(
+                                            utils::palette_color(ui.ctx(), StatusColor::Success),
+                                            format!("★ {}", text),
+                                        )
+                                        .on_hover_text("Fastest endpoint");
+                                    } else {
+                                        ui.label(text);
+                                    }
+                                    ui.label(slot.to_string());
+                                }
+                                PingOutcome::Failed(error) => {
+                                    ui.colored_label(
+                                        utils::palette_color(ui.ctx(), StatusColor::Error),
+                                        "✖ Failed",
+                                    )
+                                    .on_hover_text(error);
+                                    ui.label("—");
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+                ui.add_space(HEADER_SPACING_TINY);
+                if ui
+                    .add_enabled(!running, egui::Button::new("Ping again"))
+                    .clicked()
+                {
+                    ping_again = true;
+                }
+            });
+        self.show_endpoint_pings = open;
+
+        if ping_again {
+            self.ping_all_endpoints();
+        }
+    }
+
+    /// Save the typed custom RPC URL and switch to it.
+    fn use_custom_rpc(&mut self) {
+        if utils::validate_rpc_url(&self.custom_rpc_input).is_err() {
+            return;
+        }
+        self.show_custom_rpc_entry = false;
+        self.config_manager
+            .update_custom_rpc_url(self.custom_rpc_input.trim());
+        self.change_cluster(Cluster::Custom);
+    }
+
+    /// Render the window opened by picking "Custom…" before any URL is saved.
+    fn render_custom_rpc_window(&mut self, ctx: &egui::Context) {
+        if !self.show_custom_rpc_entry {
+            return;
+        }
+        let mut open = true;
+        egui::Window::new(utils::icon_text(ctx, "🔌", "Custom RPC"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                self.render_custom_rpc_menu(ui);
+            });
+        // `use_custom_rpc` closes the window as well
+        self.show_custom_rpc_entry &= open;
+    }
+
+    /// Render the custom RPC URL entry with its connection test.
+    fn render_custom_rpc_menu(&mut self, ui: &mut egui::Ui) {
+        ui.label("Custom RPC URL:");
+        let response = ui.add_sized(
+            [SEARCH_FIELD_WIDTH, SEARCH_FIELD_HEIGHT],
+            egui::TextEdit::singleline(&mut self.custom_rpc_input)
+                .hint_text("https://my-rpc.example.com"),
+        );
+        if response.changed()
+            && let Ok(mut connection_test) = self.connection_test.try_lock()
+        {
+            // A result for a different URL would be misleading
+            *connection_test = None;
+        }
+
+        let validation = utils::validate_rpc_url(&self.custom_rpc_input);
+        if let Err(error) = &validation
+            && !self.custom_rpc_input.trim().is_empty()
+        {
+            ui.colored_label(WARNING_COLOR, format!("⚠ {}", error));
+        }
+        let has_url = validation.is_ok();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(has_url, egui::Button::new("Test connection"))
+                .on_hover_text("Run getHealth and getVersion without switching endpoints")
+                .clicked()
+            {
+                self.test_custom_rpc();
+            }
+            if ui
+                .add_enabled(has_url, egui::Button::new("Use endpoint"))
+                .on_hover_text("Switch to this endpoint as the Custom cluster")
+                .clicked()
+            {
+                self.use_custom_rpc();
+                ui.close();
+            }
+        });
+
+        let connection_test = self
+            .connection_test
+            .try_lock()
+            .ok()
+            .and_then(|guard| guard.clone());
+        match connection_test {
+            Some(ConnectionTest::Running) => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Testing connection...");
+                });
+            }
+            Some(ConnectionTest::Succeeded(version)) => {
+                ui.colored_label(
+                    utils::palette_color(ui.ctx(), StatusColor::Success),
+                    format!("✔ Healthy, version {}", version),
+                );
+            }
+            Some(ConnectionTest::Failed(error)) => {
+                ui.colored_label(
+                    utils::palette_color(ui.ctx(), StatusColor::Error),
+                    format!("✖ {}", error),
+                );
+            }
+            None => {}
+        }
+
+        ui.separator();
+        if ui
+            .button(utils::icon_text(ui.ctx(), "📶", "Ping all endpoints"))
+            .on_hover_text("Compare latency and current slot across every configured endpoint")
+            .clicked()
+        {
+            self.ping_all_endpoints();
+            ui.close();
+        }
+    }
+
+    /// Record a submitted search term in the field's recent history.
+    fn remember_search(&mut self, field: SearchField, term: &str) {
+        self.config_manager.add_recent_search(field, term);
+        self.config_manager.auto_save();
+    }
+
+    fn save_current_state(&mut self) {
+        self.config_manager
+            .update_leader_schedule(&self.leader_identity_search, &self.leader_epoch_search);
+        self.config_manager.update_search_filters(
+            &self.identity_search,
+            &self.vote_account_search,
+            &self.slot_search,
+            &self.voter_account_search,
+            &self.gossip_identity_search,
+        );
         self.config_manager
             .update_selected_tab(self.current_tab.id());
         self.config_manager.auto_save();
@@ -417,167 +1948,731 @@ impl ValidatorApp {
         ui.horizontal(|ui| {
             ui.add_space(16.0); // Left padding
                                 // Left side: Slot and epoch information
-            let slot_info = if let Ok(guard) = self.slot_info.try_lock() {
+            let slot_info = if let Ok(guard) = self.session().slot_info.try_lock() {
                 *guard
             } else {
                 (None, None, None)
             };
 
             if let (Some(current_slot), Some(latest_slot), Some(current_epoch)) = slot_info {
-                ui.label(format!(
-                    "🔗 Epoch: {} | Current Slot: {} | Latest Slot: {}",
-                    current_epoch, current_slot, latest_slot
-                ));
+                let response = ui
+                    .add(
+                        egui::Label::new(format!(
+                            "🔗 Epoch: {} | Current Slot: {} | Latest Slot: {}",
+                            current_epoch, current_slot, latest_slot
+                        ))
+                        .sense(egui::Sense::click()),
+                    )
+                    .on_hover_text("Click to copy epoch, slots and cluster for an incident report");
+                if response.clicked() {
+                    ui.ctx().copy_text(utils::slot_info_snapshot(
+                        current_epoch,
+                        current_slot,
+                        latest_slot,
+                        self.session().cluster,
+                        self.session().client.rpc_url(),
+                    ));
+                }
             } else {
                 ui.label("🔗 Network info: Loading...");
             }
 
-            // Spacer to push right content to the right
-            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                ui.add_space(16.0); // Right padding
-                                    // Right side: Status and loading indicator
-                ui.colored_label(
-                    if self.status_manager.is_loading() {
-                        egui::Color32::from_rgb(204, 102, 0) // Dark orange for loading
-                    } else if self.status_manager.refresh_status == "Ready" {
-                        egui::Color32::from_rgb(0, 128, 0) // Dark green for ready
+            let rpc_version = self
+                .session()
+                .rpc_version
+                .try_lock()
+                .ok()
+                .and_then(|guard| guard.clone());
+            if let Some(version) = rpc_version {
+                ui.separator();
+                ui.label(format!("RPC v{}", version))
+                    .on_hover_text("solana-core version reported by getVersion");
+            }
+
+            // Spacer to push right content to the right
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                ui.add_space(16.0); // Right padding
+                                    // Right side: Status and loading indicator
+                ui.colored_label(
+                    if self.status_manager.is_loading() {
+                        egui::Color32::from_rgb(204, 102, 0) // Dark orange for loading
+                    } else if self.status_manager.is_ready() {
+                        // Dark green (blue with the colorblind palette) for ready
+                        utils::accent_color(
+                            ui.ctx(),
+                            utils::palette_color(ui.ctx(), StatusColor::Success),
+                        )
+                    } else {
+                        // Dark blue for status updates
+                        utils::accent_color(
+                            ui.ctx(),
+                            utils::palette_color(ui.ctx(), StatusColor::Request),
+                        )
+                    },
+                    format!("⚡ {}", self.status_manager.status_text()),
+                );
+
+                if self.status_manager.is_loading() {
+                    ui.add_space(8.0);
+                    ui.spinner();
+                }
+            });
+        });
+    }
+
+    fn handle_tab_refresh(&mut self) {
+        match self.current_tab {
+            AppTab::Validators => self.refresh_validators(),
+            AppTab::GossipNodes => self.refresh_gossip_nodes(),
+            AppTab::FindVoters => {
+                let max_range = self.config_manager.config().max_find_voters_range;
+                if let Some((start_slot, end_slot)) = utils::parse_slot_range(&self.slot_search)
+                    && utils::slot_range_len(start_slot, end_slot)
+                        .is_some_and(|len| len <= max_range)
+                {
+                    self.search_voters_in_slots(start_slot, end_slot);
+                }
+            }
+            AppTab::LeaderSchedule => {
+                if !self.leader_identity_search.is_empty() {
+                    let identity = self.leader_identity_search.clone();
+                    let epoch = if self.leader_epoch_search.is_empty() {
+                        None
+                    } else {
+                        self.leader_epoch_search.parse::<u64>().ok()
+                    };
+                    self.fetch_leader_schedule(&identity, epoch);
+                }
+            }
+            AppTab::Network => {
+                self.refresh_validators();
+                self.refresh_network_overview();
+            }
+            AppTab::Logs => {
+                // No refresh action needed for logs tab
+            }
+            AppTab::Update => {
+                // No refresh action needed for update tab
+            }
+        }
+        self.refresh_slot_info();
+    }
+
+    fn trigger_update_check(&mut self) {
+        // Switch to update tab and log the action
+        self.current_tab = AppTab::Update;
+
+        // Log the update check trigger
+        crate::tabs::logs::log_update(
+            &self.log_store,
+            "check_updates_triggered",
+            "Update check triggered via keyboard shortcut (Cmd+Shift+U)",
+            "Triggered"
+        );
+
+        // Save config due to tab change
+        self.config_manager.update_selected_tab(self.current_tab.id());
+        self.config_manager.auto_save();
+    }
+
+    fn handle_keyboard_shortcuts(&mut self, ctx: &egui::Context) {
+        // Plain-key shortcuts must not fire while typing into a text field
+        let typing = ctx.wants_keyboard_input();
+        let triggered: Vec<ShortcutAction> = ctx.input(|i| {
+            SHORTCUTS
+                .iter()
+                .filter(|shortcut| (shortcut.command || !typing) && shortcut.matches(i))
+                .map(|shortcut| shortcut.action)
+                .collect()
+        });
+
+        let previous_tab = self.current_tab;
+        if self.current_tab != AppTab::Logs {
+            self.previous_tab = self.current_tab;
+        }
+        for action in triggered {
+            match action {
+                ShortcutAction::Quit => ctx.send_viewport_cmd(egui::ViewportCommand::Close),
+                ShortcutAction::Refresh => self.handle_tab_refresh(),
+                ShortcutAction::FocusSearch => self.should_focus_search = true,
+                ShortcutAction::CheckForUpdates => self.trigger_update_check(),
+                ShortcutAction::SwitchTab(tab) => self.current_tab = tab,
+                ShortcutAction::ToggleLogs => {
+                    self.current_tab = if self.current_tab == AppTab::Logs {
+                        self.previous_tab
+                    } else {
+                        AppTab::Logs
+                    };
+                }
+                ShortcutAction::ToggleShortcutHelp => {
+                    self.show_shortcuts = !self.show_shortcuts;
+                }
+                ShortcutAction::ToggleSettings => {
+                    self.show_settings = !self.show_settings;
+                }
+                ShortcutAction::RowCursor(command) => {
+                    if self.current_tab == AppTab::Validators {
+                        self.row_cursor.pending = Some(command);
+                    }
+                }
+            }
+        }
+
+        // Save config if tab changed via keyboard shortcut
+        if previous_tab != self.current_tab {
+            self.config_manager
+                .update_selected_tab(self.current_tab.id());
+            self.config_manager.auto_save();
+        }
+    }
+
+    fn save_config_changes(&mut self) {
+        self.config_manager.update_search_filters(
+            &self.identity_search,
+            &self.vote_account_search,
+            &self.slot_search,
+            &self.voter_account_search,
+            &self.gossip_identity_search,
+        );
+        self.config_manager
+            .update_leader_schedule(&self.leader_identity_search, &self.leader_epoch_search);
+        self.config_manager.auto_save();
+    }
+
+    /// Import watchlist identities from the file chosen in the validators tab.
+    fn import_watchlist(&mut self) {
+        self.watchlist_import.requested = false;
+        let path = self.watchlist_import.path.trim().to_string();
+
+        let status = match std::fs::read_to_string(&path) {
+            Ok(contents) => {
+                let parsed = utils::parse_watchlist(&contents);
+                let added = self.config_manager.add_to_watchlist(&parsed.accepted);
+                self.config_manager.auto_save();
+                let status = format!(
+                    "Accepted {} identities ({} new), rejected {}",
+                    parsed.accepted.len(),
+                    added,
+                    parsed.rejected
+                );
+                logs::log_response(
+                    &self.log_store,
+                    "watchlist_import",
+                    &path,
+                    &status,
+                    "Imported",
+                    None,
+                );
+                status
+            }
+            Err(e) => {
+                let status = format!("Failed to read {}: {}", path, e);
+                logs::log_error(&self.log_store, "watchlist_import", &path, &status);
+                status
+            }
+        };
+        self.watchlist_import.status = Some(status);
+    }
+
+    /// Request window attention when new watchlist commission alerts have arrived.
+    fn notify_commission_alerts(&mut self, ctx: &egui::Context) {
+        let Ok(alerts) = self.session().commission_alerts.try_lock() else {
+            return;
+        };
+        let alert_count = alerts.len();
+        drop(alerts);
+
+        if alert_count > self.session().notified_alert_count
+            && self.config_manager.config().commission_alert_notify
+        {
+            ctx.send_viewport_cmd(egui::ViewportCommand::RequestUserAttention(
+                egui::UserAttentionType::Informational,
+            ));
+        }
+        self.session_mut().notified_alert_count = alert_count;
+    }
+
+    /// When the active session's validators were last fetched successfully.
+    fn validators_refreshed_at(&self) -> Option<chrono::DateTime<chrono::Local>> {
+        self.session()
+            .status_info
+            .try_lock()
+            .ok()
+            .and_then(|status| status.last_refresh)
+    }
+
+    /// The active session's validators, or the last copy read while a fetch is
+    /// writing them. The list is only copied again once its generation moves on.
+    fn latest_validators(&mut self) -> Arc<Vec<ValidatorInfo>> {
+        let validators = Arc::clone(&self.session().validators);
+        let generation = Arc::clone(&self.session().validators_generation);
+        // Fetches bump the generation while holding the validators lock, so it
+        // is free and matches the list whenever that lock is
+        if let Ok(guard) = validators.try_lock()
+            && let Ok(generation) = generation.try_lock()
+            && self.shown_validators_generation != Some(*generation)
+        {
+            self.shown_validators = Arc::new(guard.clone());
+            self.shown_validators_generation = Some(*generation);
+        }
+        Arc::clone(&self.shown_validators)
+    }
+
+    /// The active session's gossip nodes, or the last copy read while a fetch
+    /// is writing them.
+    fn latest_gossip_nodes(&mut self) -> Arc<Vec<GossipNodeInfo>> {
+        let gossip_nodes = Arc::clone(&self.session().gossip_nodes);
+        if let Ok(guard) = gossip_nodes.try_lock() {
+            self.shown_gossip_nodes = Arc::new(guard.clone());
+        }
+        Arc::clone(&self.shown_gossip_nodes)
+    }
+
+    /// Freeze or release the validators and gossip lists shown in their tabs.
+    /// Fetches keep landing in the session while held and show up on release.
+    fn set_hold(&mut self, hold: bool) {
+        if !hold {
+            self.held = None;
+            return;
+        }
+        let (Ok(validators), Ok(validators_generation), Ok(gossip_nodes)) = (
+            self.session().validators.try_lock(),
+            self.session().validators_generation.try_lock(),
+            self.session().gossip_nodes.try_lock(),
+        ) else {
+            // A fetch is writing right now; the toggle stays off and can be retried
+            return;
+        };
+        let held = HeldData {
+            validators: validators.clone(),
+            validators_generation: *validators_generation,
+            gossip_nodes: gossip_nodes.clone(),
+            validators_refreshed_at: self.validators_refreshed_at(),
+            gossip_refreshed_at: self
+                .session()
+                .gossip_refreshed_at
+                .try_lock()
+                .ok()
+                .and_then(|guard| *guard),
+        };
+        drop((validators, validators_generation, gossip_nodes));
+        self.held = Some(held);
+    }
+
+    /// Filtered result count for a tab's label badge, once it has data to filter.
+    fn tab_count(&self, tab: AppTab) -> Option<usize> {
+        let case_sensitive = self.config_manager.config().case_sensitive_pubkey_search;
+        match tab {
+            AppTab::Validators => {
+                let guard;
+                let shown: &[ValidatorInfo] = match &self.held {
+                    Some(held) => &held.validators,
+                    None => match self.session().validators.try_lock() {
+                        Ok(locked) => {
+                            guard = locked;
+                            guard.as_slice()
+                        }
+                        Err(_) => &self.shown_validators,
+                    },
+                };
+                (!shown.is_empty()).then(|| {
+                    validators::filtered_validator_count(
+                        shown,
+                        &self.validators_search,
+                        case_sensitive,
+                        self.validators_outdated_only,
+                        self.config_manager
+                            .config()
+                            .min_validator_version
+                            .as_deref(),
+                        self.validators_commission_tier,
+                    )
+                })
+            }
+            AppTab::GossipNodes => {
+                let guard;
+                let shown: &[GossipNodeInfo] = match &self.held {
+                    Some(held) => &held.gossip_nodes,
+                    None => match self.session().gossip_nodes.try_lock() {
+                        Ok(locked) => {
+                            guard = locked;
+                            guard.as_slice()
+                        }
+                        Err(_) => &self.shown_gossip_nodes,
+                    },
+                };
+                (!shown.is_empty()).then(|| {
+                    gossip_nodes::filtered_gossip_node_count(
+                        shown,
+                        &self.gossip_nodes_search,
+                        case_sensitive,
+                    )
+                })
+            }
+            AppTab::Logs => {
+                let guard = self.log_store.lock().ok()?;
+                (!guard.is_empty()).then(|| logs::filtered_log_count(&guard, &self.logs_search))
+            }
+            _ => None,
+        }
+    }
+
+    /// Advance a pending table capture: request the screenshot once the menu has
+    /// closed, then crop it to the active tab's table and copy or save it.
+    fn tick_table_capture(&mut self, ctx: &egui::Context) {
+        match self.table_capture {
+            TableCapture::Idle => {}
+            TableCapture::Requested(target) => {
+                ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::default()));
+                self.table_capture = TableCapture::Waiting { target, frames: 0 };
+            }
+            TableCapture::Waiting { target, frames } => {
+                let screenshot = ctx.input(|i| {
+                    i.raw.events.iter().find_map(|event| match event {
+                        egui::Event::Screenshot { image, .. } => Some(image.clone()),
+                        _ => None,
+                    })
+                });
+                let Some(screenshot) = screenshot else {
+                    if frames >= CAPTURE_TIMEOUT_FRAMES {
+                        self.table_capture = TableCapture::Idle;
+                        self.status_manager
+                            .show_notice("Could not capture the table: no screenshot arrived");
                     } else {
-                        egui::Color32::from_rgb(0, 102, 204) // Dark blue for status updates
+                        self.table_capture = TableCapture::Waiting {
+                            target,
+                            frames: frames + 1,
+                        };
+                        ctx.request_repaint();
+                    }
+                    return;
+                };
+                self.table_capture = TableCapture::Idle;
+
+                let image = screenshot.region(&self.table_region, Some(ctx.pixels_per_point()));
+                match target {
+                    CaptureTarget::Clipboard => {
+                        ctx.copy_image(image);
+                        self.status_manager.show_notice("Copied table image");
+                    }
+                    CaptureTarget::Png => match save_table_png(&image, self.current_tab) {
+                        Ok(path) => self
+                            .status_manager
+                            .show_notice(format!("Saved {}", path.display())),
+                        Err(e) => self
+                            .status_manager
+                            .show_notice(format!("Could not save image: {}", e)),
                     },
-                    format!("⚡ {}", self.status_manager.refresh_status),
-                );
-
-                if self.status_manager.is_loading() {
-                    ui.add_space(8.0);
-                    ui.spinner();
                 }
+            }
+        }
+    }
+
+    /// Whether the opt-in busy overlay is up for a heavy fetch still in flight.
+    fn busy_overlay_active(&self) -> bool {
+        self.config_manager.config().busy_overlay
+            && self
+                .busy_task
+                .as_ref()
+                .is_some_and(|task| !task.handle.is_finished())
+    }
+
+    /// Dim the window behind a spinner and Cancel button while a heavy fetch runs.
+    fn render_busy_overlay(&mut self, ctx: &egui::Context) {
+        if !self.busy_overlay_active() {
+            self.busy_task = None;
+            return;
+        }
+        let Some(task) = &self.busy_task else {
+            return;
+        };
+
+        let mut cancelled = false;
+        egui::Modal::new(egui::Id::new("busy_overlay")).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(&task.label);
             });
+            ui.add_space(HEADER_SPACING_TINY);
+            cancelled = ui.button("Cancel").clicked();
         });
-    }
+        // The modal only repaints on input; keep polling for the task to finish, at the
+        // background cadence while the window is unfocused
+        if self.window_active {
+            ctx.request_repaint_after(std::time::Duration::from_millis(BUSY_OVERLAY_POLL_MS));
+        }
 
-    fn handle_tab_refresh(&mut self) {
-        match self.current_tab {
-            AppTab::Validators => self.refresh_validators(),
-            AppTab::GossipNodes => self.refresh_gossip_nodes(),
-            AppTab::FindVoters => {
-                if let Ok(slot) = self.slot_search.parse::<u64>() {
-                    self.search_voters_in_slot(slot);
-                }
-            }
-            AppTab::LeaderSchedule => {
-                if !self.leader_identity_search.is_empty() {
-                    let identity = self.leader_identity_search.clone();
-                    let epoch = if self.leader_epoch_search.is_empty() {
-                        None
-                    } else {
-                        self.leader_epoch_search.parse::<u64>().ok()
-                    };
-                    self.fetch_leader_schedule(&identity, epoch);
-                }
+        if cancelled && let Some(task) = self.busy_task.take() {
+            task.handle.abort();
+            self.status_manager.cancel_fetch(task.operation);
+            // A report that raced the abort must not end the next fetch of this kind
+            if let Ok(mut completed) = self.completed_fetches.try_lock() {
+                completed.remove(&task.operation);
             }
-            AppTab::Logs => {
-                // No refresh action needed for logs tab
-            }
-            AppTab::Update => {
-                // No refresh action needed for update tab
+            // Only the cancelled operation's own state goes back to idle
+            if task.operation == FetchOperation::LeaderSchedule
+                && let Ok(mut state) = self.session().leader_schedule_state.try_lock()
+                && *state == LeaderScheduleState::Loading
+            {
+                *state = LeaderScheduleState::Idle;
             }
         }
-        self.refresh_slot_info();
     }
 
-    fn trigger_update_check(&mut self) {
-        // Switch to update tab and log the action
-        self.current_tab = AppTab::Update;
+    /// Stop tracking fetches whose tasks reported back, and flash the taskbar
+    /// when one finishes while the window is in the background.
+    fn drain_completed_fetches(&mut self, ctx: &egui::Context) {
+        let Ok(mut completed) = self.completed_fetches.try_lock() else {
+            return;
+        };
+        let mut finished_any = false;
+        for operation in std::mem::take(&mut *completed) {
+            finished_any |= self.status_manager.is_fetching(operation);
+            self.status_manager.finish_fetch(operation);
+        }
+        drop(completed);
 
-        // Log the update check trigger
-        crate::tabs::logs::log_update(
-            &self.log_store,
-            "check_updates_triggered",
-            "Update check triggered via keyboard shortcut (Cmd+Shift+U)",
-            "Triggered"
-        );
+        let focused = ctx.input(|i| i.viewport().focused).unwrap_or(true);
+        if finished_any && !focused && self.config_manager.config().fetch_complete_notify {
+            ctx.send_viewport_cmd(egui::ViewportCommand::RequestUserAttention(
+                egui::UserAttentionType::Informational,
+            ));
+        }
+    }
 
-        // Save config due to tab change
-        self.config_manager.update_selected_tab(self.current_tab.id());
-        self.config_manager.auto_save();
+    pub fn clear_logs(&mut self) {
+        if let Ok(mut logs) = self.log_store.lock() {
+            logs.clear();
+        }
     }
 
-    fn handle_keyboard_shortcuts(&mut self, ctx: &egui::Context) {
-        // Quit shortcut (Cmd+Q)
-        if ctx.input(|i| i.key_pressed(egui::Key::Q) && (i.modifiers.mac_cmd || i.modifiers.ctrl)) {
-            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+    /// Clear right away, or ask first when `confirm_clear` is set.
+    fn request_clear(&mut self, target: ClearTarget) {
+        if self.config_manager.config().confirm_clear {
+            self.pending_clear = Some(target);
+        } else {
+            self.clear(target);
         }
+    }
 
-        if ctx.input(|i| i.key_pressed(egui::Key::R) && (i.modifiers.mac_cmd || i.modifiers.ctrl)) {
-            self.handle_tab_refresh();
+    fn clear(&mut self, target: ClearTarget) {
+        match target {
+            ClearTarget::Logs => self.clear_logs(),
+            ClearTarget::FindVoters => {
+                self.slot_search.clear();
+                self.voter_account_search.clear();
+                if let Ok(mut result) = self.session().slot_voter_result.try_lock() {
+                    *result = None;
+                }
+                if let Ok(mut rolling_voters) = self.rolling_voters.try_lock() {
+                    rolling_voters.clear();
+                }
+                self.save_config_changes();
+            }
+            ClearTarget::LeaderSchedule => {
+                self.leader_identity_search.clear();
+                self.leader_epoch_search.clear();
+                if let Ok(mut result) = self.session().leader_schedule_result.try_lock() {
+                    *result = None;
+                }
+                if let Ok(mut state) = self.session().leader_schedule_state.try_lock() {
+                    *state = LeaderScheduleState::Idle;
+                }
+                if let Ok(mut fetch_errors) = self.session().fetch_errors.try_lock() {
+                    fetch_errors.remove(&FetchOperation::LeaderSchedule);
+                }
+                self.error_messages.remove(&FetchOperation::LeaderSchedule);
+                self.save_config_changes();
+            }
         }
+    }
 
-        // Search focus shortcut (Cmd+F)
-        if ctx.input(|i| i.key_pressed(egui::Key::F) && (i.modifiers.mac_cmd || i.modifiers.ctrl)) {
-            self.should_focus_search = true;
+    /// Show the settings window, pushing changed commitments to every open session.
+    fn render_settings_window(&mut self, ctx: &egui::Context) {
+        if !self.show_settings {
+            return;
         }
+        let mut open = true;
+        let mut commitments_changed = false;
+        let mut download_dir_changed = false;
+        egui::Window::new(utils::icon_text(ctx, "⚙", "Settings"))
+            .open(&mut open)
+            .collapsible(false)
+            .default_width(SETTINGS_WINDOW_WIDTH)
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    egui::CollapsingHeader::new("Connection")
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            self.render_custom_rpc_menu(ui);
+                            ui.add_space(HEADER_SPACING_TINY);
+                            ui.label("Commitment per operation:");
+                            commitments_changed =
+                                settings::render_commitment_settings(ui, &mut self.config_manager);
+                        });
+                    egui::CollapsingHeader::new("Display")
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            settings::render_display_settings(ui, &mut self.config_manager);
+                        });
+                    egui::CollapsingHeader::new("Data and refresh")
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            settings::render_data_settings(ui, &mut self.config_manager);
+                        });
+                    egui::CollapsingHeader::new("Status endpoint").show(ui, |ui| {
+                        settings::render_status_server_settings(ui, &mut self.config_manager);
+                    });
+                    egui::CollapsingHeader::new("Metrics log").show(ui, |ui| {
+                        settings::render_metrics_log_settings(ui, &mut self.config_manager);
+                    });
+                    egui::CollapsingHeader::new("Updates").show(ui, |ui| {
+                        download_dir_changed =
+                            settings::render_update_settings(ui, &mut self.config_manager);
+                    });
+                });
+            });
+        self.show_settings = open;
 
-        // Update check shortcut (Cmd+Shift+U)
-        if ctx.input(|i| i.key_pressed(egui::Key::U) && (i.modifiers.mac_cmd || i.modifiers.ctrl) && i.modifiers.shift) {
-            self.trigger_update_check();
+        if download_dir_changed {
+            self.update_tab
+                .set_download_dir(self.config_manager.config().download_dir.clone());
         }
 
-        // Tab switching shortcuts (Cmd+1, Cmd+2, etc.)
-        let previous_tab = self.current_tab;
-        ctx.input(|i| {
-            if i.modifiers.mac_cmd || i.modifiers.ctrl {
-                if i.key_pressed(egui::Key::Num1) {
-                    self.current_tab = AppTab::Validators;
-                } else if i.key_pressed(egui::Key::Num2) {
-                    self.current_tab = AppTab::GossipNodes;
-                } else if i.key_pressed(egui::Key::Num3) {
-                    self.current_tab = AppTab::FindVoters;
-                } else if i.key_pressed(egui::Key::Num4) {
-                    self.current_tab = AppTab::LeaderSchedule;
-                } else if i.key_pressed(egui::Key::Num5) {
-                    self.current_tab = AppTab::Logs;
-                } else if i.key_pressed(egui::Key::Num6) {
-                    self.current_tab = AppTab::Update;
-                }
+        if commitments_changed {
+            let commitments = self.config_manager.config().rpc_commitments.clone();
+            for session in &mut self.sessions {
+                session.client.set_commitments(commitments.clone());
             }
-        });
-
-        // Save config if tab changed via keyboard shortcut
-        if previous_tab != self.current_tab {
-            self.config_manager
-                .update_selected_tab(self.current_tab.id());
-            self.config_manager.auto_save();
         }
     }
 
-    fn save_config_changes(&mut self) {
-        self.config_manager.update_search_filters(
-            &self.identity_search,
-            &self.vote_account_search,
-            &self.slot_search,
-            &self.voter_account_search,
-            &self.gossip_identity_search,
-        );
-        self.config_manager
-            .update_leader_schedule(&self.leader_identity_search, &self.leader_epoch_search);
-        self.config_manager.auto_save();
+    /// Show the alias dialog; saving writes the alias file and updates every table.
+    fn render_alias_editor(&mut self, ctx: &egui::Context) {
+        let Some(editor) = &mut self.alias_editor else {
+            return;
+        };
+        let mut save = false;
+        let mut cancelled = false;
+        egui::Window::new("Set Alias")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.monospace(editor.pubkey.to_string());
+                ui.add_space(HEADER_SPACING_TINY);
+                let response = ui.add_sized(
+                    [SEARCH_FIELD_WIDTH, SEARCH_FIELD_HEIGHT],
+                    egui::TextEdit::singleline(&mut editor.name)
+                        .hint_text("Friendly name (empty removes the alias)"),
+                );
+                if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    save = true;
+                }
+                if let Some(error) = &editor.error {
+                    ui.colored_label(utils::palette_color(ui.ctx(), StatusColor::Error), error);
+                }
+                ui.add_space(HEADER_SPACING_TINY);
+                ui.horizontal(|ui| {
+                    save |= ui.button("Save").clicked();
+                    cancelled = ui.button("Cancel").clicked();
+                });
+            });
+
+        if save {
+            match self.aliases.set(&editor.pubkey.to_string(), &editor.name) {
+                Ok(()) => self.alias_editor = None,
+                Err(e) => editor.error = Some(e.to_string()),
+            }
+        } else if cancelled {
+            self.alias_editor = None;
+        }
     }
 
-    pub fn clear_logs(&mut self) {
-        if let Ok(mut logs) = self.log_store.lock() {
-            logs.clear();
+    /// Show the Confirm/Cancel dialog for a pending clear.
+    fn render_clear_confirmation(&mut self, ctx: &egui::Context) {
+        let Some(target) = self.pending_clear else {
+            return;
+        };
+        let mut confirmed = false;
+        let mut cancelled = false;
+        egui::Window::new("Confirm Clear")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "Clear {}? This cannot be undone.",
+                    target.description()
+                ));
+                ui.add_space(HEADER_SPACING_TINY);
+                ui.horizontal(|ui| {
+                    confirmed = ui.button("Confirm").clicked();
+                    cancelled = ui.button("Cancel").clicked();
+                });
+            });
+        if confirmed {
+            self.pending_clear = None;
+            self.clear(target);
+        } else if cancelled {
+            self.pending_clear = None;
         }
     }
 }
 
 impl eframe::App for ValidatorApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Apply display settings before anything is rendered
+        self.display_settings().store(ctx);
+        self.apply_accent_color(ctx);
+
         // Handle keyboard shortcuts
         self.handle_keyboard_shortcuts(ctx);
 
-        // Update refresh status based on elapsed time
+        // Update refresh status from finished tasks, then elapsed time
+        self.drain_completed_fetches(ctx);
         self.status_manager.update();
 
+        // Each tab shows the latest failure of its own fetch
+        let fetch_errors = self
+            .session()
+            .fetch_errors
+            .try_lock()
+            .ok()
+            .map(|guard| guard.clone());
+        if let Some(fetch_errors) = fetch_errors {
+            self.error_messages = fetch_errors;
+        }
+
+        // Surface new commission alerts outside the window
+        self.notify_commission_alerts(ctx);
+
+        // Name the active cluster in the title so instances are distinguishable
+        self.update_window_title(ctx);
+
+        // Wait for the RPC node to be reachable before the first fetch
+        self.tick_connectivity();
+        self.tick_validators_first_show();
+
+        // Slow down polling and repaints while nobody is looking
+        self.window_active = utils::window_active(ctx);
+
+        // Keep the rolling voters window up to date
+        self.tick_rolling_voters();
+
+        // Keep slot info fresh independently of the heavier list refreshes
+        self.tick_slot_poll();
+
+        // Deliver a requested table image once the screenshot arrives
+        self.tick_table_capture(ctx);
+
+        // Periodically re-check the RPC node version
+        if self.last_rpc_version_check.is_none_or(|last| {
+            last.elapsed() > Duration::from_secs(ui_constants::RPC_VERSION_INTERVAL_SECS)
+        }) {
+            self.refresh_rpc_version();
+        }
+
         // Save window geometry if changed
         if let Some(viewport) = ctx.input(|i| i.viewport().inner_rect) {
             let current_size = (viewport.width(), viewport.height());
@@ -602,41 +2697,59 @@ impl eframe::App for ValidatorApp {
                 ui.horizontal(|ui| {
                     ui.add_space(16.0); // More generous left spacing
 
-                    // Tab selection on the left
+                    // Tab selection on the left, with filtered result counts
                     let previous_tab = self.current_tab;
+                    let validators_count = self.tab_count(AppTab::Validators);
+                    let gossip_nodes_count = self.tab_count(AppTab::GossipNodes);
+                    let logs_count = self.tab_count(AppTab::Logs);
                     ui.selectable_value(
                         &mut self.current_tab,
                         AppTab::Validators,
-                        AppTab::Validators.name(),
+                        AppTab::Validators.label_with_count(ui.ctx(), validators_count),
                     )
                     .on_hover_text("Switch to Validators tab (Cmd+1)");
                     ui.add_space(8.0);
                     ui.selectable_value(
                         &mut self.current_tab,
                         AppTab::GossipNodes,
-                        AppTab::GossipNodes.name(),
+                        AppTab::GossipNodes.label_with_count(ui.ctx(), gossip_nodes_count),
                     )
                     .on_hover_text("Switch to Gossip Nodes tab (Cmd+2)");
                     ui.add_space(8.0);
                     ui.selectable_value(
                         &mut self.current_tab,
                         AppTab::FindVoters,
-                        AppTab::FindVoters.name(),
+                        AppTab::FindVoters.label(ui.ctx()),
                     )
                     .on_hover_text("Switch to Find Voters tab (Cmd+3)");
                     ui.add_space(8.0);
                     ui.selectable_value(
                         &mut self.current_tab,
                         AppTab::LeaderSchedule,
-                        AppTab::LeaderSchedule.name(),
+                        AppTab::LeaderSchedule.label(ui.ctx()),
                     )
                     .on_hover_text("Switch to Leader Schedule tab (Cmd+4)");
                     ui.add_space(8.0);
-                    ui.selectable_value(&mut self.current_tab, AppTab::Logs, AppTab::Logs.name())
-                        .on_hover_text("Switch to Logs tab (Cmd+5)");
+                    ui.selectable_value(
+                        &mut self.current_tab,
+                        AppTab::Network,
+                        AppTab::Network.label(ui.ctx()),
+                    )
+                    .on_hover_text("Switch to Network tab (Cmd+5)");
+                    ui.add_space(8.0);
+                    ui.selectable_value(
+                        &mut self.current_tab,
+                        AppTab::Logs,
+                        AppTab::Logs.label_with_count(ui.ctx(), logs_count),
+                    )
+                    .on_hover_text("Switch to Logs tab (Cmd+6, or Cmd+L to toggle)");
                     ui.add_space(8.0);
-                    ui.selectable_value(&mut self.current_tab, AppTab::Update, AppTab::Update.name())
-                        .on_hover_text("Switch to Update tab (Cmd+6 or Cmd+Shift+U)");
+                    ui.selectable_value(
+                        &mut self.current_tab,
+                        AppTab::Update,
+                        AppTab::Update.label(ui.ctx()),
+                    )
+                    .on_hover_text("Switch to Update tab (Cmd+7 or Cmd+Shift+U)");
 
                     // Save config if tab changed
                     if previous_tab != self.current_tab {
@@ -648,23 +2761,129 @@ impl eframe::App for ValidatorApp {
                     // Push controls to the right
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         // Cluster selection dropdown in top right
-                        let mut selected_cluster = self.selected_cluster;
+                        let mut selected_cluster = self.session().cluster;
                         egui::ComboBox::from_label("Cluster")
-                            .selected_text(self.selected_cluster.name())
+                            .selected_text(self.session().cluster.name())
                             .show_ui(ui, |ui| {
                                 for &cluster in Cluster::all() {
+                                    if cluster == Cluster::Custom {
+                                        let saved_url =
+                                            self.config_manager.config().custom_rpc_url.clone();
+                                        let hover_text = if saved_url.is_empty() {
+                                            "Enter your own RPC endpoint URL".to_string()
+                                        } else {
+                                            saved_url.clone()
+                                        };
+                                        if ui
+                                            .selectable_label(
+                                                selected_cluster == cluster,
+                                                "Custom…",
+                                            )
+                                            .on_hover_text(hover_text)
+                                            .clicked()
+                                        {
+                                            if saved_url.is_empty() {
+                                                self.show_custom_rpc_entry = true;
+                                            } else if selected_cluster != cluster {
+                                                selected_cluster = cluster;
+                                                self.change_cluster(cluster);
+                                            }
+                                        }
+                                        continue;
+                                    }
                                     if ui
-                                        .selectable_value(
-                                            &mut selected_cluster,
-                                            cluster,
+                                        .selectable_label(
+                                            selected_cluster == cluster,
                                             cluster.name(),
                                         )
-                                        .changed()
+                                        .clicked()
+                                        && selected_cluster != cluster
                                     {
+                                        selected_cluster = cluster;
                                         self.change_cluster(cluster);
                                     }
                                 }
                             });
+
+                        ui.add_space(8.0);
+                        if ui
+                            .button(utils::icon_text(ui.ctx(), "🔁", "Reconnect"))
+                            .on_hover_text(format!(
+                                "Rebuild the RPC client for {} and refetch the current tab",
+                                self.session().client.rpc_url()
+                            ))
+                            .clicked()
+                        {
+                            self.reconnect();
+                        }
+
+                        ui.add_space(8.0);
+                        ui.menu_button(
+                            utils::icon_text(
+                                ui.ctx(),
+                                "🗂",
+                                &format!("Sessions ({})", self.sessions.len()),
+                            ),
+                            |ui| {
+                                self.render_sessions_menu(ui);
+                            },
+                        )
+                        .response
+                        .on_hover_text("Keep several clusters open and switch between them");
+
+                        ui.add_space(8.0);
+                        ui.menu_button(utils::icon_text(ui.ctx(), "🔌", "Custom RPC"), |ui| {
+                            self.render_custom_rpc_menu(ui);
+                        })
+                        .response
+                        .on_hover_text("Enter, test, and switch to a custom RPC endpoint");
+
+                        ui.add_space(8.0);
+                        ui.menu_button(utils::icon_text(ui.ctx(), "📷", "Capture"), |ui| {
+                            if ui.button("Copy image to clipboard").clicked() {
+                                self.table_capture =
+                                    TableCapture::Requested(CaptureTarget::Clipboard);
+                                ui.close();
+                            }
+                            if ui
+                                .button("Save as PNG")
+                                .on_hover_text("Save to your Downloads folder")
+                                .clicked()
+                            {
+                                self.table_capture = TableCapture::Requested(CaptureTarget::Png);
+                                ui.close();
+                            }
+                        })
+                        .response
+                        .on_hover_text("Capture the active tab's table as an image for sharing");
+
+                        ui.add_space(8.0);
+                        let shortcuts_label = if utils::plain_labels(ui.ctx()) {
+                            "Shortcuts"
+                        } else {
+                            "⌨"
+                        };
+                        if ui
+                            .button(shortcuts_label)
+                            .on_hover_text("Show keyboard shortcuts (?)")
+                            .clicked()
+                        {
+                            self.show_shortcuts = !self.show_shortcuts;
+                        }
+
+                        ui.add_space(8.0);
+                        ui.menu_button(utils::icon_text(ui.ctx(), "👁", "View"), |ui| {
+                            settings::render_display_settings(ui, &mut self.config_manager);
+                        });
+
+                        ui.add_space(8.0);
+                        if ui
+                            .button(utils::icon_text(ui.ctx(), "⚙", "Settings"))
+                            .on_hover_text("Open all settings (Cmd+,)")
+                            .clicked()
+                        {
+                            self.show_settings = !self.show_settings;
+                        }
                     });
                 });
             });
@@ -680,30 +2899,140 @@ impl eframe::App for ValidatorApp {
         egui::CentralPanel::default()
             .frame(egui::Frame::central_panel(&ctx.style()).inner_margin(16.0))
             .show(ctx, |ui| {
+                let content_rect = ui.max_rect();
                 ui.add_space(8.0);
+                self.render_offline_banner(ui);
 
                 // Render current tab
                 match self.current_tab {
                     AppTab::Validators => {
-                        let all_validators = if let Ok(guard) = self.validators.try_lock() {
-                            guard.clone()
-                        } else {
-                            Vec::new()
+                        let all_validators = match &self.held {
+                            Some(held) => Arc::new(held.validators.clone()),
+                            None => self.latest_validators(),
+                        };
+                        let validators_generation = match &self.held {
+                            Some(held) => held.validators_generation,
+                            None => self.shown_validators_generation.unwrap_or_default(),
+                        };
+
+                        let commission_changes =
+                            if let Ok(guard) = self.session().commission_changes.try_lock() {
+                                guard.clone()
+                            } else {
+                                HashMap::new()
+                            };
+                        let commission_alerts =
+                            if let Ok(guard) = self.session().commission_alerts.try_lock() {
+                                guard.clone()
+                            } else {
+                                Vec::new()
+                            };
+                        let validator_changed_at =
+                            if let Ok(guard) = self.session().validator_changed_at.try_lock() {
+                                guard.clone()
+                            } else {
+                                HashMap::new()
+                            };
+
+                        let total_supply = self
+                            .session()
+                            .total_supply
+                            .try_lock()
+                            .ok()
+                            .and_then(|guard| *guard);
+
+                        let current_slot = self
+                            .session()
+                            .slot_info
+                            .try_lock()
+                            .ok()
+                            .and_then(|guard| guard.0);
+
+                        let capped_from = self
+                            .session()
+                            .validators_capped_from
+                            .try_lock()
+                            .ok()
+                            .and_then(|guard| *guard);
+
+                        let failed_steps = self
+                            .session()
+                            .validators_failed_steps
+                            .try_lock()
+                            .map(|guard| guard.clone())
+                            .unwrap_or_default();
+
+                        let last_updated = match &self.held {
+                            Some(held) => held.validators_refreshed_at,
+                            None => self.validators_refreshed_at(),
                         };
+                        let mut hold = self.held.is_some();
+                        let vote_balance = self.selected_validator.and_then(|vote_account| {
+                            self.session()
+                                .vote_balances
+                                .try_lock()
+                                .ok()
+                                .and_then(|guard| guard.get(&vote_account).cloned())
+                        });
 
                         let mut sort_request: Option<(SortColumn, bool)> = None;
                         let mut refresh_requested = false;
+                        let mut watchlist_toggle: Option<Pubkey> = None;
+                        let mut dismiss_alerts = false;
+                        let mut commission_alert_notify =
+                            self.config_manager.config().commission_alert_notify;
+                        let mut search_committed = false;
 
                         let should_focus = self.should_focus_search;
                         validators::render_validators_tab(
                             ui,
                             ValidatorsTabParams {
                                 validators: &all_validators,
+                                validators_generation,
                                 sort_states: &self.sort_states,
                                 search_term: &mut self.validators_search,
-                                error_message: &self.error_message,
-                                is_loading: self.status_manager.is_loading(),
+                                recent_searches: self
+                                    .config_manager
+                                    .recent_searches(SearchField::Validators),
+                                error_message: self
+                                    .error_messages
+                                    .get(&FetchOperation::Validators)
+                                    .map(String::as_str),
+                                is_loading: self
+                                    .status_manager
+                                    .is_fetching(FetchOperation::Validators),
                                 should_focus_search: should_focus,
+                                watchlist: &self.config_manager.config().watchlist,
+                                external_links: &self.config_manager.config().external_links,
+                                commission_changes: &commission_changes,
+                                changed_at: &validator_changed_at,
+                                commission_alerts: &commission_alerts,
+                                commission_alert_notify: &mut commission_alert_notify,
+                                selected_validator: &mut self.selected_validator,
+                                alias_edit: &mut self.alias_edit_request,
+                                aliases: &self.aliases,
+                                vote_balance: vote_balance.as_ref(),
+                                total_supply,
+                                current_slot,
+                                last_updated,
+                                capped_from,
+                                failed_steps: &failed_steps,
+                                min_validator_version: self
+                                    .config_manager
+                                    .config()
+                                    .min_validator_version
+                                    .as_deref(),
+                                outdated_only: &mut self.validators_outdated_only,
+                                commission_tier: &mut self.validators_commission_tier,
+                                watchlist_import: &mut self.watchlist_import,
+                                snapshots: &mut self.validator_snapshots,
+                                jump: &mut self.vote_account_jump,
+                                commission_chart_stake_weighted: &mut self
+                                    .commission_chart_stake_weighted,
+                                export: &mut self.validator_export,
+                                log_store: &self.log_store,
+                                hold: &mut hold,
+                                row_cursor: &mut self.row_cursor,
                             },
                             |column, shift| {
                                 sort_request = Some((column, shift));
@@ -711,49 +3040,149 @@ impl eframe::App for ValidatorApp {
                             || {
                                 refresh_requested = true;
                             },
+                            |identity| {
+                                watchlist_toggle = Some(*identity);
+                            },
+                            || {
+                                dismiss_alerts = true;
+                            },
+                            || {
+                                search_committed = true;
+                            },
                         );
 
+                        if search_committed {
+                            let term = self.validators_search.clone();
+                            self.remember_search(SearchField::Validators, &term);
+                        }
+
                         if let Some((column, shift)) = sort_request {
                             self.handle_column_sort(column, shift);
                         }
                         if refresh_requested {
                             self.refresh_validators();
                         }
+                        if hold != self.held.is_some() {
+                            self.set_hold(hold);
+                        }
+                        if self.watchlist_import.requested {
+                            self.import_watchlist();
+                        }
+                        if let Some(identity) = watchlist_toggle {
+                            self.config_manager.toggle_watchlist(&identity.to_string());
+                            self.config_manager.auto_save();
+                        }
+                        if dismiss_alerts {
+                            if let Ok(mut alerts) = self.session().commission_alerts.try_lock() {
+                                alerts.clear();
+                            }
+                            self.session_mut().notified_alert_count = 0;
+                        }
+                        if commission_alert_notify
+                            != self.config_manager.config().commission_alert_notify
+                        {
+                            self.config_manager
+                                .update_commission_alert_notify(commission_alert_notify);
+                            self.config_manager.auto_save();
+                        }
+                        if let Some(pubkey) = self.alias_edit_request.take() {
+                            self.alias_editor = Some(AliasEditor {
+                                pubkey,
+                                name: self
+                                    .aliases
+                                    .get(&pubkey.to_string())
+                                    .unwrap_or_default()
+                                    .to_string(),
+                                error: None,
+                            });
+                        }
+                        self.fetch_vote_balance_on_open();
                     }
                     AppTab::GossipNodes => {
-                        let all_gossip_nodes = if let Ok(guard) = self.gossip_nodes.try_lock() {
-                            guard.clone()
-                        } else {
-                            Vec::new()
+                        let all_gossip_nodes = match &self.held {
+                            Some(held) => Arc::new(held.gossip_nodes.clone()),
+                            None => self.latest_gossip_nodes(),
+                        };
+                        let last_updated = match &self.held {
+                            Some(held) => held.gossip_refreshed_at,
+                            None => self
+                                .session()
+                                .gossip_refreshed_at
+                                .try_lock()
+                                .ok()
+                                .and_then(|guard| *guard),
                         };
+                        let mut hold = self.held.is_some();
 
                         let mut refresh_requested = false;
+                        let mut search_committed = false;
 
                         let should_focus = self.should_focus_search;
                         gossip_nodes::render_gossip_nodes_tab(
                             ui,
-                            &all_gossip_nodes,
-                            &mut self.gossip_nodes_search,
-                            &self.error_message,
-                            self.status_manager.is_loading(),
-                            should_focus,
+                            GossipNodesTabParams {
+                                gossip_nodes: &all_gossip_nodes,
+                                search_term: &mut self.gossip_nodes_search,
+                                recent_searches: self
+                                    .config_manager
+                                    .recent_searches(SearchField::GossipNodes),
+                                error_message: self
+                                    .error_messages
+                                    .get(&FetchOperation::GossipNodes)
+                                    .map(String::as_str),
+                                is_loading: self
+                                    .status_manager
+                                    .is_fetching(FetchOperation::GossipNodes),
+                                should_focus_search: should_focus,
+                                last_updated,
+                                hold: &mut hold,
+                                sort: &mut self.gossip_sort,
+                                aliases: &self.aliases,
+                            },
                             || {
                                 refresh_requested = true;
                             },
+                            || {
+                                search_committed = true;
+                            },
                         );
 
                         if refresh_requested {
                             self.refresh_gossip_nodes();
                         }
+                        if hold != self.held.is_some() {
+                            self.set_hold(hold);
+                        }
+                        if search_committed {
+                            let term = self.gossip_nodes_search.clone();
+                            self.remember_search(SearchField::GossipNodes, &term);
+                        }
                     }
                     AppTab::FindVoters => {
-                        let voter_result = if let Ok(guard) = self.slot_voter_result.try_lock() {
-                            guard.clone()
-                        } else {
-                            None
+                        // The fetch number is written under the result's lock, so the
+                        // two match whenever both locks are free
+                        let (voter_result, voter_fetch) = match (
+                            self.session().slot_voter_result.try_lock(),
+                            self.session().slot_voter_fetch.try_lock(),
+                        ) {
+                            (Ok(result), Ok(fetch)) => (result.clone(), *fetch),
+                            _ => (None, 0),
                         };
 
-                        let mut search_slot: Option<u64> = None;
+                        // Borrowed for the frame; the window caches its combined result
+                        let rolling_voters = Arc::clone(&self.rolling_voters);
+                        let rolling_voters = rolling_voters.try_lock().ok();
+
+                        let latest_slot = self
+                            .session()
+                            .slot_info
+                            .try_lock()
+                            .ok()
+                            .and_then(|guard| guard.1);
+
+                        let rpc_url = self.session().client.rpc_url().to_string();
+                        let mut search_slots: Option<(u64, u64)> = None;
+                        let mut voter_detail = self.config_manager.config().find_voters_detail;
                         let mut clear_needed = false;
                         let mut save_needed = false;
 
@@ -762,14 +3191,32 @@ impl eframe::App for ValidatorApp {
                             ui,
                             FindVotersTabParams {
                                 slot_search: &mut self.slot_search,
+                                recent_slot_searches: self
+                                    .config_manager
+                                    .recent_searches(SearchField::FindVotersSlot),
+                                latest_slot,
                                 voter_result: &voter_result,
+                                voter_fetch,
                                 search_term: &mut self.find_voters_search,
-                                error_message: &self.error_message,
-                                is_loading: self.status_manager.is_loading(),
+                                error_message: self
+                                    .error_messages
+                                    .get(&FetchOperation::FindVoters)
+                                    .map(String::as_str),
+                                is_loading: self
+                                    .status_manager
+                                    .is_fetching(FetchOperation::FindVoters),
                                 should_focus_search: should_focus,
+                                table_cache: &mut self.voters_table_cache,
+                                rolling_mode: &mut self.rolling_voters_mode,
+                                rolling_voters: rolling_voters.as_deref(),
+                                max_range: self.config_manager.config().max_find_voters_range,
+                                voter_detail: &mut voter_detail,
+                                rpc_url: &rpc_url,
+                                target_vote_account: &mut self.voter_target_account,
+                                aliases: &self.aliases,
                             },
-                            |slot| {
-                                search_slot = Some(slot);
+                            |start_slot, end_slot| {
+                                search_slots = Some((start_slot, end_slot));
                             },
                             || {
                                 clear_needed = true;
@@ -779,16 +3226,15 @@ impl eframe::App for ValidatorApp {
                             },
                         );
 
-                        if let Some(slot) = search_slot {
-                            self.search_voters_in_slot(slot);
+                        if voter_detail != self.config_manager.config().find_voters_detail {
+                            self.config_manager.update_find_voters_detail(voter_detail);
+                            self.config_manager.auto_save();
+                        }
+                        if let Some((start_slot, end_slot)) = search_slots {
+                            self.search_voters_in_slots(start_slot, end_slot);
                         }
                         if clear_needed {
-                            self.slot_search.clear();
-                            self.voter_account_search.clear();
-                            if let Ok(mut result) = self.slot_voter_result.try_lock() {
-                                *result = None;
-                            }
-                            self.save_config_changes();
+                            self.request_clear(ClearTarget::FindVoters);
                         }
                         if save_needed {
                             self.save_config_changes();
@@ -796,12 +3242,20 @@ impl eframe::App for ValidatorApp {
                     }
                     AppTab::LeaderSchedule => {
                         let leader_result =
-                            if let Ok(guard) = self.leader_schedule_result.try_lock() {
+                            if let Ok(guard) = self.session().leader_schedule_result.try_lock() {
                                 guard.clone()
                             } else {
                                 None
                             };
 
+                        let leader_state =
+                            if let Ok(guard) = self.session().leader_schedule_state.try_lock() {
+                                guard.clone()
+                            } else {
+                                LeaderScheduleState::Loading
+                            };
+
+                        let rpc_url = self.session().client.rpc_url().to_string();
                         let mut fetch_request: Option<(String, Option<u64>)> = None;
                         let mut clear_needed = false;
                         let mut save_needed = false;
@@ -810,10 +3264,20 @@ impl eframe::App for ValidatorApp {
                             ui,
                             LeaderScheduleTabParams {
                                 leader_identity_search: &mut self.leader_identity_search,
+                                recent_identity_searches: self
+                                    .config_manager
+                                    .recent_searches(SearchField::LeaderIdentity),
                                 leader_epoch_search: &mut self.leader_epoch_search,
                                 leader_result: &leader_result,
-                                error_message: &self.error_message,
-                                is_loading: self.status_manager.is_loading(),
+                                state: &leader_state,
+                                error_message: self
+                                    .error_messages
+                                    .get(&FetchOperation::LeaderSchedule)
+                                    .map(String::as_str),
+                                is_loading: self
+                                    .status_manager
+                                    .is_fetching(FetchOperation::LeaderSchedule),
+                                rpc_url: &rpc_url,
                             },
                             |identity, epoch| {
                                 fetch_request = Some((identity.to_string(), epoch));
@@ -830,39 +3294,113 @@ impl eframe::App for ValidatorApp {
                             self.fetch_leader_schedule(&identity, epoch);
                         }
                         if clear_needed {
-                            self.leader_identity_search.clear();
-                            self.leader_epoch_search.clear();
-                            if let Ok(mut result) = self.leader_schedule_result.try_lock() {
-                                *result = None;
-                            }
-                            self.save_config_changes();
+                            self.request_clear(ClearTarget::LeaderSchedule);
                         }
                         if save_needed {
                             self.save_config_changes();
                         }
                     }
+                    AppTab::Network => {
+                        if self.last_network_overview_fetch.is_none() {
+                            self.refresh_network_overview();
+                        }
+
+                        let all_validators = self.latest_validators();
+                        let network_overview =
+                            if let Ok(guard) = self.session().network_overview.try_lock() {
+                                guard.clone()
+                            } else {
+                                None
+                            };
+                        let total_supply = self
+                            .session()
+                            .total_supply
+                            .try_lock()
+                            .ok()
+                            .and_then(|guard| *guard);
+
+                        let mut refresh_requested = false;
+                        network::render_network_tab(
+                            ui,
+                            NetworkTabParams {
+                                cluster: self.session().cluster,
+                                validators: &all_validators,
+                                overview: network_overview.as_ref(),
+                                total_supply,
+                                error_message: self
+                                    .error_messages
+                                    .get(&FetchOperation::Validators)
+                                    .map(String::as_str),
+                                is_loading: self
+                                    .status_manager
+                                    .is_fetching(FetchOperation::Validators),
+                                last_updated: self.validators_refreshed_at(),
+                            },
+                            || {
+                                refresh_requested = true;
+                            },
+                        );
+
+                        if refresh_requested {
+                            self.refresh_validators();
+                            self.refresh_network_overview();
+                        }
+                    }
                     AppTab::Logs => {
                         let mut clear_requested = false;
+                        let mut auto_scroll = logs::AutoScroll {
+                            enabled: self.config_manager.config().logs_auto_scroll,
+                            paused: self.logs_scroll_paused,
+                        };
                         let should_focus = self.should_focus_search;
                         logs::render_logs_tab(
                             ui,
                             &self.log_store,
                             &mut self.logs_search,
                             should_focus,
+                            &mut auto_scroll,
+                            self.config_manager.config().log_content_max_lines,
                             || {
                                 clear_requested = true;
                             },
                         );
                         if clear_requested {
-                            self.clear_logs();
+                            self.request_clear(ClearTarget::Logs);
+                        }
+                        self.logs_scroll_paused = auto_scroll.paused;
+                        if auto_scroll.enabled != self.config_manager.config().logs_auto_scroll {
+                            self.config_manager
+                                .update_logs_auto_scroll(auto_scroll.enabled);
+                            self.config_manager.auto_save();
                         }
                     }
                     AppTab::Update => {
                         self.update_tab.ui(ui, ctx);
                     }
                 }
+                // Tabs without a table are captured whole
+                self.table_region = utils::take_table_rect(ctx).unwrap_or(content_rect);
             });
 
+        // Keyboard shortcut overlay
+        shortcuts::render_shortcuts_overlay(ctx, &mut self.show_shortcuts);
+
+        // Settings window
+        self.render_settings_window(ctx);
+
+        // Endpoint ping results
+        self.render_endpoint_pings_window(ctx);
+        self.render_custom_rpc_window(ctx);
+
+        // Confirmation before clearing data
+        self.render_clear_confirmation(ctx);
+
+        // Alias editing dialog
+        self.render_alias_editor(ctx);
+
+        // Busy overlay for heavy fetches
+        self.render_busy_overlay(ctx);
+
         // Periodic save of configuration (every 30 seconds when app is active)
         if self.last_config_save.elapsed() > std::time::Duration::from_secs(30) {
             self.save_current_state();
@@ -874,10 +3412,14 @@ impl eframe::App for ValidatorApp {
             self.should_focus_search = false;
         }
 
-        // Request repaint at regular intervals for UI updates
-        ctx.request_repaint_after(std::time::Duration::from_secs(
-            ui_constants::UI_UPDATE_INTERVAL_SECS,
-        ));
+        // Request repaint at regular intervals for UI updates; focus and input
+        // events repaint right away, so the slower background cadence ends on refocus
+        let repaint_secs = if self.window_active {
+            ui_constants::UI_UPDATE_INTERVAL_SECS
+        } else {
+            BACKGROUND_REPAINT_SECS
+        };
+        ctx.request_repaint_after(std::time::Duration::from_secs(repaint_secs));
     }
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
@@ -885,3 +3427,53 @@ impl eframe::App for ValidatorApp {
         self.save_current_state();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `retry_transient` over scripted results, returning its result and the attempt count.
+    async fn run_retry(
+        log_store: &logs::LogStore,
+        results: Vec<solana::Result<u64>>,
+    ) -> (solana::Result<u64>, usize) {
+        let mut results = results.into_iter();
+        let mut attempts = 0;
+        let result = retry_transient(log_store, "get_slot", "http://rpc", Duration::ZERO, || {
+            attempts += 1;
+            std::future::ready(results.next().expect("unexpected extra attempt"))
+        })
+        .await;
+        (result, attempts)
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_policy() {
+        let log_store = logs::create_log_store();
+
+        // Success and permanent errors are returned without retrying
+        let (result, attempts) = run_retry(&log_store, vec![Ok(7)]).await;
+        assert_eq!((result.ok(), attempts), (Some(7), 1));
+        let (result, attempts) =
+            run_retry(&log_store, vec![Err(SolanaError::Parse("bad".to_string()))]).await;
+        assert!(matches!(result, Err(SolanaError::Parse(_))));
+        assert_eq!(attempts, 1);
+        assert!(log_store.lock().unwrap().is_empty());
+
+        // A transient error is logged and retried exactly once
+        let (result, attempts) =
+            run_retry(&log_store, vec![Err(SolanaError::RateLimited), Ok(8)]).await;
+        assert_eq!((result.ok(), attempts), (Some(8), 2));
+        let (result, attempts) = run_retry(
+            &log_store,
+            vec![Err(SolanaError::RateLimited), Err(SolanaError::RateLimited)],
+        )
+        .await;
+        assert!(matches!(result, Err(SolanaError::RateLimited)));
+        assert_eq!(attempts, 2);
+
+        let logs = log_store.lock().unwrap();
+        assert_eq!(logs.len(), 2);
+        assert!(logs.iter().all(|entry| entry.operation == "get_slot"));
+    }
+}
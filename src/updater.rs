@@ -1,17 +1,28 @@
 //! Auto-updater module for checking and installing updates from GitHub releases.
 
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 
 use crate::tabs::logs::{LogStore, log_update};
+use crate::utils;
 
 const GITHUB_API_BASE: &str = "https://api.github.com/repos";
 const REPO_OWNER: &str = "qwerity"; // Replace with actual username
 const REPO_NAME: &str = "solana-ui";
 const USER_AGENT: &str = concat!("solana-ui/", env!("CARGO_PKG_VERSION"));
+/// Download attempts before giving up; each retry resumes the `.part` file
+const DOWNLOAD_ATTEMPTS: u32 = 3;
+/// Delay before the first retry, doubled for each further one
+const DOWNLOAD_RETRY_BASE_SECS: u64 = 2;
+
+/// Whether a downloaded file of `len` bytes is complete; an unknown (zero) size accepts any.
+fn is_complete(len: u64, expected_size: u64) -> bool {
+    expected_size == 0 || len == expected_size
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReleaseInfo {
@@ -31,18 +42,54 @@ pub struct ReleaseAsset {
     pub size: u64,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum UpdateStatus {
     UpToDate,
     UpdateAvailable(ReleaseInfo),
     CheckFailed(String),
 }
 
+/// Last successful update check, saved so the Update tab can show it without a new request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedUpdateCheck {
+    pub checked_at: DateTime<Local>,
+    /// Version that ran the check; a result saved by another version is stale
+    pub app_version: String,
+    pub status: UpdateStatus,
+}
+
+impl CachedUpdateCheck {
+    /// Record a check made just now by the running version.
+    pub fn new(status: UpdateStatus) -> Self {
+        Self {
+            checked_at: Local::now(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            status,
+        }
+    }
+
+    /// Load the saved check from `path`, ignoring it when missing, unreadable or
+    /// made by a different version.
+    pub fn load(path: &Path) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        let cached: Self = serde_json::from_str(&contents).ok()?;
+        (cached.app_version == env!("CARGO_PKG_VERSION")).then_some(cached)
+    }
+
+    /// Write the check to `path`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 pub struct Updater {
     current_version: String,
     client: reqwest::Client,
     log_store: LogStore,
+    /// Configured download directory; the system Downloads folder when unset
+    download_dir: Option<PathBuf>,
 }
 
 impl Updater {
@@ -59,9 +106,15 @@ impl Updater {
             current_version,
             client,
             log_store,
+            download_dir: None,
         }
     }
 
+    /// Download updates to `dir` instead of the system Downloads folder.
+    pub fn set_download_dir(&mut self, dir: Option<PathBuf>) {
+        self.download_dir = dir;
+    }
+
     /// Check if a new version is available on GitHub releases
     pub async fn check_for_updates(&self) -> UpdateStatus {
         log_update(&self.log_store, "check_updates_started", "Checking for updates...", "Starting");
@@ -141,7 +194,17 @@ impl Updater {
     }
 
 
-    /// Download the DMG file for the given release to Downloads folder
+    /// Pick the download directory; see [`utils::writable_download_dir`].
+    async fn resolve_download_dir(&self) -> Result<PathBuf> {
+        let configured = self.download_dir.clone();
+        tokio::task::spawn_blocking(move || utils::writable_download_dir(configured.as_deref()))
+            .await?
+            .map_err(|e| anyhow!(e))
+    }
+
+    /// Download the DMG file for the given release to the download directory.
+    /// Data goes to a `.part` file that is renamed only once complete; an interrupted
+    /// download is resumed with an HTTP range request, retrying with backoff.
     pub async fn download_update(&self, release: &ReleaseInfo) -> Result<PathBuf> {
         log_update(&self.log_store, "download_started", &format!("Starting download of {}", release.tag_name), "Starting");
         
@@ -154,43 +217,90 @@ impl Updater {
 
         log_update(&self.log_store, "dmg_found", &format!("Found DMG: {} ({} bytes)", asset.name, asset.size), "Found");
 
-        // Get Downloads directory
-        let downloads_dir = dirs::download_dir()
-            .ok_or_else(|| anyhow!("Could not find Downloads directory"))?;
+        let downloads_dir = self.resolve_download_dir().await?;
+        log_update(&self.log_store, "download_dir", &format!("Using download directory: {}", downloads_dir.display()), "OK");
         
         let dmg_path = downloads_dir.join(&asset.name);
+        let part_path = downloads_dir.join(format!("{}.part", asset.name));
         
-        // Check if file already exists
-        if dmg_path.exists() {
-            log_update(&self.log_store, "file_exists", &format!("DMG already exists: {}", dmg_path.display()), "Exists");
-            return Ok(dmg_path);
+        // Reuse an existing file only if it is complete
+        if let Ok(metadata) = fs::metadata(&dmg_path).await {
+            if is_complete(metadata.len(), asset.size) {
+                log_update(&self.log_store, "file_exists", &format!("DMG already exists: {}", dmg_path.display()), "Exists");
+                return Ok(dmg_path);
+            }
+            log_update(&self.log_store, "file_exists", &format!("Discarding incomplete DMG ({} of {} bytes): {}", metadata.len(), asset.size, dmg_path.display()), "Incomplete");
+            fs::remove_file(&dmg_path).await?;
         }
 
-        log_update(&self.log_store, "download_start", &format!("Downloading to: {}", dmg_path.display()), "Downloading");
+        log_update(&self.log_store, "download_start", &format!("Downloading to: {}", part_path.display()), "Downloading");
 
-        // Download the file
-        let response = self
-            .client
-            .get(&asset.browser_download_url)
-            .send()
-            .await?
-            .error_for_status()?;
-
-        let mut file = fs::File::create(&dmg_path).await?;
-        
-        // Get the response bytes directly
-        let bytes = response.bytes().await?;
-        file.write_all(&bytes).await?;
-        let downloaded = bytes.len() as u64;
-        
-        log_update(&self.log_store, "download_progress", &format!("Downloaded {} bytes", downloaded), "Progress");
+        let mut attempt = 1;
+        loop {
+            match self.download_part(&asset.browser_download_url, &part_path, asset.size).await {
+                Ok(()) => break,
+                Err(e) if attempt < DOWNLOAD_ATTEMPTS => {
+                    let delay = DOWNLOAD_RETRY_BASE_SECS << (attempt - 1);
+                    log_update(&self.log_store, "download_retry", &format!("Attempt {} failed: {}; resuming in {}s", attempt, e, delay), "Retrying");
+                    tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    log_update(&self.log_store, "download_failed", &format!("Download failed after {} attempts: {}", attempt, e), "Error");
+                    return Err(e);
+                }
+            }
+        }
 
-        file.flush().await?;
+        fs::rename(&part_path, &dmg_path).await?;
         log_update(&self.log_store, "download_complete", &format!("Download complete: {}", dmg_path.display()), "Complete");
 
         Ok(dmg_path)
     }
 
+    /// Download into `part_path`, resuming after any bytes already there.
+    /// Succeeds only once the file holds `expected_size` bytes (when the size is known).
+    async fn download_part(&self, url: &str, part_path: &Path, expected_size: u64) -> Result<()> {
+        let mut existing = fs::metadata(part_path).await.map(|m| m.len()).unwrap_or(0);
+        if expected_size > 0 && existing == expected_size {
+            return Ok(());
+        }
+        if expected_size > 0 && existing > expected_size {
+            // Larger than the asset: not ours to resume
+            fs::remove_file(part_path).await?;
+            existing = 0;
+        }
+
+        let mut request = self.client.get(url);
+        if existing > 0 {
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-", existing));
+        }
+        let mut response = request.send().await?.error_for_status()?;
+
+        // 206 continues the partial file; a plain 200 means the server sent it all again
+        let resumed = existing > 0 && response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+        let mut file = if resumed {
+            log_update(&self.log_store, "download_resume", &format!("Resuming from byte {}", existing), "Resuming");
+            fs::OpenOptions::new().append(true).open(part_path).await?
+        } else {
+            fs::File::create(part_path).await?
+        };
+        let mut downloaded = if resumed { existing } else { 0 };
+
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).await?;
+            downloaded += chunk.len() as u64;
+        }
+        file.flush().await?;
+        
+        log_update(&self.log_store, "download_progress", &format!("Downloaded {} bytes", downloaded), "Progress");
+
+        if !is_complete(downloaded, expected_size) {
+            return Err(anyhow!("Download incomplete: {} of {} bytes", downloaded, expected_size));
+        }
+        Ok(())
+    }
+
     /// Get the current version
     pub fn current_version(&self) -> &str {
         &self.current_version
@@ -6,11 +6,20 @@
 //! - Status management for async operations
 //! - Formatting utilities for display
 
+use chrono::{DateTime, Local};
 use eframe::egui;
 use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
+use crate::config::AliasStore;
 use crate::constants::*;
+use crate::solana::{GossipNodeInfo, PartialFailure, ValidatorInfo};
 
 /// Direction for sorting table columns.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,14 +33,31 @@ pub enum SortDirection {
 pub enum Cluster {
     Testnet,
     Mainnet,
+    Devnet,
+    /// Local `solana-test-validator` instance
+    Localhost,
+    /// User-entered RPC endpoint
+    Custom,
 }
 
 impl Cluster {
-    /// Get the RPC URL for this cluster.
+    /// Get the built-in RPC URL for this cluster.
+    /// `Custom` has no built-in URL; use [`Cluster::endpoint`] instead.
     pub const fn url(self) -> &'static str {
         match self {
             Self::Testnet => "https://api.testnet.solana.com",
             Self::Mainnet => "https://api.mainnet-beta.solana.com",
+            Self::Devnet => "https://api.devnet.solana.com",
+            Self::Localhost => "http://127.0.0.1:8899",
+            Self::Custom => "",
+        }
+    }
+
+    /// Get the RPC URL for this cluster, using `custom_url` for `Custom`.
+    pub fn endpoint(self, custom_url: &str) -> &str {
+        match self {
+            Self::Custom => custom_url,
+            _ => self.url(),
         }
     }
 
@@ -40,12 +66,32 @@ impl Cluster {
         match self {
             Self::Testnet => "Testnet",
             Self::Mainnet => "Mainnet",
+            Self::Devnet => "Devnet",
+            Self::Localhost => "Localhost",
+            Self::Custom => "Custom",
         }
     }
 
-    /// Get all available clusters.
+    /// Get all available clusters, in dropdown order.
     pub const fn all() -> &'static [Self] {
-        &[Self::Testnet, Self::Mainnet]
+        &[
+            Self::Mainnet,
+            Self::Testnet,
+            Self::Devnet,
+            Self::Localhost,
+            Self::Custom,
+        ]
+    }
+
+    /// Look up a cluster by display name, ignoring case; `mainnet-beta` also names `Mainnet`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name.eq_ignore_ascii_case("mainnet-beta") {
+            return Some(Self::Mainnet);
+        }
+        Self::all()
+            .iter()
+            .copied()
+            .find(|cluster| cluster.name().eq_ignore_ascii_case(name))
     }
 }
 
@@ -75,25 +121,75 @@ pub enum SortColumn {
     VoteAccount,
     Commission,
     LastVote,
+    /// Slots between the current slot and the validator's last vote
+    VoteLag,
     RootSlot,
     VoteCredits,
     ActivatedStake,
     Version,
     SkipRate,
+    /// Epochs spanned by the vote account's credits history
+    EpochsActive,
+}
+
+/// Sortable columns of the gossip nodes table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GossipSortColumn {
+    Pubkey,
+    GossipAddress,
+    Version,
+    FeatureSet,
+    ShredVersion,
+}
+
+/// A user-triggered fetch whose loading state is tracked on its own, so one
+/// running fetch doesn't hold up the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FetchOperation {
+    Validators,
+    GossipNodes,
+    FindVoters,
+    LeaderSchedule,
+}
+
+impl FetchOperation {
+    /// What is being fetched, for the in-flight status message.
+    pub const fn noun(self) -> &'static str {
+        match self {
+            Self::Validators => "validators",
+            Self::GossipNodes => "gossip nodes",
+            Self::FindVoters => "voters",
+            Self::LeaderSchedule => "leader schedule",
+        }
+    }
+
+    /// Status message shown once the fetch is done.
+    pub const fn finished_message(self) -> &'static str {
+        match self {
+            Self::Validators => "Validators updated",
+            Self::GossipNodes => "Gossip nodes updated",
+            Self::FindVoters => "Voter search finished",
+            Self::LeaderSchedule => "Leader schedule updated",
+        }
+    }
 }
 
 /// Manages loading states and status messages for async operations.
 pub struct StatusManager {
-    pub refresh_status: String,
-    pub validators_loading: bool,
+    /// Last finished operation or notice, shown while nothing is in flight
+    refresh_status: String,
+    /// Fetches in flight and when each started
+    fetches: BTreeMap<FetchOperation, Instant>,
     pub slot_loading: bool,
-    pub last_validators_fetch: Option<Instant>,
     pub last_slot_fetch: Option<Instant>,
+    /// When `refresh_status` last changed, for resetting it to "Ready"
+    status_shown_at: Option<Instant>,
 }
 
 /// Timeouts for different operations in seconds.
 mod timeouts {
-    pub const VALIDATORS_TIMEOUT: u64 = 5;
+    /// Safety net for a fetch whose task never reports back
+    pub const FETCH_TIMEOUT: u64 = 600;
     pub const SLOT_TIMEOUT: u64 = 3;
     pub const STATUS_DISPLAY: u64 = 7;
 }
@@ -101,129 +197,1093 @@ mod timeouts {
 /// Status messages used throughout the application.
 mod status_messages {
     pub const READY: &str = "Ready";
-    pub const FETCHING_VALIDATORS: &str = "Fetching validators...";
     pub const UPDATING_SLOT: &str = "Updating slot info...";
-    pub const VALIDATORS_UPDATED: &str = "Validators updated";
+    pub const CANCELLED: &str = "Cancelled";
 }
 
 impl Default for StatusManager {
     fn default() -> Self {
         Self {
             refresh_status: status_messages::READY.to_string(),
-            validators_loading: false,
+            fetches: BTreeMap::new(),
             slot_loading: false,
-            last_validators_fetch: None,
             last_slot_fetch: None,
+            status_shown_at: None,
         }
     }
 }
 
 impl StatusManager {
-    /// Start tracking a validators refresh operation.
-    pub fn start_validators_refresh(&mut self) {
-        self.validators_loading = true;
-        self.refresh_status = status_messages::FETCHING_VALIDATORS.to_string();
-        self.last_validators_fetch = Some(Instant::now());
+    /// Start tracking a fetch operation.
+    pub fn start_fetch(&mut self, operation: FetchOperation) {
+        self.fetches.insert(operation, Instant::now());
+    }
+
+    /// Whether a fetch of this kind is still in flight.
+    pub fn is_fetching(&self, operation: FetchOperation) -> bool {
+        self.fetches.contains_key(&operation)
+    }
+
+    /// Stop tracking a fetch whose task reported back.
+    pub fn finish_fetch(&mut self, operation: FetchOperation) {
+        if self.fetches.remove(&operation).is_some() {
+            self.set_status(operation.finished_message());
+        }
     }
 
     /// Start tracking a slot info refresh operation.
     pub fn start_slot_refresh(&mut self) {
         self.slot_loading = true;
-        if !self.validators_loading {
-            self.refresh_status = status_messages::UPDATING_SLOT.to_string();
-        }
         self.last_slot_fetch = Some(Instant::now());
     }
 
+    /// Stop tracking a fetch the user cancelled.
+    pub fn cancel_fetch(&mut self, operation: FetchOperation) {
+        self.fetches.remove(&operation);
+        self.set_status(status_messages::CANCELLED);
+    }
+
+    /// Show a one-off message in the status bar for a few seconds.
+    pub fn show_notice(&mut self, message: impl Into<String>) {
+        self.set_status(message);
+    }
+
+    fn set_status(&mut self, message: impl Into<String>) {
+        self.refresh_status = message.into();
+        self.status_shown_at = Some(Instant::now());
+    }
+
     /// Update loading states based on elapsed time.
     /// Should be called regularly from the UI update loop.
     pub fn update(&mut self) {
         let now = Instant::now();
 
-        self.check_validators_timeout(now);
+        self.check_fetch_timeouts(now);
         self.check_slot_timeout(now);
         self.auto_reset_status(now);
     }
 
-    /// Check if validators fetch operation has timed out.
-    fn check_validators_timeout(&mut self, now: Instant) {
-        if self.validators_loading {
-            if let Some(start_time) = self.last_validators_fetch {
-                if now.duration_since(start_time)
-                    > Duration::from_secs(timeouts::VALIDATORS_TIMEOUT)
-                {
-                    self.validators_loading = false;
-                    self.refresh_status = status_messages::VALIDATORS_UPDATED.to_string();
-                }
-            }
+    /// Stop tracking fetches that have run past the safety-net timeout.
+    fn check_fetch_timeouts(&mut self, now: Instant) {
+        let timed_out: Vec<FetchOperation> = self
+            .fetches
+            .iter()
+            .filter(|&(_, &started)| {
+                now.duration_since(started) > Duration::from_secs(timeouts::FETCH_TIMEOUT)
+            })
+            .map(|(&operation, _)| operation)
+            .collect();
+        for operation in timed_out {
+            self.fetches.remove(&operation);
+            self.set_status(format!("Stopped waiting for {}", operation.noun()));
         }
     }
 
     /// Check if slot fetch operation has timed out.
     fn check_slot_timeout(&mut self, now: Instant) {
-        if self.slot_loading {
-            if let Some(start_time) = self.last_slot_fetch {
-                if now.duration_since(start_time) > Duration::from_secs(timeouts::SLOT_TIMEOUT) {
-                    self.slot_loading = false;
-                    if !self.validators_loading
-                        && self.refresh_status == status_messages::UPDATING_SLOT
-                    {
-                        self.refresh_status = status_messages::READY.to_string();
-                    }
-                }
-            }
+        if self.slot_loading
+            && let Some(start_time) = self.last_slot_fetch
+            && now.duration_since(start_time) > Duration::from_secs(timeouts::SLOT_TIMEOUT)
+        {
+            self.slot_loading = false;
         }
     }
 
     /// Auto-reset status to Ready after showing completion message for a while.
     fn auto_reset_status(&mut self, now: Instant) {
-        if !self.is_loading() && self.refresh_status == status_messages::VALIDATORS_UPDATED {
-            if let Some(start_time) = self.last_validators_fetch {
-                if now.duration_since(start_time) > Duration::from_secs(timeouts::STATUS_DISPLAY) {
-                    self.refresh_status = status_messages::READY.to_string();
-                }
-            }
+        if let Some(shown_at) = self.status_shown_at
+            && now.duration_since(shown_at) > Duration::from_secs(timeouts::STATUS_DISPLAY)
+        {
+            self.refresh_status = status_messages::READY.to_string();
+            self.status_shown_at = None;
         }
     }
 
+    /// Status bar text: the fetches in flight, else the latest message.
+    pub fn status_text(&self) -> String {
+        if !self.fetches.is_empty() {
+            let nouns: Vec<&str> = self.fetches.keys().map(|op| op.noun()).collect();
+            format!("Fetching {}...", nouns.join(", "))
+        } else if self.slot_loading && self.is_ready() {
+            status_messages::UPDATING_SLOT.to_string()
+        } else {
+            self.refresh_status.clone()
+        }
+    }
+
+    /// Whether no message is pending and the status reads "Ready".
+    pub fn is_ready(&self) -> bool {
+        self.fetches.is_empty() && self.refresh_status == status_messages::READY
+    }
+
     /// Check if any operations are currently loading.
-    pub const fn is_loading(&self) -> bool {
-        self.validators_loading || self.slot_loading
+    pub fn is_loading(&self) -> bool {
+        !self.fetches.is_empty() || self.slot_loading
     }
 }
 
 /// Formatting constants for display values.
 mod formatting {
-    pub const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+    pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+    pub const LAMPORT_DECIMALS: u32 = 9;
     pub const DECIMAL_PLACES: usize = 2;
+    pub const SMALL_DECIMAL_PLACES: u32 = 4;
+    /// Stake from which whole SOL are shown without decimals
+    pub const WHOLE_SOL_THRESHOLD: u64 = 1_000 * LAMPORTS_PER_SOL;
+    /// Stake below which every lamport is shown (0.0001 SOL)
+    pub const FULL_PRECISION_THRESHOLD: u64 = 100_000;
+}
+
+/// Decimals `format_stake` shows for a stake of this many lamports.
+fn stake_decimals(stake: u64) -> u32 {
+    if stake == 0 || stake >= formatting::WHOLE_SOL_THRESHOLD {
+        0
+    } else if stake >= formatting::LAMPORTS_PER_SOL {
+        formatting::DECIMAL_PLACES as u32
+    } else if stake >= formatting::FULL_PRECISION_THRESHOLD {
+        formatting::SMALL_DECIMAL_PLACES
+    } else {
+        formatting::LAMPORT_DECIMALS
+    }
 }
 
-/// Format stake amount from lamports to SOL with appropriate precision.
+/// Format stake amount from lamports to SOL with precision adapted to its size:
+/// whole SOL with thousands separators from 1,000 SOL, two decimals from 1 SOL,
+/// and more decimals below that so small stakes don't round to zero.
 pub fn format_stake(stake: u64) -> String {
-    let sol_amount = stake as f64 / formatting::LAMPORTS_PER_SOL;
+    // Round in integer lamports so large stakes stay exact
+    let round = |decimals: u32| {
+        let unit = 10u64.pow(formatting::LAMPORT_DECIMALS - decimals);
+        (stake / unit + u64::from(stake % unit * 2 >= unit), unit)
+    };
+    let mut decimals = stake_decimals(stake);
+    let (mut rounded, unit) = round(decimals);
+    // Rounding can carry into the next tier, e.g. 999.995 SOL to 1,000 SOL
+    let carried = stake_decimals(rounded.saturating_mul(unit));
+    if carried != decimals {
+        decimals = carried;
+        rounded = round(decimals).0;
+    }
+    let scale = 10u64.pow(decimals);
+    let whole = with_thousands_separators(rounded / scale);
+
+    if decimals == 0 {
+        format!("{} SOL", whole)
+    } else {
+        format!(
+            "{}.{:0width$} SOL",
+            whole,
+            rounded % scale,
+            width = decimals as usize
+        )
+    }
+}
+
+/// Format lamports as an exact SOL amount for exports: all nine decimals, no
+/// separators or unit, e.g. `1500.000000001`.
+pub fn format_sol_exact(lamports: u64) -> String {
     format!(
-        "{:.precision$} SOL",
-        sol_amount,
-        precision = formatting::DECIMAL_PLACES
+        "{}.{:0width$}",
+        lamports / formatting::LAMPORTS_PER_SOL,
+        lamports % formatting::LAMPORTS_PER_SOL,
+        width = formatting::LAMPORT_DECIMALS as usize
     )
 }
 
-/// Format skip rate as a percentage with appropriate precision.
-pub fn format_skip_rate(skip_rate: f64) -> String {
+/// Insert `,` between groups of three digits.
+fn with_thousands_separators(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Format skip rate as a percentage with appropriate precision, or "N/A" without data.
+pub fn format_skip_rate(skip_rate: Option<f64>) -> String {
+    match skip_rate {
+        Some(skip_rate) => format!(
+            "{:.precision$}%",
+            skip_rate,
+            precision = formatting::DECIMAL_PLACES
+        ),
+        None => "N/A".to_string(),
+    }
+}
+
+/// Percentage of the total supply that is actively staked.
+/// Returns `None` when the supply is unknown or zero.
+pub fn staked_supply_percent(total_stake: u64, total_supply: u64) -> Option<f64> {
+    if total_supply == 0 {
+        return None;
+    }
+    Some(total_stake as f64 / total_supply as f64 * 100.0)
+}
+
+/// Keep the `limit` validators with the most activated stake, dropping the rest.
+/// Returns the count before capping when anything was dropped.
+pub fn cap_validators_by_stake(validators: &mut Vec<ValidatorInfo>, limit: usize) -> Option<usize> {
+    let total = validators.len();
+    if total <= limit {
+        return None;
+    }
+    validators.sort_by_key(|validator| Reverse(validator.activated_stake));
+    validators.truncate(limit);
+    Some(total)
+}
+
+/// Commission buckets of the distribution chart as (label, lowest, highest) percent.
+pub const COMMISSION_BUCKETS: [(&str, u8, u8); 4] = [
+    ("0%", 0, 0),
+    ("1–5%", 1, 5),
+    ("6–10%", 6, 10),
+    (">10%", 11, u8::MAX),
+];
+
+/// Names of the commission buckets as offered in the validators tab's filter.
+pub const COMMISSION_TIER_NAMES: [&str; 4] = ["0%", "Low (1–5%)", "Medium (6–10%)", "High (>10%)"];
+
+/// Index into `COMMISSION_BUCKETS` of the bucket holding `commission`.
+pub fn commission_bucket(commission: u8) -> usize {
+    COMMISSION_BUCKETS
+        .iter()
+        .position(|&(_, low, high)| (low..=high).contains(&commission))
+        .unwrap_or(COMMISSION_BUCKETS.len() - 1)
+}
+
+/// Validators per commission bucket, or their summed activated stake when `stake_weighted`.
+pub fn commission_distribution(validators: &[ValidatorInfo], stake_weighted: bool) -> [u64; 4] {
+    let mut buckets = [0; 4];
+    for validator in validators {
+        buckets[commission_bucket(validator.commission)] += if stake_weighted {
+            validator.activated_stake
+        } else {
+            1
+        };
+    }
+    buckets
+}
+
+/// Parse a `major.minor.patch` version, ignoring any pre-release or build suffix.
+pub fn parse_version(version: &str) -> Option<[u32; 3]> {
+    let core = version.trim().trim_start_matches('v');
+    let core = core.split(['-', '+']).next().unwrap_or_default();
+    let mut parts = core.split('.').map(|part| part.parse::<u32>().ok());
+    let parsed = [parts.next()??, parts.next()??, parts.next()??];
+    parts.next().is_none().then_some(parsed)
+}
+
+/// Version below which validators count as outdated: the configured minimum
+/// when it parses, otherwise the most common version (the newer one on a tie).
+pub fn outdated_version_threshold(
+    validators: &[ValidatorInfo],
+    min_version: Option<&str>,
+) -> Option<[u32; 3]> {
+    if let Some(min_version) = min_version.and_then(parse_version) {
+        return Some(min_version);
+    }
+
+    let mut counts: HashMap<[u32; 3], usize> = HashMap::new();
+    for version in validators.iter().filter_map(|v| parse_version(&v.version)) {
+        *counts.entry(version).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(version, count)| (count, version))
+        .map(|(version, _)| version)
+}
+
+/// Whether a validator version is behind the threshold. Unparseable versions
+/// such as "Unknown" are never considered outdated.
+pub fn is_outdated_version(version: &str, threshold: Option<[u32; 3]>) -> bool {
+    match (parse_version(version), threshold) {
+        (Some(version), Some(threshold)) => version < threshold,
+        _ => false,
+    }
+}
+
+/// Gini coefficient of the activated stake distribution.
+/// Returns 0.0 for a perfectly even distribution, approaching 1.0 as stake
+/// concentrates in a single validator. Empty or zero-stake input yields 0.0.
+pub fn gini_coefficient(validators: &[ValidatorInfo]) -> f64 {
+    let mut stakes: Vec<u64> = validators.iter().map(|v| v.activated_stake).collect();
+    let total: u128 = stakes.iter().map(|&stake| stake as u128).sum();
+    if stakes.is_empty() || total == 0 {
+        return 0.0;
+    }
+
+    stakes.sort_unstable();
+    let count = stakes.len() as f64;
+    let weighted_sum: f64 = stakes
+        .iter()
+        .enumerate()
+        .map(|(index, &stake)| (index + 1) as f64 * stake as f64)
+        .sum();
+
+    (2.0 * weighted_sum) / (count * total as f64) - (count + 1.0) / count
+}
+
+/// Credits earned per epoch from vote account `epoch_credits` entries
+/// (`(epoch, credits, previous_credits)`), oldest first. Epochs missing from a sparse
+/// history are `None`; at most the last `CREDITS_CHART_MAX_EPOCHS` epochs are returned.
+pub fn credits_by_epoch(epoch_credits: &[(u64, u64, u64)]) -> Vec<(u64, Option<u64>)> {
+    let Some(&(last_epoch, _, _)) = epoch_credits.last() else {
+        return Vec::new();
+    };
+    let earned: HashMap<u64, u64> = epoch_credits
+        .iter()
+        .map(|(epoch, credits, previous)| (*epoch, credits.saturating_sub(*previous)))
+        .collect();
+    let first_epoch = epoch_credits[0]
+        .0
+        .max(last_epoch.saturating_sub(CREDITS_CHART_MAX_EPOCHS - 1));
+    (first_epoch..=last_epoch)
+        .map(|epoch| (epoch, earned.get(&epoch).copied()))
+        .collect()
+}
+
+/// Approximate epochs a vote account has been active: the span of its `epoch_credits`
+/// history, inclusive. Vote accounts only keep `EPOCH_CREDITS_HISTORY_LEN` epochs, so
+/// older validators top out there. `None` without any history.
+pub fn epochs_active(epoch_credits: &[(u64, u64, u64)]) -> Option<u64> {
+    let (first, _, _) = epoch_credits.first()?;
+    let (last, _, _) = epoch_credits.last()?;
+    Some(last.saturating_sub(*first) + 1)
+}
+
+/// Activated stake of the validators still voting; delinquent stake takes no
+/// part in consensus.
+pub fn active_stake(validators: &[ValidatorInfo]) -> u64 {
+    validators
+        .iter()
+        .filter(|v| !v.delinquent)
+        .map(|v| v.activated_stake)
+        .sum()
+}
+
+/// Nakamoto coefficient: the fewest voting validators whose combined stake exceeds
+/// one third of the active stake, enough to halt consensus. Zero when there is no stake.
+pub fn nakamoto_coefficient(validators: &[ValidatorInfo]) -> usize {
+    let mut stakes: Vec<u128> = validators
+        .iter()
+        .filter(|v| !v.delinquent)
+        .map(|v| v.activated_stake as u128)
+        .collect();
+    let total: u128 = stakes.iter().sum();
+    if total == 0 {
+        return 0;
+    }
+
+    stakes.sort_unstable_by(|a, b| b.cmp(a));
+    let mut cumulative = 0;
+    for (index, stake) in stakes.into_iter().enumerate() {
+        cumulative += stake;
+        if cumulative * 3 > total {
+            return index + 1;
+        }
+    }
+    0
+}
+
+/// Validator count per version string, most common first; equal counts list the
+/// newest version first.
+pub fn version_distribution(validators: &[ValidatorInfo]) -> Vec<(String, usize)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for validator in validators {
+        *counts.entry(validator.version.as_str()).or_default() += 1;
+    }
+
+    let mut distribution: Vec<(String, usize)> = counts
+        .into_iter()
+        .map(|(version, count)| (version.to_string(), count))
+        .collect();
+    // Compare versions numerically so 2.1.10 ranks above 2.1.9, then by text for
+    // unparseable ones
+    distribution.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| parse_version(&b.0).cmp(&parse_version(&a.0)))
+            .then_with(|| b.0.cmp(&a.0))
+    });
+    distribution
+}
+
+/// Whether an advertised `IP:port` (or bare IP) is loopback, private, link-local or
+/// unspecified, i.e. not reachable from the public internet. Unparseable input is
+/// not flagged.
+pub fn is_private_address(address: &str) -> bool {
+    let ip = match address.parse::<SocketAddr>() {
+        Ok(socket) => socket.ip(),
+        Err(_) => match address.parse::<IpAddr>() {
+            Ok(ip) => ip,
+            Err(_) => return false,
+        },
+    };
+    let ip = match ip {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(IpAddr::V6(v6), IpAddr::V4),
+        ip => ip,
+    };
+    match ip {
+        IpAddr::V4(v4) => {
+            v4.is_loopback() || v4.is_private() || v4.is_link_local() || v4.is_unspecified()
+        }
+        IpAddr::V6(v6) => {
+            v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.is_unique_local()
+                || v6.is_unicast_link_local()
+        }
+    }
+}
+
+/// Names of the endpoints a gossip node advertises on private or loopback addresses.
+pub fn private_gossip_endpoints(node: &GossipNodeInfo) -> Vec<&'static str> {
+    [
+        ("Gossip", Some(node.gossip.as_str())),
+        ("TPU", node.tpu.as_deref()),
+        ("RPC", node.rpc.as_deref()),
+        ("TPU QUIC", node.tpu_quic.as_deref()),
+    ]
+    .into_iter()
+    .filter(|(_, address)| address.is_some_and(is_private_address))
+    .map(|(name, _)| name)
+    .collect()
+}
+
+/// Check that `url` is a well-formed `http://` or `https://` endpoint with a host
+/// and, if given, a numeric port. The error says what is wrong.
+pub fn validate_rpc_url(url: &str) -> Result<(), String> {
+    let url = url.trim();
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or_else(|| "URL must start with http:// or https://".to_string())?;
+    if rest.chars().any(char::is_whitespace) {
+        return Err("URL must not contain spaces".to_string());
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let authority = authority.rsplit('@').next().unwrap_or_default();
+    let (host, port) = match authority.strip_prefix('[') {
+        Some(bracketed) => {
+            let (host, after) = bracketed
+                .split_once(']')
+                .ok_or_else(|| "IPv6 host is missing its closing ]".to_string())?;
+            match after.strip_prefix(':') {
+                Some(port) => (host, Some(port)),
+                None if after.is_empty() => (host, None),
+                None => return Err(format!("Unexpected '{}' after the host", after)),
+            }
+        }
+        None => match authority.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        },
+    };
+    if host.is_empty() {
+        return Err("URL has no host".to_string());
+    }
+    if let Some(port) = port
+        && port.parse::<u16>().is_err()
+    {
+        return Err(format!("'{}' is not a valid port", port));
+    }
+    Ok(())
+}
+
+/// Parse a slot search as a single slot (`1000`) or an inclusive range (`1000-1010`).
+/// Returns `None` for malformed input or a range that ends before it starts.
+pub fn parse_slot_range(input: &str) -> Option<(u64, u64)> {
+    let input = input.trim();
+    match input.split_once('-') {
+        Some((start, end)) => {
+            let start = start.trim().parse::<u64>().ok()?;
+            let end = end.trim().parse::<u64>().ok()?;
+            (start <= end).then_some((start, end))
+        }
+        None => input.parse::<u64>().ok().map(|slot| (slot, slot)),
+    }
+}
+
+/// Number of slots in an inclusive range, or `None` when it overflows a `u64`.
+pub fn slot_range_len(start_slot: u64, end_slot: u64) -> Option<u64> {
+    end_slot.saturating_sub(start_slot).checked_add(1)
+}
+
+/// One-line slot and epoch snapshot for incident reports. Custom clusters also
+/// name their RPC URL, since the cluster alone doesn't identify them.
+pub fn slot_info_snapshot(
+    epoch: u64,
+    current_slot: u64,
+    latest_slot: u64,
+    cluster: Cluster,
+    rpc_url: &str,
+) -> String {
+    let mut snapshot = format!(
+        "epoch={} current_slot={} latest_slot={} cluster={}",
+        epoch,
+        current_slot,
+        latest_slot,
+        cluster.name().to_lowercase()
+    );
+    if cluster == Cluster::Custom {
+        snapshot.push_str(&format!(" rpc_url={}", rpc_url));
+    }
+    snapshot
+}
+
+/// Quote `value` for a POSIX shell: wrapped in single quotes, with embedded
+/// single quotes closed, escaped and reopened.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// `solana` CLI command that fetches the same blocks as a Find Voters search.
+/// Ranges expand to a shell loop over `solana block`.
+pub fn find_voters_cli_command(rpc_url: &str, start_slot: u64, end_slot: u64) -> String {
+    let url = shell_quote(rpc_url);
+    if start_slot == end_slot {
+        format!("solana block {} --url {}", start_slot, url)
+    } else {
+        format!(
+            "for slot in $(seq {} {}); do solana block \"$slot\" --url {}; done",
+            start_slot, end_slot, url
+        )
+    }
+}
+
+/// `solana` CLI command that lists the same leader slots as a Leader Schedule fetch,
+/// or `None` when `identity` is not a valid pubkey.
+pub fn leader_schedule_cli_command(
+    rpc_url: &str,
+    identity: &str,
+    epoch: Option<u64>,
+) -> Option<String> {
+    let identity = Pubkey::from_str(identity.trim()).ok()?;
+    let epoch_arg = epoch.map_or_else(String::new, |epoch| format!(" --epoch {}", epoch));
+    Some(format!(
+        "solana leader-schedule{} --url {} | grep {}",
+        epoch_arg,
+        shell_quote(rpc_url),
+        identity
+    ))
+}
+
+/// Identities read from a watchlist import file.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ParsedWatchlist {
+    /// Valid identity pubkeys, in file order
+    pub accepted: Vec<String>,
+    /// Entries that are not valid pubkeys
+    pub rejected: usize,
+}
+
+/// Parse validator identities from CSV or newline-delimited text.
+/// Only the first column of each line is read, blank lines and a leading
+/// `identity` header are skipped, and entries that are not pubkeys are rejected.
+pub fn parse_watchlist(contents: &str) -> ParsedWatchlist {
+    let mut parsed = ParsedWatchlist::default();
+    for (index, line) in contents.lines().enumerate() {
+        let field = line
+            .split(',')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .trim_matches('"');
+        if field.is_empty() || (index == 0 && field.eq_ignore_ascii_case("identity")) {
+            continue;
+        }
+        match Pubkey::from_str(field) {
+            Ok(pubkey) => parsed.accepted.push(pubkey.to_string()),
+            Err(_) => parsed.rejected += 1,
+        }
+    }
+    parsed
+}
+
+/// Display settings from the config that labels and tables read while rendering.
+///
+/// The app stores them in the egui context every frame, so they reach every
+/// widget without being threaded through each tab's parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplaySettings {
+    /// Render labels without emoji icons
+    pub plain_labels: bool,
+    /// Show pubkeys abbreviated in tables
+    pub abbreviate_pubkeys: bool,
+    /// Match pubkey searches case exactly
+    pub case_sensitive_pubkey_search: bool,
+    /// Seconds after which fetched data is flagged as stale; 0 never flags it
+    pub stale_after_secs: u64,
+    /// Most rows a large table renders; 0 renders every row
+    pub max_rendered_rows: usize,
+    /// Theme accent; `None` keeps the built-in colors
+    pub accent_color: Option<[u8; 3]>,
+    /// Replace red/green status colors with the colorblind-safe palette
+    pub colorblind_palette: bool,
+    /// Shade of alternate table rows
+    pub table_stripes: StripeIntensity,
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            plain_labels: false,
+            abbreviate_pubkeys: false,
+            case_sensitive_pubkey_search: true,
+            stale_after_secs: 0,
+            max_rendered_rows: 0,
+            accent_color: None,
+            colorblind_palette: false,
+            table_stripes: StripeIntensity::default(),
+        }
+    }
+}
+
+impl DisplaySettings {
+    fn id() -> egui::Id {
+        egui::Id::new("display_settings")
+    }
+
+    /// Render everything in `ctx` with these settings from now on.
+    pub fn store(self, ctx: &egui::Context) {
+        ctx.data_mut(|data| data.insert_temp(Self::id(), self));
+    }
+
+    /// The settings `ctx` renders with, or the defaults before any are stored.
+    pub fn get(ctx: &egui::Context) -> Self {
+        ctx.data(|data| data.get_temp(Self::id()))
+            .unwrap_or_default()
+    }
+}
+
+/// Check whether labels should be rendered without emoji icons.
+pub fn plain_labels(ctx: &egui::Context) -> bool {
+    DisplaySettings::get(ctx).plain_labels
+}
+
+/// Normalize a base58 pubkey or signature, or a search term compared against one.
+/// Base58 is case-sensitive, so text is left as is unless `case_sensitive` is off.
+pub fn pubkey_search_key(text: &str, case_sensitive: bool) -> String {
+    if case_sensitive {
+        text.to_string()
+    } else {
+        text.to_lowercase()
+    }
+}
+
+/// Whether the window is focused and not minimized; repaints that only keep the
+/// display current are skipped otherwise.
+pub fn window_active(ctx: &egui::Context) -> bool {
+    ctx.input(|i| i.viewport().focused.unwrap_or(true) && !i.viewport().minimized.unwrap_or(false))
+}
+
+/// Whether data fetched at `updated` is older than `stale_after_secs` at `now`.
+pub fn is_stale(updated: DateTime<Local>, now: DateTime<Local>, stale_after_secs: u64) -> bool {
+    stale_after_secs > 0 && (now - updated).num_seconds() > stale_after_secs as i64
+}
+
+/// How long until data of the given age turns stale, just past the threshold since
+/// `is_stale` compares whole seconds.
+fn stale_repaint_delay(age: Duration, stale_after_secs: u64) -> Duration {
+    Duration::from_secs(stale_after_secs + 1).saturating_sub(age)
+}
+
+/// Render when the data was last fetched, in the warning color once it is stale.
+pub fn render_last_updated(ui: &mut egui::Ui, updated: Option<DateTime<Local>>) {
+    let Some(updated) = updated else {
+        return;
+    };
+    let stale_after_secs = DisplaySettings::get(ui.ctx()).stale_after_secs;
+    let label = format!("Updated {}", updated.format("%H:%M:%S"));
+    if is_stale(updated, Local::now(), stale_after_secs) {
+        ui.colored_label(WARNING_COLOR, format!("⚠ {} · data may be stale", label))
+            .on_hover_text(format!(
+                "Fetched more than {}s ago; refresh before relying on these numbers",
+                stale_after_secs
+            ));
+    } else {
+        ui.label(label);
+        if stale_after_secs > 0 && window_active(ui.ctx()) {
+            // Repaint so the warning appears once the threshold passes
+            let age = (Local::now() - updated).to_std().unwrap_or_default();
+            ui.ctx()
+                .request_repaint_after(stale_repaint_delay(age, stale_after_secs));
+        }
+    }
+}
+
+/// Draw the active tab's main table and record where it went, so a table capture
+/// can crop to it.
+pub fn record_table_rect<R>(ui: &mut egui::Ui, add_table: impl FnOnce(&mut egui::Ui) -> R) -> R {
+    let scope = ui.scope(add_table);
+    ui.ctx()
+        .data_mut(|data| data.insert_temp(table_rect_id(), Some(scope.response.rect)));
+    scope.inner
+}
+
+/// Where the active tab drew its main table this frame, clearing the record.
+pub fn take_table_rect(ctx: &egui::Context) -> Option<egui::Rect> {
+    ctx.data_mut(|data| data.remove_temp::<Option<egui::Rect>>(table_rect_id()))
+        .flatten()
+}
+
+fn table_rect_id() -> egui::Id {
+    egui::Id::new("table_capture_rect")
+}
+
+/// The leading `max_rows` of `rows`, or all of them when `max_rows` is 0.
+pub fn capped_rows<T>(rows: &[T], max_rows: usize) -> &[T] {
+    if max_rows == 0 {
+        rows
+    } else {
+        &rows[..rows.len().min(max_rows)]
+    }
+}
+
+/// The rows a table renders under the configured row cap, in their current order.
+pub fn rendered_rows<'a, T>(ctx: &egui::Context, rows: &'a [T]) -> &'a [T] {
+    capped_rows(rows, DisplaySettings::get(ctx).max_rendered_rows)
+}
+
+/// Note that only the first rows of `total` are rendered, when the row cap applies.
+pub fn render_row_cap_notice(ui: &mut egui::Ui, total: usize) {
+    let max_rows = DisplaySettings::get(ui.ctx()).max_rendered_rows;
+    if max_rows > 0 && total > max_rows {
+        ui.colored_label(
+            WARNING_COLOR,
+            format!(
+                "⚠ Showing first {} of {} — refine your search",
+                max_rows, total
+            ),
+        )
+        .on_hover_text(
+            "Only the top rows in the current order are rendered; change the limit under \
+             Settings → Data and refresh",
+        );
+    }
+}
+
+/// The configured accent color, or `fallback` when none is set.
+pub fn accent_color(ctx: &egui::Context, fallback: egui::Color32) -> egui::Color32 {
+    match DisplaySettings::get(ctx).accent_color {
+        Some([r, g, b]) => egui::Color32::from_rgb(r, g, b),
+        None => fallback,
+    }
+}
+
+/// Status colors that the colorblind-safe palette swaps; other colors are
+/// always drawn as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusColor {
+    /// Succeeded, found or reached
+    Success,
+    /// Failed operations and errors
+    Error,
+    /// Stalled votes and missing voters
+    Stalled,
+    /// Outgoing RPC requests in the logs
+    Request,
+    /// RPC responses in the logs
+    Response,
+}
+
+impl StatusColor {
+    /// The color in the default palette, or in the colorblind-safe one where
+    /// green becomes blue, red becomes orange and request blue becomes purple.
+    pub const fn color(self, colorblind: bool) -> egui::Color32 {
+        match (self, colorblind) {
+            (Self::Success, false) => SUCCESS_COLOR,
+            (Self::Error, false) => LOG_ERROR_COLOR,
+            (Self::Stalled, false) => STALLED_VOTE_COLOR,
+            (Self::Request, false) => LOG_REQUEST_COLOR,
+            (Self::Response, false) => LOG_RESPONSE_COLOR,
+            (Self::Success | Self::Response, true) => COLORBLIND_SUCCESS_COLOR,
+            (Self::Error | Self::Stalled, true) => COLORBLIND_ERROR_COLOR,
+            (Self::Request, true) => COLORBLIND_REQUEST_COLOR,
+        }
+    }
+}
+
+/// `status` as drawn with the palette `ctx` renders with.
+pub fn palette_color(ctx: &egui::Context, status: StatusColor) -> egui::Color32 {
+    status.color(DisplaySettings::get(ctx).colorblind_palette)
+}
+
+/// How strongly alternate table rows are shaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeIntensity {
+    Off,
+    /// The theme's own faint background
+    #[default]
+    Subtle,
+    /// A darker (or, in dark mode, lighter) shade for low-contrast monitors
+    Strong,
+}
+
+impl StripeIntensity {
+    /// All intensities, weakest first.
+    pub const fn all() -> &'static [Self] {
+        &[Self::Off, Self::Subtle, Self::Strong]
+    }
+
+    /// Display name for settings.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Subtle => "Subtle",
+            Self::Strong => "Strong",
+        }
+    }
+}
+
+/// Apply the configured stripe shade to `ui` before building a table in it.
+/// Returns whether the table should be striped at all.
+pub fn apply_row_stripes(ui: &mut egui::Ui) -> bool {
+    match DisplaySettings::get(ui.ctx()).table_stripes {
+        StripeIntensity::Off => false,
+        StripeIntensity::Subtle => true,
+        StripeIntensity::Strong => {
+            let visuals = ui.visuals_mut();
+            visuals.faint_bg_color = if visuals.dark_mode {
+                egui::Color32::from_additive_luminance(STRONG_STRIPE_DARK_LUMINANCE)
+            } else {
+                egui::Color32::from_black_alpha(STRONG_STRIPE_LIGHT_ALPHA)
+            };
+            true
+        }
+    }
+}
+
+/// Path for an exported file in the first writable download folder.
+pub fn download_path(file_name: &str) -> Result<PathBuf, String> {
+    Ok(writable_download_dir(None)?.join(file_name))
+}
+
+/// Directory for exports and downloaded updates: `configured` when set, which
+/// must be writable, else the first writable of the system Downloads folder, the
+/// home folder and the temp dir.
+pub fn writable_download_dir(configured: Option<&Path>) -> Result<PathBuf, String> {
+    if let Some(dir) = configured {
+        check_writable(dir).map_err(|e| {
+            format!(
+                "Configured download directory {} is not usable: {}",
+                dir.display(),
+                e
+            )
+        })?;
+        return Ok(dir.to_path_buf());
+    }
+    let candidates = dirs::download_dir()
+        .into_iter()
+        .chain(dirs::home_dir())
+        .chain([std::env::temp_dir()]);
+    first_writable_dir(candidates)
+}
+
+/// First of `candidates` we can create files in, or why each one was rejected.
+fn first_writable_dir(candidates: impl IntoIterator<Item = PathBuf>) -> Result<PathBuf, String> {
+    let mut failures = Vec::new();
+    for dir in candidates {
+        match check_writable(&dir) {
+            Ok(()) => return Ok(dir),
+            Err(e) => failures.push(format!("{}: {}", dir.display(), e)),
+        }
+    }
+    Err(format!(
+        "No writable download directory found ({})",
+        failures.join("; ")
+    ))
+}
+
+/// Check that `dir` is an existing directory we can create files in.
+fn check_writable(dir: &Path) -> Result<(), String> {
+    if !std::fs::metadata(dir).is_ok_and(|metadata| metadata.is_dir()) {
+        return Err("not an existing directory".to_string());
+    }
+    let probe = dir.join(".solana-ui-write-test");
+    std::fs::write(&probe, b"").map_err(|e| format!("not writable ({})", e))?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(())
+}
+
+/// Shorten a base58 pubkey to `ABCD…WXYZ`; short strings are returned unchanged.
+pub fn abbreviate_pubkey(pubkey: &str) -> String {
+    if !pubkey.is_ascii() || pubkey.len() <= ABBREVIATED_PUBKEY_CHARS * 2 + 1 {
+        return pubkey.to_string();
+    }
     format!(
-        "{:.precision$}%",
-        skip_rate,
-        precision = formatting::DECIMAL_PLACES
+        "{}…{}",
+        &pubkey[..ABBREVIATED_PUBKEY_CHARS],
+        &pubkey[pubkey.len() - ABBREVIATED_PUBKEY_CHARS..]
     )
 }
 
+/// First `max_chars` characters of `text`, cut on a char boundary so
+/// multi-byte characters such as emoji are never split.
+pub fn truncate_chars(text: &str, max_chars: usize) -> &str {
+    match text.char_indices().nth(max_chars) {
+        Some((index, _)) => &text[..index],
+        None => text,
+    }
+}
+
+/// Minimum width of a pubkey table column for the current display mode.
+pub fn pubkey_column_width(ctx: &egui::Context) -> f32 {
+    if DisplaySettings::get(ctx).abbreviate_pubkeys {
+        COLUMN_PUBKEY_ABBREVIATED_WIDTH
+    } else {
+        COLUMN_PUBKEY_WIDTH
+    }
+}
+
+/// Render a pubkey in monospace, or its alias when one is set. When aliased or
+/// abbreviated, the full value is shown on hover and clicking copies it.
+pub fn render_pubkey(ui: &mut egui::Ui, aliases: &AliasStore, pubkey: &str) -> egui::Response {
+    let text = match aliases.get(pubkey) {
+        Some(alias) => egui::RichText::new(alias).strong(),
+        None if DisplaySettings::get(ui.ctx()).abbreviate_pubkeys => {
+            egui::RichText::new(abbreviate_pubkey(pubkey)).monospace()
+        }
+        None => return ui.monospace(pubkey),
+    };
+
+    let response = ui
+        .add(egui::Label::new(text).sense(egui::Sense::click()))
+        .on_hover_text(format!("{}\n(click to copy)", pubkey));
+    if response.clicked() {
+        ui.ctx().copy_text(pubkey.to_string());
+    }
+    response
+}
+
+/// Prefix a label with an emoji icon unless plain text labels are enabled.
+pub fn icon_text(ctx: &egui::Context, icon: &str, text: &str) -> String {
+    if plain_labels(ctx) {
+        text.to_string()
+    } else {
+        format!("{} {}", icon, text)
+    }
+}
+
 /// Create a standard error frame with consistent styling.
-pub fn create_error_frame() -> egui::Frame {
+pub fn create_error_frame(ctx: &egui::Context) -> egui::Frame {
     egui::Frame::new()
         .fill(ERROR_BACKGROUND)
         .inner_margin(FRAME_INNER_MARGIN)
         .corner_radius(FRAME_CORNER_RADIUS)
-        .stroke(egui::Stroke::new(FRAME_STROKE_WIDTH, egui::Color32::RED))
+        .stroke(egui::Stroke::new(
+            FRAME_STROKE_WIDTH,
+            palette_color(ctx, StatusColor::Error),
+        ))
+}
+
+/// Add a button that starts a fetch, disabled while one is already in progress
+/// since the app ignores refreshes until it finishes. `disabled_reason` also
+/// disables it, e.g. for invalid input, and is shown on hover instead.
+pub fn refresh_button(
+    ui: &mut egui::Ui,
+    is_loading: bool,
+    disabled_reason: Option<&str>,
+    text: String,
+    hover_text: &str,
+) -> egui::Response {
+    let response = ui
+        .add_enabled(
+            !is_loading && disabled_reason.is_none(),
+            egui::Button::new(text),
+        )
+        .on_hover_text(hover_text);
+    match disabled_reason {
+        _ if is_loading => response.on_disabled_hover_text("Refresh in progress"),
+        Some(reason) => response.on_disabled_hover_text(reason),
+        None => response,
+    }
+}
+
+/// Render an error in the standard error frame with a button that copies the
+/// error and its operation context for bug reports.
+pub fn render_error_frame(ui: &mut egui::Ui, operation: &str, error: &str) {
+    create_error_frame(ui.ctx()).show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.colored_label(
+                palette_color(ui.ctx(), StatusColor::Error),
+                format!("❌ {} failed: {}", operation, error),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .small_button(icon_text(ui.ctx(), "📋", "Copy details"))
+                    .on_hover_text("Copy the error and its context to the clipboard")
+                    .clicked()
+                {
+                    ui.ctx().copy_text(error_details(operation, error));
+                }
+            });
+        });
+    });
+}
+
+/// Render the caveat shown with partial results: a warning line and a collapsible
+/// list of the sub-requests that failed. Draws nothing when none did.
+pub fn render_partial_failures(
+    ui: &mut egui::Ui,
+    id_salt: &str,
+    summary: &str,
+    failures: &[PartialFailure],
+) {
+    if failures.is_empty() {
+        return;
+    }
+    ui.colored_label(WARNING_COLOR, format!("⚠ Partial results: {}", summary));
+    egui::CollapsingHeader::new(format!("Failed requests ({})", failures.len()))
+        .id_salt(id_salt)
+        .show(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .id_salt(id_salt)
+                .max_height(PARTIAL_FAILURES_HEIGHT)
+                .show(ui, |ui| {
+                    for failure in failures {
+                        ui.label(format!("{}: {}", failure.step, failure.error));
+                    }
+                });
+        });
+}
+
+/// Format an error with its operation context for pasting into a bug report.
+pub fn error_details(operation: &str, error: &str) -> String {
+    format!(
+        "Operation: {}\nError: {}\nTime: {}\nApp version: {}",
+        operation,
+        error,
+        chrono::Local::now().to_rfc3339(),
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/// Render the Hold toggle that freezes the validators and gossip tables, with a
+/// "Held" badge while it is on.
+pub fn render_hold_toggle(ui: &mut egui::Ui, hold: &mut bool) {
+    ui.toggle_value(hold, icon_text(ui.ctx(), "⏸", "Hold"))
+        .on_hover_text(
+            "Freeze the validators and gossip tables while you inspect them; refreshes keep \
+         running and show up on release",
+        );
+    if *hold {
+        ui.colored_label(WARNING_COLOR, "Held");
+    }
+}
+
+/// Lay out a table cell's contents right-aligned, for numeric columns.
+pub fn right_aligned<R>(ui: &mut egui::Ui, add_contents: impl FnOnce(&mut egui::Ui) -> R) -> R {
+    ui.with_layout(
+        egui::Layout::right_to_left(egui::Align::Center),
+        add_contents,
+    )
+    .inner
 }
 
 /// Create a standard info frame with consistent styling.
@@ -261,6 +1321,47 @@ pub fn render_search_field(
     response
 }
 
+/// Move `term` to the front of a recent-search history, dropping duplicates
+/// and anything beyond `limit`. Blank terms are ignored.
+pub fn push_recent_search(history: &mut Vec<String>, term: &str, limit: usize) {
+    let term = term.trim();
+    if term.is_empty() {
+        return;
+    }
+    history.retain(|entry| entry != term);
+    history.insert(0, term.to_string());
+    history.truncate(limit);
+}
+
+/// Render a dropdown of recent searches next to a search field.
+/// Picking an entry fills `search_term`; returns whether one was picked.
+pub fn render_recent_searches(
+    ui: &mut egui::Ui,
+    search_term: &mut String,
+    recent: &[String],
+) -> bool {
+    let mut picked = false;
+    let label = if plain_labels(ui.ctx()) {
+        "Recent"
+    } else {
+        "🕘"
+    };
+    ui.add_enabled_ui(!recent.is_empty(), |ui| {
+        ui.menu_button(label, |ui| {
+            for term in recent {
+                if ui.button(term).clicked() {
+                    *search_term = term.clone();
+                    picked = true;
+                    ui.close();
+                }
+            }
+        })
+        .response
+        .on_hover_text("Recent searches");
+    });
+    picked
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -268,15 +1369,593 @@ mod tests {
     #[test]
     fn test_format_stake() {
         assert_eq!(format_stake(1_000_000_000), "1.00 SOL");
-        assert_eq!(format_stake(500_000_000), "0.50 SOL");
-        assert_eq!(format_stake(0), "0.00 SOL");
+        assert_eq!(format_stake(500_000_000), "0.5000 SOL");
+        assert_eq!(format_stake(0), "0 SOL");
+    }
+
+    #[test]
+    fn test_format_stake_boundaries() {
+        // Full lamport precision below 0.0001 SOL
+        assert_eq!(format_stake(1), "0.000000001 SOL");
+        assert_eq!(format_stake(99_999), "0.000099999 SOL");
+        // Four decimals below 1 SOL
+        assert_eq!(format_stake(100_000), "0.0001 SOL");
+        assert_eq!(format_stake(999_949_999), "0.9999 SOL");
+        // Rounding up to 1 SOL switches to its two decimals
+        assert_eq!(format_stake(999_999_999), "1.00 SOL");
+        // Two decimals below 1,000 SOL
+        assert_eq!(format_stake(999_994_999_999), "999.99 SOL");
+        // Rounding up to 1,000 SOL switches to whole SOL
+        assert_eq!(format_stake(999_995_000_000), "1,000 SOL");
+        // Whole SOL with separators from 1,000 SOL
+        assert_eq!(format_stake(1_000_000_000_000), "1,000 SOL");
+        assert_eq!(format_stake(12_345_678_400_000_000), "12,345,678 SOL");
+        assert_eq!(format_stake(12_345_678_500_000_000), "12,345,679 SOL");
+        assert_eq!(format_stake(u64::MAX), "18,446,744,074 SOL");
     }
 
     #[test]
     fn test_format_skip_rate() {
-        assert_eq!(format_skip_rate(5.25), "5.25%");
-        assert_eq!(format_skip_rate(0.0), "0.00%");
-        assert_eq!(format_skip_rate(100.0), "100.00%");
+        assert_eq!(format_skip_rate(Some(5.25)), "5.25%");
+        assert_eq!(format_skip_rate(Some(0.0)), "0.00%");
+        assert_eq!(format_skip_rate(Some(100.0)), "100.00%");
+        assert_eq!(format_skip_rate(None), "N/A");
+    }
+
+    #[test]
+    fn test_credits_by_epoch_marks_gaps() {
+        assert!(credits_by_epoch(&[]).is_empty());
+        let history = [(10, 500, 100), (12, 900, 500), (13, 1_300, 900)];
+        assert_eq!(
+            credits_by_epoch(&history),
+            vec![
+                (10, Some(400)),
+                (11, None),
+                (12, Some(400)),
+                (13, Some(400))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_credits_by_epoch_keeps_recent_epochs() {
+        let history = [(0, 10, 0), (200, 30, 10)];
+        let earned = credits_by_epoch(&history);
+        assert_eq!(earned.len() as u64, CREDITS_CHART_MAX_EPOCHS);
+        assert_eq!(
+            earned.first(),
+            Some(&(200 - CREDITS_CHART_MAX_EPOCHS + 1, None))
+        );
+        assert_eq!(earned.last(), Some(&(200, Some(20))));
+    }
+
+    #[test]
+    fn test_stripe_intensity_config_names() {
+        assert_eq!(
+            serde_json::to_string(&StripeIntensity::Strong).unwrap(),
+            "\"strong\""
+        );
+        let parsed: StripeIntensity = serde_json::from_str("\"off\"").unwrap();
+        assert_eq!(parsed, StripeIntensity::Off);
+        assert_eq!(StripeIntensity::default(), StripeIntensity::Subtle);
+    }
+
+    #[test]
+    fn test_epochs_active_spans_history() {
+        assert_eq!(epochs_active(&[]), None);
+        assert_eq!(epochs_active(&[(812, 400, 0)]), Some(1));
+        assert_eq!(
+            epochs_active(&[(749, 100, 0), (750, 300, 100), (812, 900, 300)]),
+            Some(64)
+        );
+    }
+
+    #[test]
+    fn test_format_sol_exact() {
+        assert_eq!(format_sol_exact(0), "0.000000000");
+        assert_eq!(format_sol_exact(1_500_000_000_001), "1500.000000001");
+    }
+
+    #[test]
+    fn test_status_color() {
+        assert_eq!(StatusColor::Success.color(false), SUCCESS_COLOR);
+        assert_eq!(StatusColor::Success.color(true), COLORBLIND_SUCCESS_COLOR);
+        assert_eq!(StatusColor::Response.color(true), COLORBLIND_SUCCESS_COLOR);
+        assert_eq!(StatusColor::Stalled.color(false), STALLED_VOTE_COLOR);
+        assert_eq!(StatusColor::Stalled.color(true), COLORBLIND_ERROR_COLOR);
+        assert_eq!(StatusColor::Request.color(true), COLORBLIND_REQUEST_COLOR);
+    }
+
+    #[test]
+    fn test_validate_rpc_url() {
+        assert!(validate_rpc_url("https://mainnet.helius-rpc.com/?api-key=abc").is_ok());
+        assert!(validate_rpc_url(" http://127.0.0.1:8899 ").is_ok());
+        assert!(validate_rpc_url("http://[::1]:8899/").is_ok());
+        assert!(validate_rpc_url("ftp://rpc.example.com").is_err());
+        assert!(validate_rpc_url("rpc.example.com").is_err());
+        assert!(validate_rpc_url("https://").is_err());
+        assert!(validate_rpc_url("https://rpc.example.com:port").is_err());
+        assert!(validate_rpc_url("https://rpc example.com").is_err());
+    }
+
+    #[test]
+    fn test_capped_rows() {
+        let rows = [1, 2, 3, 4];
+        assert_eq!(capped_rows(&rows, 0), &rows);
+        assert_eq!(capped_rows(&rows, 2), &[1, 2]);
+        assert_eq!(capped_rows(&rows, 10), &rows);
+    }
+
+    #[test]
+    fn test_is_private_address() {
+        assert!(is_private_address("127.0.0.1:8001"));
+        assert!(is_private_address("10.1.2.3:8001"));
+        assert!(is_private_address("192.168.0.5:8899"));
+        assert!(is_private_address("169.254.1.1:8001"));
+        assert!(is_private_address("0.0.0.0:8001"));
+        assert!(is_private_address("[::1]:8001"));
+        assert!(is_private_address("[fd00::1]:8001"));
+        assert!(is_private_address("[::ffff:172.16.0.1]:8001"));
+        assert!(!is_private_address("145.40.67.83:8001"));
+        assert!(!is_private_address("[2604:1380::1]:8001"));
+        assert!(!is_private_address("not an address"));
+    }
+
+    #[test]
+    fn test_pubkey_search_key_case() {
+        assert_eq!(pubkey_search_key("AbC", true), "AbC");
+        assert_eq!(pubkey_search_key("AbC", false), "abc");
+    }
+
+    #[test]
+    fn test_slot_info_snapshot() {
+        assert_eq!(
+            slot_info_snapshot(812, 350_000_100, 350_000_105, Cluster::Mainnet, "unused"),
+            "epoch=812 current_slot=350000100 latest_slot=350000105 cluster=mainnet"
+        );
+        assert_eq!(
+            slot_info_snapshot(1, 2, 3, Cluster::Custom, "http://10.0.0.5:8899"),
+            "epoch=1 current_slot=2 latest_slot=3 cluster=custom rpc_url=http://10.0.0.5:8899"
+        );
+    }
+
+    #[test]
+    fn test_find_voters_cli_command() {
+        let url = "https://api.testnet.solana.com";
+        assert_eq!(
+            find_voters_cli_command(url, 42, 42),
+            "solana block 42 --url 'https://api.testnet.solana.com'"
+        );
+        assert_eq!(
+            find_voters_cli_command(url, 10, 12),
+            "for slot in $(seq 10 12); do solana block \"$slot\" --url \
+             'https://api.testnet.solana.com'; done"
+        );
+        // Query strings and quotes in custom URLs stay inside one argument
+        assert_eq!(
+            find_voters_cli_command("https://rpc.example.com/?a=1&key=x'y", 1, 1),
+            r"solana block 1 --url 'https://rpc.example.com/?a=1&key=x'\''y'"
+        );
+    }
+
+    #[test]
+    fn test_leader_schedule_cli_command() {
+        let url = "http://127.0.0.1:8899";
+        let identity = Pubkey::new_from_array([7; 32]).to_string();
+        assert_eq!(
+            leader_schedule_cli_command(url, &identity, Some(500)),
+            Some(format!(
+                "solana leader-schedule --epoch 500 --url 'http://127.0.0.1:8899' | grep {}",
+                identity
+            ))
+        );
+        assert_eq!(
+            leader_schedule_cli_command(url, &identity, None),
+            Some(format!(
+                "solana leader-schedule --url 'http://127.0.0.1:8899' | grep {}",
+                identity
+            ))
+        );
+        assert_eq!(
+            leader_schedule_cli_command(url, "not a pubkey; rm", None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cluster_from_name() {
+        assert_eq!(Cluster::from_name("testnet"), Some(Cluster::Testnet));
+        assert_eq!(Cluster::from_name("MAINNET"), Some(Cluster::Mainnet));
+        assert_eq!(Cluster::from_name("mainnet-beta"), Some(Cluster::Mainnet));
+        assert_eq!(Cluster::from_name("devnet"), Some(Cluster::Devnet));
+        assert_eq!(Cluster::from_name("betanet"), None);
+    }
+
+    #[test]
+    fn test_is_stale() {
+        let updated = Local::now();
+        let later = updated + chrono::Duration::seconds(61);
+        assert!(is_stale(updated, later, 60));
+        assert!(!is_stale(updated, later, 61));
+        assert!(!is_stale(updated, later, 0));
+    }
+
+    #[test]
+    fn test_stale_repaint_delay() {
+        assert_eq!(
+            stale_repaint_delay(Duration::from_secs(20), 60),
+            Duration::from_secs(41)
+        );
+        assert_eq!(
+            stale_repaint_delay(Duration::from_secs(90), 60),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_accent_color() {
+        let ctx = egui::Context::default();
+        let fallback = egui::Color32::from_rgb(1, 2, 3);
+        assert_eq!(accent_color(&ctx, fallback), fallback);
+
+        DisplaySettings {
+            accent_color: Some([255, 0, 128]),
+            ..DisplaySettings::default()
+        }
+        .store(&ctx);
+        assert_eq!(
+            accent_color(&ctx, fallback),
+            egui::Color32::from_rgb(255, 0, 128)
+        );
+        // Settings belong to their context, not the process
+        assert_eq!(accent_color(&egui::Context::default(), fallback), fallback);
+    }
+
+    fn validator_with_stake(activated_stake: u64) -> ValidatorInfo {
+        ValidatorInfo {
+            identity: Default::default(),
+            vote_account: Default::default(),
+            commission: 0,
+            last_vote: 0,
+            root_slot: 0,
+            vote_credits: 0,
+            epoch_credits: Vec::new(),
+            activated_stake,
+            version: String::new(),
+            skip_rate: Some(0.0),
+            delinquent: false,
+        }
+    }
+
+    #[test]
+    fn test_commission_distribution() {
+        let validators: Vec<_> = [(0, 100), (5, 200), (7, 300), (10, 400), (100, 500)]
+            .into_iter()
+            .map(|(commission, stake)| ValidatorInfo {
+                commission,
+                ..validator_with_stake(stake)
+            })
+            .collect();
+        assert_eq!(commission_distribution(&validators, false), [1, 1, 2, 1]);
+        assert_eq!(
+            commission_distribution(&validators, true),
+            [100, 200, 700, 500]
+        );
+    }
+
+    #[test]
+    fn test_commission_bucket_boundaries() {
+        let buckets: Vec<usize> = [0, 1, 5, 6, 10, 11, 100]
+            .into_iter()
+            .map(commission_bucket)
+            .collect();
+        assert_eq!(buckets, vec![0, 1, 1, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_gini_coefficient_uniform() {
+        let validators: Vec<_> = (0..10).map(|_| validator_with_stake(1_000)).collect();
+        assert!(gini_coefficient(&validators).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gini_coefficient_concentrated() {
+        let mut validators: Vec<_> = (0..999).map(|_| validator_with_stake(0)).collect();
+        validators.push(validator_with_stake(1_000_000));
+        let gini = gini_coefficient(&validators);
+        assert!((gini - 0.999).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gini_coefficient_empty() {
+        assert_eq!(gini_coefficient(&[]), 0.0);
+        assert_eq!(gini_coefficient(&[validator_with_stake(0)]), 0.0);
+    }
+
+    #[test]
+    fn test_nakamoto_coefficient() {
+        let validators: Vec<_> = [40, 30, 20, 10]
+            .into_iter()
+            .map(validator_with_stake)
+            .collect();
+        assert_eq!(nakamoto_coefficient(&validators), 1);
+
+        let validators: Vec<_> = (0..9).map(|_| validator_with_stake(100)).collect();
+        assert_eq!(nakamoto_coefficient(&validators), 4);
+
+        assert_eq!(nakamoto_coefficient(&[]), 0);
+        assert_eq!(nakamoto_coefficient(&[validator_with_stake(0)]), 0);
+
+        // Delinquent stake counts toward neither the total nor the coefficient
+        let mut validators: Vec<_> = [40, 10, 10, 10, 10]
+            .into_iter()
+            .map(validator_with_stake)
+            .collect();
+        assert_eq!(nakamoto_coefficient(&validators), 1);
+        validators[0].delinquent = true;
+        assert_eq!(active_stake(&validators), 40);
+        assert_eq!(nakamoto_coefficient(&validators), 2);
+    }
+
+    #[test]
+    fn test_version_distribution() {
+        let validators = vec![
+            validator_with_version("2.0.20"),
+            validator_with_version("2.1.14"),
+            validator_with_version("2.1.14"),
+            validator_with_version("2.1.13"),
+        ];
+        assert_eq!(
+            version_distribution(&validators),
+            vec![
+                ("2.1.14".to_string(), 2),
+                ("2.1.13".to_string(), 1),
+                ("2.0.20".to_string(), 1),
+            ]
+        );
+        // Ties rank by numeric version, not by string
+        let validators = vec![
+            validator_with_version("2.1.9"),
+            validator_with_version("2.1.10"),
+            validator_with_version("Unknown"),
+        ];
+        assert_eq!(
+            version_distribution(&validators),
+            vec![
+                ("2.1.10".to_string(), 1),
+                ("2.1.9".to_string(), 1),
+                ("Unknown".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_abbreviate_pubkey() {
+        assert_eq!(
+            abbreviate_pubkey("Vote111111111111111111111111111111111111111"),
+            "Vote…1111"
+        );
+        assert_eq!(abbreviate_pubkey("ABCDEFGHI"), "ABCDEFGHI");
+        assert_eq!(abbreviate_pubkey(""), "");
+    }
+
+    #[test]
+    fn test_status_manager_tracks_fetches_separately() {
+        let mut status = StatusManager::default();
+        assert!(status.is_ready());
+
+        status.start_fetch(FetchOperation::FindVoters);
+        status.start_fetch(FetchOperation::GossipNodes);
+        assert!(status.is_fetching(FetchOperation::FindVoters));
+        assert!(!status.is_fetching(FetchOperation::Validators));
+        assert_eq!(status.status_text(), "Fetching gossip nodes, voters...");
+
+        status.cancel_fetch(FetchOperation::FindVoters);
+        assert!(status.is_fetching(FetchOperation::GossipNodes));
+        assert_eq!(status.status_text(), "Fetching gossip nodes...");
+    }
+
+    #[test]
+    fn test_status_manager_finish_fetch() {
+        let mut status = StatusManager::default();
+        status.start_fetch(FetchOperation::FindVoters);
+
+        // Still in flight until the task reports back, however long it takes
+        status.update();
+        assert!(status.is_fetching(FetchOperation::FindVoters));
+
+        status.finish_fetch(FetchOperation::FindVoters);
+        assert!(!status.is_loading());
+        assert_eq!(status.status_text(), "Voter search finished");
+
+        // A late report for a fetch no longer tracked changes nothing
+        status.cancel_fetch(FetchOperation::Validators);
+        status.finish_fetch(FetchOperation::Validators);
+        assert_eq!(status.status_text(), "Cancelled");
+    }
+
+    #[test]
+    fn test_truncate_chars() {
+        assert_eq!(truncate_chars("hello", 10), "hello");
+        assert_eq!(truncate_chars("hello", 3), "hel");
+        assert_eq!(truncate_chars("", 3), "");
+        assert_eq!(truncate_chars("ab🚀cd", 3), "ab🚀");
+        assert_eq!(truncate_chars("épée", 2), "ép");
+    }
+
+    #[test]
+    fn test_staked_supply_percent() {
+        assert_eq!(staked_supply_percent(650, 1_000), Some(65.0));
+        assert_eq!(staked_supply_percent(0, 1_000), Some(0.0));
+        assert_eq!(staked_supply_percent(650, 0), None);
+    }
+
+    #[test]
+    fn test_cap_validators_by_stake() {
+        let mut validators = vec![
+            validator_with_stake(10),
+            validator_with_stake(30),
+            validator_with_stake(20),
+        ];
+        assert_eq!(cap_validators_by_stake(&mut validators, 3), None);
+        assert_eq!(cap_validators_by_stake(&mut validators, 2), Some(3));
+        let stakes: Vec<u64> = validators.iter().map(|v| v.activated_stake).collect();
+        assert_eq!(stakes, vec![30, 20]);
+    }
+
+    fn validator_with_version(version: &str) -> ValidatorInfo {
+        ValidatorInfo {
+            version: version.to_string(),
+            ..validator_with_stake(0)
+        }
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("2.1.14"), Some([2, 1, 14]));
+        assert_eq!(parse_version("v1.18.26"), Some([1, 18, 26]));
+        assert_eq!(parse_version("2.2.0-beta.1"), Some([2, 2, 0]));
+        assert_eq!(parse_version("2.1"), None);
+        assert_eq!(parse_version("2.1.0.4"), None);
+        assert_eq!(parse_version("Unknown"), None);
+    }
+
+    #[test]
+    fn test_outdated_version_threshold() {
+        let validators = vec![
+            validator_with_version("2.1.14"),
+            validator_with_version("2.1.14"),
+            validator_with_version("2.0.20"),
+            validator_with_version("Unknown"),
+        ];
+        assert_eq!(
+            outdated_version_threshold(&validators, None),
+            Some([2, 1, 14])
+        );
+        assert_eq!(
+            outdated_version_threshold(&validators, Some("2.2.0")),
+            Some([2, 2, 0])
+        );
+        assert_eq!(
+            outdated_version_threshold(&validators, Some("latest")),
+            Some([2, 1, 14])
+        );
+        assert_eq!(outdated_version_threshold(&[], None), None);
+
+        assert!(is_outdated_version("2.0.20", Some([2, 1, 14])));
+        assert!(!is_outdated_version("2.1.14", Some([2, 1, 14])));
+        assert!(!is_outdated_version("Unknown", Some([2, 1, 14])));
+        assert!(!is_outdated_version("2.0.20", None));
+    }
+
+    /// Fresh empty directory under the system temp dir, unique to this test.
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("solana-ui-test-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_download_dir_fallback_order() {
+        let root = test_dir("download-fallback");
+        let missing = root.join("missing");
+        let not_a_dir = root.join("file");
+        std::fs::write(&not_a_dir, b"").unwrap();
+        let first = root.join("first");
+        let second = root.join("second");
+        std::fs::create_dir_all(&first).unwrap();
+        std::fs::create_dir_all(&second).unwrap();
+
+        assert_eq!(
+            first_writable_dir([
+                missing.clone(),
+                not_a_dir.clone(),
+                first.clone(),
+                second.clone()
+            ]),
+            Ok(first.clone())
+        );
+        let error = first_writable_dir([missing.clone(), not_a_dir]).unwrap_err();
+        assert!(error.contains("not an existing directory"));
+
+        // A configured directory wins, and is never silently replaced by a fallback
+        assert_eq!(writable_download_dir(Some(&second)), Ok(second));
+        assert!(writable_download_dir(Some(&missing))
+            .unwrap_err()
+            .starts_with("Configured download directory"));
+        assert!(writable_download_dir(None).is_ok());
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_download_dir_rejects_read_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let root = test_dir("download-read-only");
+        let read_only = root.join("read-only");
+        let writable = root.join("writable");
+        std::fs::create_dir_all(&read_only).unwrap();
+        std::fs::create_dir_all(&writable).unwrap();
+        std::fs::set_permissions(&read_only, std::fs::Permissions::from_mode(0o555)).unwrap();
+
+        // Permissions don't bind root, so there is nothing to check there
+        if std::fs::write(read_only.join("probe"), b"").is_err() {
+            assert!(check_writable(&read_only)
+                .unwrap_err()
+                .starts_with("not writable"));
+            assert_eq!(
+                first_writable_dir([read_only.clone(), writable.clone()]),
+                Ok(writable)
+            );
+        }
+        std::fs::set_permissions(&read_only, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_parse_slot_range() {
+        assert_eq!(parse_slot_range("1000"), Some((1000, 1000)));
+        assert_eq!(parse_slot_range(" 1000 - 1010 "), Some((1000, 1010)));
+        assert_eq!(parse_slot_range("1010-1000"), None);
+        assert_eq!(parse_slot_range("abc"), None);
+        assert_eq!(parse_slot_range(""), None);
+    }
+
+    #[test]
+    fn test_slot_range_len() {
+        assert_eq!(slot_range_len(1000, 1000), Some(1));
+        assert_eq!(slot_range_len(1000, 1010), Some(11));
+        assert_eq!(slot_range_len(1, u64::MAX), Some(u64::MAX));
+        assert_eq!(slot_range_len(0, u64::MAX), None);
+    }
+
+    #[test]
+    fn test_parse_watchlist() {
+        let contents = "identity,vote_account\n\
+                        Vote111111111111111111111111111111111111111,x\n\
+                        \n\
+                        \"11111111111111111111111111111111\"\n\
+                        not-a-pubkey\n";
+        let parsed = parse_watchlist(contents);
+        assert_eq!(
+            parsed.accepted,
+            vec![
+                "Vote111111111111111111111111111111111111111".to_string(),
+                "11111111111111111111111111111111".to_string(),
+            ]
+        );
+        assert_eq!(parsed.rejected, 1);
+    }
+
+    #[test]
+    fn test_error_details() {
+        let details = error_details("Find voters", "Request to the RPC node timed out");
+        assert!(details
+            .starts_with("Operation: Find voters\nError: Request to the RPC node timed out\n"));
+        assert!(details.contains(env!("CARGO_PKG_VERSION")));
     }
 
     #[test]
@@ -286,5 +1965,48 @@ mod tests {
             Cluster::Mainnet.url(),
             "https://api.mainnet-beta.solana.com"
         );
+        assert_eq!(Cluster::Devnet.url(), "https://api.devnet.solana.com");
+        assert_eq!(Cluster::Localhost.url(), "http://127.0.0.1:8899");
+    }
+
+    #[test]
+    fn test_cluster_deserializes_saved_names() {
+        // Config files written before Devnet existed still load
+        for (saved, cluster) in [
+            ("\"Testnet\"", Cluster::Testnet),
+            ("\"Mainnet\"", Cluster::Mainnet),
+            ("\"Devnet\"", Cluster::Devnet),
+        ] {
+            assert_eq!(serde_json::from_str::<Cluster>(saved).unwrap(), cluster);
+        }
+    }
+
+    #[test]
+    fn test_cluster_endpoint() {
+        assert_eq!(
+            Cluster::Custom.endpoint("https://rpc.example.com"),
+            "https://rpc.example.com"
+        );
+        assert_eq!(
+            Cluster::Testnet.endpoint("https://rpc.example.com"),
+            "https://api.testnet.solana.com"
+        );
+    }
+
+    #[test]
+    fn test_push_recent_search() {
+        let mut history = vec!["a".to_string(), "b".to_string()];
+        push_recent_search(&mut history, " b ", 3);
+        assert_eq!(history, vec!["b".to_string(), "a".to_string()]);
+
+        push_recent_search(&mut history, "c", 3);
+        push_recent_search(&mut history, "d", 3);
+        assert_eq!(
+            history,
+            vec!["d".to_string(), "c".to_string(), "b".to_string()]
+        );
+
+        push_recent_search(&mut history, "   ", 3);
+        assert_eq!(history.len(), 3);
     }
 }